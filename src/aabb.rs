@@ -1,4 +1,4 @@
-use crate::{Scalar, VecN};
+use crate::{DisplayAabb, Scalar, VecN};
 
 /// Trait for axis-aligned bounding boxes
 pub trait Aabb: Sized {
@@ -26,6 +26,10 @@ pub trait Aabb: Sized {
     fn end_dim(&self, dim: usize) -> <Self::Vector as VecN>::Scalar {
         self.origin_dim(dim) + self.size_dim(dim)
     }
+    /// Get an adapter that displays the aabb as `(x, y)..(x, y)` from its origin to its end
+    fn display_aabb(&self) -> DisplayAabb<'_, Self> {
+        DisplayAabb::new(self)
+    }
     /// The the center value of a dimension
     fn center_dim(&self, dim: usize) -> <Self::Vector as VecN>::Scalar {
         self.origin_dim(dim) + self.size_dim(dim) / <Self::Vector as VecN>::Scalar::TWO
@@ -78,6 +82,18 @@ pub trait Aabb: Sized {
     }
 }
 
+/// Get the minimum and maximum corners of an aabb
+///
+/// Aabbs with negative sizes have their origin and end swapped.
+pub(crate) fn corners<A>(aabb: &A) -> (A::Vector, A::Vector)
+where
+    A: Aabb,
+{
+    let min = A::Vector::from_fn(|i| aabb.origin_dim(i).minn(aabb.end_dim(i)));
+    let max = A::Vector::from_fn(|i| aabb.origin_dim(i).maxx(aabb.end_dim(i)));
+    (min, max)
+}
+
 impl<T, const N: usize> Aabb for [[T; N]; 2]
 where
     T: Scalar,
@@ -0,0 +1,114 @@
+use crate::{
+    arc::wrap_angle, steer::perpendicular, FloatingScalar, FloatingVecN, Scalar, Vec3, VecN,
+};
+
+/// Get an orientation whose forward axis points from a position toward a target
+///
+/// The result is the right, up, and forward vectors. Forward is the negation of right
+/// cross up, as for a camera looking along its negative Z axis. The up vector is as close
+/// to `up` as it can be while staying perpendicular to the forward vector. If the target
+/// is at the position, the current up is kept and forward is chosen arbitrarily.
+pub fn look_at<T>(position: [T; 3], target: [T; 3], up: [T; 3]) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    let forward = target.sub(position).unit_or(perpendicular(up).unit());
+    basis(forward, up)
+}
+
+/// Turn a direction toward another by at most an angle in radians
+///
+/// The result is a unit vector. It turns along the shortest arc, and if the directions
+/// are opposite, in an arbitrary perpendicular direction. Returns the unit current
+/// direction if the target direction is zero.
+pub fn aim_toward<V>(current: V, target: V, max_angle: V::Scalar) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let current = current.unit();
+    let Some(target) = target.try_unit() else {
+        return current;
+    };
+    if current.squared_mag().is_zero() {
+        return target;
+    }
+    let angle = current.angle_between(target);
+    if angle <= max_angle {
+        return target;
+    }
+    let side = target
+        .reject_from(current)
+        .unit_or(perpendicular(current).unit());
+    let [cos, sin] = max_angle.maxx(V::Scalar::ZERO).angle_as_vector();
+    current.mul(cos).add(side.mul(sin))
+}
+
+/// Turn an angle in radians toward another by at most a maximum change
+///
+/// The angle turns whichever way is shorter and stops at the target without overshooting.
+/// The result is not wrapped, so it stays close to `current`.
+pub fn rotate_toward<T>(current: T, target: T, max_delta: T) -> T
+where
+    T: FloatingScalar,
+{
+    current
+        + shortest_turn(current, target)
+            .maxx(-max_delta)
+            .minn(max_delta)
+}
+
+/// Move an angle in radians toward another with exponential decay over a time step
+///
+/// The angle turns whichever way is shorter. The result is not wrapped, so it stays close
+/// to `current`. See [`FloatingScalar::exp_decay`].
+pub fn exp_decay_angle<T>(current: T, target: T, lambda: T, dt: T) -> T
+where
+    T: FloatingScalar,
+{
+    let turn = shortest_turn(current, target);
+    current + turn - turn * (-lambda * dt).exp()
+}
+
+/// Get the signed angle of the shortest turn from one angle to another
+fn shortest_turn<T>(current: T, target: T) -> T
+where
+    T: FloatingScalar,
+{
+    let delta = wrap_angle(target - current);
+    if delta > T::PI {
+        delta - T::TAU
+    } else {
+        delta
+    }
+}
+
+/// Turn an orientation's forward axis toward a target by at most an angle in radians
+///
+/// This is [`look_at`] with the forward direction limited by [`aim_toward`], for turrets,
+/// heads, and cameras that turn at a limited speed.
+pub fn aim_at<T>(
+    forward: [T; 3],
+    position: [T; 3],
+    target: [T; 3],
+    up: [T; 3],
+    max_angle: T,
+) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    basis(aim_toward(forward, target.sub(position), max_angle), up)
+}
+
+/// Get the right, up, and forward vectors for a unit forward vector and an up preference
+///
+/// If forward is parallel to up, right is chosen arbitrarily. For a Y up, that is the X axis.
+pub(crate) fn basis<T>(forward: [T; 3], up: [T; 3]) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    let right = forward
+        .cross(up)
+        .unit_or(forward.cross(perpendicular(forward)).unit());
+    [right, right.cross(forward), forward]
+}
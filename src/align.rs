@@ -0,0 +1,357 @@
+use crate::{
+    eigen::symmetric_eigen, steer::perpendicular, AabbTree, FloatingScalar, FloatingVecN, Vec3,
+    VecN,
+};
+
+/// Rotate a vector by a rotation given as the rotated X, Y, and Z axes
+///
+/// This is the representation returned by [`kabsch`], [`look_at`](crate::look_at), and
+/// [`billboard`](crate::billboard).
+pub fn rotate_by<T>(rotation: [[T; 3]; 3], v: [T; 3]) -> [T; 3]
+where
+    T: FloatingScalar,
+{
+    let [x, y, z] = rotation;
+    x.mul(v[0]).add(y.mul(v[1])).add(z.mul(v[2]))
+}
+
+/// Get the rotation that does nothing
+fn identity<T>() -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    [
+        [T::ONE, T::ZERO, T::ZERO],
+        [T::ZERO, T::ONE, T::ZERO],
+        [T::ZERO, T::ZERO, T::ONE],
+    ]
+}
+
+/// Get the rotation around a unit axis by an angle in radians
+fn around_axis<T>(axis: [T; 3], angle: T) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    identity().map(|v: [T; 3]| v.rotate_around(axis, angle))
+}
+
+/// Get the rotation that applies one rotation and then another
+///
+/// The result maps `v` to `rotate_by(second, rotate_by(first, v))`.
+pub fn compose_rotations<T>(second: [[T; 3]; 3], first: [[T; 3]; 3]) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    first.map(|axis| rotate_by(second, axis))
+}
+
+/// Get the rotation that undoes a rotation
+pub fn invert_rotation<T>(rotation: [[T; 3]; 3]) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    std::array::from_fn(|i| std::array::from_fn(|j| rotation[j][i]))
+}
+
+/// Get the shortest rotation that turns one direction to another
+///
+/// Opposite directions are turned half way around an arbitrary perpendicular axis.
+/// Returns the identity rotation if either direction is zero.
+pub fn rotation_between<T>(from: [T; 3], to: [T; 3]) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    let (Some(from), Some(to)) = (from.try_unit(), to.try_unit()) else {
+        return identity();
+    };
+    let axis = from.cross(to);
+    let (sin, cos) = (axis.mag(), from.dot(to));
+    if sin.is_zero() {
+        return if cos > T::ZERO {
+            identity()
+        } else {
+            around_axis(perpendicular(from).unit(), T::PI)
+        };
+    }
+    around_axis(axis.div(sin), sin.atan2(cos))
+}
+
+/// Split a rotation into a swing and a twist around an axis
+///
+/// The twist rotates around the axis, and the swing turns the axis to where the rotation
+/// takes it along the shortest arc. Applying the twist and then the swing gives the
+/// rotation, so `compose_rotations(swing, twist)` is the original rotation. This is useful
+/// for limiting how far a joint can twist or bend.
+///
+/// Returns the rotation as the swing and no twist if the axis is zero.
+pub fn swing_twist<T>(rotation: [[T; 3]; 3], axis: [T; 3]) -> ([[T; 3]; 3], [[T; 3]; 3])
+where
+    T: FloatingScalar,
+{
+    let Some(axis) = axis.try_unit() else {
+        return (rotation, identity());
+    };
+    let swing = rotation_between(axis, rotate_by(rotation, axis));
+    let twist = compose_rotations(invert_rotation(swing), rotation);
+    (swing, twist)
+}
+
+/// Get the rotation around a vector's direction by an angle equal to its magnitude
+///
+/// This is the exponential map from an axis-angle vector to a rotation.
+/// A zero vector gives the identity rotation.
+pub fn rotation_exp<T>(axis_angle: [T; 3]) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    around_axis(axis_angle, axis_angle.mag())
+}
+
+/// Get the axis-angle vector of a rotation
+///
+/// This is the logarithm map, the inverse of [`rotation_exp`].
+/// The angle is in the range `[0, π]`.
+pub fn rotation_ln<T>(rotation: [[T; 3]; 3]) -> [T; 3]
+where
+    T: FloatingScalar,
+{
+    let r = rotation;
+    // The skew-symmetric part is the axis scaled by the sine of the angle
+    let skew = [r[1][2] - r[2][1], r[2][0] - r[0][2], r[0][1] - r[1][0]].div(T::TWO);
+    let sin = skew.mag();
+    let cos = (r[0][0] + r[1][1] + r[2][2] - T::ONE) / T::TWO;
+    let angle = sin.atan2(cos);
+    if cos >= T::ZERO {
+        return if sin.is_zero() {
+            skew
+        } else {
+            skew.mul(angle / sin)
+        };
+    }
+    // Near a half turn the sine is too small to find the axis,
+    // so take it from the symmetric part instead
+    let sym: [[T; 3]; 3] =
+        std::array::from_fn(|i| std::array::from_fn(|j| (r[i][j] + r[j][i]) / T::TWO));
+    let k = (0..3)
+        .max_by(|&i, &j| {
+            sym[i][i]
+                .partial_cmp(&sym[j][j])
+                .expect("comparison failed")
+        })
+        .unwrap_or(0);
+    let mut axis = sym[k];
+    axis[k] -= cos;
+    let axis = axis.unit();
+    let axis = if axis.dot(skew) < T::ZERO {
+        axis.map(|x| -x)
+    } else {
+        axis
+    };
+    axis.mul(angle)
+}
+
+/// Scale the angle of a rotation
+///
+/// A factor of `0` gives the identity rotation, and `1` gives the rotation itself.
+/// Interpolating between rotations `a` and `b` is
+/// `compose_rotations(rotation_pow(compose_rotations(b, invert_rotation(a)), t), a)`.
+pub fn rotation_pow<T>(rotation: [[T; 3]; 3], t: T) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    rotation_exp(rotation_ln(rotation).mul(t))
+}
+
+/// Turn an orientation by a world-space angular velocity over a time step
+///
+/// The angular velocity's direction is the axis, and its magnitude is the speed in radians
+/// per unit time.
+pub fn integrate_angular_velocity<T>(
+    orientation: [[T; 3]; 3],
+    angular_velocity: [T; 3],
+    dt: T,
+) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    compose_rotations(rotation_exp(angular_velocity.mul(dt)), orientation)
+}
+
+/// Get the mean of some points
+fn centroid<T>(points: &[[T; 3]]) -> [T; 3]
+where
+    T: FloatingScalar,
+{
+    let sum = points.iter().fold([T::ZERO; 3], |acc, p| acc.add(*p));
+    sum.div(T::from_f64(points.len() as f64))
+}
+
+/// Get the rotation that best maps the centered source points onto the centered targets
+fn best_rotation<T>(
+    src: &[[T; 3]],
+    dst: &[[T; 3]],
+    src_center: [T; 3],
+    dst_center: [T; 3],
+) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    // The cross-covariance of the centered points
+    let mut s = [[T::ZERO; 3]; 3];
+    for (a, b) in src.iter().zip(dst) {
+        let (a, b) = (a.sub(src_center), b.sub(dst_center));
+        for i in 0..3 {
+            for j in 0..3 {
+                s[i][j] += a[i] * b[j];
+            }
+        }
+    }
+    // The best rotation is the quaternion that is the top eigenvector of this matrix
+    let [[xx, xy, xz], [yx, yy, yz], [zx, zy, zz]] = s;
+    let n = [
+        [xx + yy + zz, yz - zy, zx - xz, xy - yx],
+        [yz - zy, xx - yy - zz, xy + yx, zx + xz],
+        [zx - xz, xy + yx, yy - xx - zz, yz + zy],
+        [xy - yx, zx + xz, yz + zy, zz - xx - yy],
+    ];
+    let (_, [[w, x, y, z], ..]) = symmetric_eigen(n);
+    let two = T::TWO;
+    [
+        [
+            T::ONE - two * (y * y + z * z),
+            two * (x * y + w * z),
+            two * (x * z - w * y),
+        ],
+        [
+            two * (x * y - w * z),
+            T::ONE - two * (x * x + z * z),
+            two * (y * z + w * x),
+        ],
+        [
+            two * (x * z + w * y),
+            two * (y * z - w * x),
+            T::ONE - two * (x * x + y * y),
+        ],
+    ]
+}
+
+/// Get the rotation and translation that best map points onto corresponding targets
+///
+/// The result minimizes the sum of the squared distances between the transformed points
+/// and their targets, and maps a point `p` to `rotate_by(rotation, p) + translation`. The
+/// rotation is given as the rotated X, Y, and Z axes, and is never a reflection.
+///
+/// Returns `None` if there are no points.
+///
+/// # Panics
+///
+/// Panics if there are not as many targets as points.
+pub fn kabsch<T>(src: &[[T; 3]], dst: &[[T; 3]]) -> Option<([[T; 3]; 3], [T; 3])>
+where
+    T: FloatingScalar,
+{
+    let (rotation, _, translation) = align(src, dst, false)?;
+    Some((rotation, translation))
+}
+
+/// Get the rotation, uniform scale, and translation that best map points onto targets
+///
+/// This is like [`kabsch`], but a point `p` is mapped to
+/// `rotate_by(rotation, p) * scale + translation`.
+///
+/// Returns `None` if there are no points.
+///
+/// # Panics
+///
+/// Panics if there are not as many targets as points.
+pub fn kabsch_scaled<T>(src: &[[T; 3]], dst: &[[T; 3]]) -> Option<([[T; 3]; 3], T, [T; 3])>
+where
+    T: FloatingScalar,
+{
+    align(src, dst, true)
+}
+
+fn align<T>(src: &[[T; 3]], dst: &[[T; 3]], scaled: bool) -> Option<([[T; 3]; 3], T, [T; 3])>
+where
+    T: FloatingScalar,
+{
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "there must be a target for each point"
+    );
+    if src.is_empty() {
+        return None;
+    }
+    let (src_center, dst_center) = (centroid(src), centroid(dst));
+    let rotation = best_rotation(src, dst, src_center, dst_center);
+    let scale = if scaled {
+        let (mut num, mut den) = (T::ZERO, T::ZERO);
+        for (a, b) in src.iter().zip(dst) {
+            let a = rotate_by(rotation, a.sub(src_center));
+            num += a.dot(b.sub(dst_center));
+            den += a.squared_mag();
+        }
+        if den.is_zero() {
+            T::ONE
+        } else {
+            num / den
+        }
+    } else {
+        T::ONE
+    };
+    let translation = dst_center.sub(rotate_by(rotation, src_center).mul(scale));
+    Some((rotation, scale, translation))
+}
+
+/// Align points to a target point cloud with point-to-point iterative closest point
+///
+/// Each iteration pairs every transformed point with its nearest target point, found with
+/// an [`AabbTree`], and re-solves the best rigid transform for those pairs with [`kabsch`].
+/// Iteration stops after `max_iterations`, or once the mean squared distance between the
+/// pairs improves by no more than `tolerance`.
+///
+/// The result is a rotation and translation in the same form as [`kabsch`]'s. ICP only
+/// finds a local best fit, so the point sets should already be roughly aligned.
+///
+/// Returns `None` if either set of points is empty.
+pub fn icp<T>(
+    points: &[[T; 3]],
+    target: &[[T; 3]],
+    max_iterations: usize,
+    tolerance: T,
+) -> Option<([[T; 3]; 3], [T; 3])>
+where
+    T: FloatingScalar,
+{
+    if points.is_empty() || target.is_empty() {
+        return None;
+    }
+    // Tree ids are not point indices, so map them back
+    let mut tree = AabbTree::new(T::ZERO);
+    let mut indices = vec![0; 2 * target.len()];
+    for (i, &p) in target.iter().enumerate() {
+        indices[tree.insert([p, [T::ZERO; 3]])] = i;
+    }
+    let mut transform = (identity(), [T::ZERO; 3]);
+    let mut last_error = T::INFINITY;
+    let mut matches = Vec::with_capacity(points.len());
+    for _ in 0..max_iterations {
+        matches.clear();
+        let mut error = T::ZERO;
+        for &p in points {
+            let moved = rotate_by(transform.0, p).add(transform.1);
+            let id = tree.nearest(moved).expect("the tree is not empty");
+            let matched = target[indices[id]];
+            error += moved.squared_dist(matched);
+            matches.push(matched);
+        }
+        error /= T::from_f64(points.len() as f64);
+        if last_error - error <= tolerance {
+            break;
+        }
+        last_error = error;
+        transform = kabsch(points, &matches)?;
+    }
+    Some(transform)
+}
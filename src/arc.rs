@@ -0,0 +1,141 @@
+use crate::{polygon::vec2, quadratic_roots, Aabb, FloatingScalar, VecN, XVec, YVec};
+
+/// A 2D circular arc
+///
+/// Angles are in radians, measured counter-clockwise from the positive X axis.
+/// The arc runs from the start angle to the end angle, so it is clockwise
+/// if the end angle is less than the start angle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CircularArc<T> {
+    /// The center of the arc's circle
+    pub center: [T; 2],
+    /// The radius of the arc's circle
+    pub radius: T,
+    /// The angle of the start of the arc
+    pub start_angle: T,
+    /// The angle of the end of the arc
+    pub end_angle: T,
+}
+
+/// Get an angle wrapped to the range `[0, τ)`
+pub(crate) fn wrap_angle<T>(angle: T) -> T
+where
+    T: FloatingScalar,
+{
+    angle - T::TAU * T::from_f64((angle / T::TAU).to_f64().floor())
+}
+
+impl<T> CircularArc<T>
+where
+    T: FloatingScalar,
+{
+    /// Create a new arc
+    pub fn new(center: [T; 2], radius: T, start_angle: T, end_angle: T) -> Self {
+        CircularArc {
+            center,
+            radius,
+            start_angle,
+            end_angle,
+        }
+    }
+    /// Get the signed angle swept by the arc
+    ///
+    /// This is positive for counter-clockwise arcs.
+    pub fn sweep(&self) -> T {
+        self.end_angle - self.start_angle
+    }
+    /// Get the length of the arc
+    pub fn length(&self) -> T {
+        self.radius * self.sweep().abs()
+    }
+    /// Check if an angle is within the arc's sweep
+    pub fn contains_angle(&self, angle: T) -> bool {
+        let sweep = self.sweep();
+        if sweep.abs() >= T::TAU {
+            true
+        } else if sweep >= T::ZERO {
+            wrap_angle(angle - self.start_angle) <= sweep
+        } else {
+            wrap_angle(self.start_angle - angle) <= -sweep
+        }
+    }
+    /// Get the point on the arc's circle at an angle
+    pub fn point_at_angle<V>(&self, angle: T) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        let [x, y] = angle.angle_as_vector();
+        vec2(
+            self.center[0] + x * self.radius,
+            self.center[1] + y * self.radius,
+        )
+    }
+    /// Get the point at a fraction of the way along the arc
+    pub fn point_at<V>(&self, t: T) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        self.point_at_angle(self.start_angle + self.sweep() * t)
+    }
+    /// Get the smallest aabb that contains the arc
+    pub fn aabb<A>(&self) -> A
+    where
+        A: Aabb<Vector = [T; 2]>,
+    {
+        let quarter = T::PI / T::TWO;
+        let extremes = (0..4)
+            .map(|i| quarter * T::from_f64(i as f64))
+            .filter(|&angle| self.contains_angle(angle))
+            .map(|angle| self.point_at_angle(angle));
+        let ends = [
+            self.point_at_angle(self.start_angle),
+            self.point_at_angle(self.end_angle),
+        ];
+        A::bounding(ends.into_iter().chain(extremes)).expect("arcs have end points")
+    }
+    /// Check if the circular sector swept by the arc contains a point
+    pub fn sector_contains<V>(&self, point: V) -> bool
+    where
+        V: VecN<Scalar = T>,
+    {
+        let [dx, dy] = [point.x() - self.center[0], point.y() - self.center[1]];
+        if dx * dx + dy * dy > self.radius * self.radius {
+            return false;
+        }
+        (dx.is_zero() && dy.is_zero()) || self.contains_angle(dy.atan2(dx))
+    }
+    /// Get the intersections of the arc with the segment between two points
+    ///
+    /// Returns pairs of the fraction along the arc and the fraction along the segment,
+    /// sorted by the fraction along the segment.
+    pub fn intersect_segment<V>(&self, a: V, b: V) -> Vec<[T; 2]>
+    where
+        V: VecN<Scalar = T>,
+    {
+        let [ox, oy] = [a.x() - self.center[0], a.y() - self.center[1]];
+        let [dx, dy] = [b.x() - a.x(), b.y() - a.y()];
+        let sweep = self.sweep();
+        quadratic_roots(
+            dx * dx + dy * dy,
+            T::TWO * (ox * dx + oy * dy),
+            ox * ox + oy * oy - self.radius * self.radius,
+        )
+        .into_iter()
+        .filter(|&s| s >= T::ZERO && s <= T::ONE)
+        .filter_map(|s| {
+            let angle = (oy + dy * s).atan2(ox + dx * s);
+            if !self.contains_angle(angle) {
+                return None;
+            }
+            let t = if sweep.is_zero() {
+                T::ZERO
+            } else if sweep > T::ZERO {
+                wrap_angle(angle - self.start_angle) / sweep
+            } else {
+                wrap_angle(self.start_angle - angle) / -sweep
+            };
+            Some([t.minn(T::ONE), s])
+        })
+        .collect()
+    }
+}
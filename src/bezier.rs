@@ -0,0 +1,325 @@
+use crate::{cubic_roots, FloatingScalar, Scalar, VecN, XVec, YVec};
+
+/// Trait for treating 4 vectors as the control points of a cubic Bezier curve
+///
+/// The curve starts at the first point and ends at the last.
+/// Intersections only consider the X and Y dimensions of the vectors.
+pub trait CubicBezier: Sized {
+    /// The vector type
+    type Vector: VecN;
+    /// Get the point at a parameter along the curve
+    fn point_at(&self, t: <Self::Vector as VecN>::Scalar) -> Self::Vector;
+    /// Get the derivative of the curve at a parameter along it
+    fn tangent_at(&self, t: <Self::Vector as VecN>::Scalar) -> Self::Vector;
+    /// Split the curve at a parameter into two curves
+    fn split(&self, t: <Self::Vector as VecN>::Scalar) -> [Self; 2];
+    /// Get the parameters along the curve where it crosses the infinite line through two points
+    ///
+    /// The parameters are sorted in ascending order.
+    fn intersect_line(
+        &self,
+        a: Self::Vector,
+        b: Self::Vector,
+    ) -> Vec<<Self::Vector as VecN>::Scalar>;
+    /// Get the intersections of the curve with the segment between two points
+    ///
+    /// Returns pairs of the parameter along the curve and the parameter along the segment,
+    /// sorted by the parameter along the curve.
+    fn intersect_segment(
+        &self,
+        a: Self::Vector,
+        b: Self::Vector,
+    ) -> Vec<[<Self::Vector as VecN>::Scalar; 2]>;
+    /// Get the intersections of the curve with another by recursive subdivision
+    ///
+    /// Subdivision stops once pieces of both curves are within `tolerance` in size or of
+    /// being straight. Overlapping curves give a limited number of points along their
+    /// overlap. Returns pairs of the parameter along this curve and the parameter along the other,
+    /// sorted by the parameter along this curve.
+    fn intersect_bezier(
+        &self,
+        other: &Self,
+        tolerance: <Self::Vector as VecN>::Scalar,
+    ) -> Vec<[<Self::Vector as VecN>::Scalar; 2]>;
+}
+
+impl<V> CubicBezier for [V; 4]
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    type Vector = V;
+    fn point_at(&self, t: V::Scalar) -> V {
+        let [first, _] = self.split(t);
+        first[3]
+    }
+    fn tangent_at(&self, t: V::Scalar) -> V {
+        let [p0, p1, p2, p3] = *self;
+        let nt = V::Scalar::ONE - t;
+        p1.sub(p0)
+            .mul(nt * nt)
+            .add(p2.sub(p1).mul(V::Scalar::TWO * nt * t))
+            .add(p3.sub(p2).mul(t * t))
+            .mul(V::Scalar::from_f64(3.0))
+    }
+    fn split(&self, t: V::Scalar) -> [Self; 2] {
+        let [p0, p1, p2, p3] = *self;
+        let p01 = p0.lerp(p1, t);
+        let p12 = p1.lerp(p2, t);
+        let p23 = p2.lerp(p3, t);
+        let p012 = p01.lerp(p12, t);
+        let p123 = p12.lerp(p23, t);
+        let mid = p012.lerp(p123, t);
+        [[p0, p01, p012, mid], [mid, p123, p23, p3]]
+    }
+    fn intersect_line(&self, a: V, b: V) -> Vec<V::Scalar> {
+        // The signed distances of the control points from the line are the
+        // Bernstein coefficients of the curve's signed distance
+        let [nx, ny] = [a.y() - b.y(), b.x() - a.x()];
+        let [d0, d1, d2, d3] = self.map(|p| (p.x() - a.x()) * nx + (p.y() - a.y()) * ny);
+        let three = V::Scalar::from_f64(3.0);
+        cubic_roots(
+            d3 - d0 + three * (d1 - d2),
+            three * (d0 + d2) - three * V::Scalar::TWO * d1,
+            three * (d1 - d0),
+            d0,
+        )
+        .into_iter()
+        .filter(|&t| t >= V::Scalar::ZERO && t <= V::Scalar::ONE)
+        .collect()
+    }
+    fn intersect_segment(&self, a: V, b: V) -> Vec<[V::Scalar; 2]> {
+        let [ex, ey] = [b.x() - a.x(), b.y() - a.y()];
+        let len2 = ex * ex + ey * ey;
+        if len2.is_zero() {
+            return Vec::new();
+        }
+        self.intersect_line(a, b)
+            .into_iter()
+            .filter_map(|t| {
+                let p = self.point_at(t);
+                let s = ((p.x() - a.x()) * ex + (p.y() - a.y()) * ey) / len2;
+                (s >= V::Scalar::ZERO && s <= V::Scalar::ONE).then_some([t, s])
+            })
+            .collect()
+    }
+    fn intersect_bezier(&self, other: &Self, tolerance: V::Scalar) -> Vec<[V::Scalar; 2]> {
+        let mut hits = Vec::new();
+        let whole = [V::Scalar::ZERO, V::Scalar::ONE];
+        // Pieces smaller than the curves' precision cannot be usefully split
+        let (a_min, a_max) = control_bounds(self);
+        let (b_min, b_max) = control_bounds(other);
+        let scale = (0..2).fold(V::Scalar::ZERO, |acc, i| {
+            acc.maxx(a_max[i].maxx(b_max[i]) - a_min[i].minn(b_min[i]))
+        });
+        let tolerance = tolerance.maxx(scale * V::Scalar::EPSILON.sqrt());
+        subdivide((*self, whole), (*other, whole), tolerance, 0, &mut hits);
+        for hit in &mut hits {
+            *hit = refine(self, other, *hit);
+        }
+        hits.sort_by(|a, b| {
+            a[0].partial_cmp(&b[0])
+                .expect("parameter comparison failed")
+        });
+        // Neighboring pieces may both contain the same intersection
+        let mut merged: Vec<[V::Scalar; 2]> = Vec::with_capacity(hits.len());
+        for hit in hits {
+            let p = self.point_at(hit[0]);
+            let repeated = merged.iter().any(|&[t, _]| {
+                let q = self.point_at(t);
+                let [dx, dy] = [p.x() - q.x(), p.y() - q.y()];
+                dx * dx + dy * dy <= V::Scalar::from_f64(4.0) * tolerance * tolerance
+            });
+            if !repeated {
+                merged.push(hit);
+            }
+        }
+        merged
+    }
+}
+
+/// The maximum number of times the curves are subdivided when intersecting
+const MAX_DEPTH: usize = 32;
+
+/// The maximum number of intersections found before merging
+///
+/// Two cubic curves cross at most 9 times unless they overlap, and overlapping curves
+/// would otherwise give an intersection for every piece along the overlap.
+const MAX_HITS: usize = 64;
+
+type Piece<V> = ([V; 4], [<V as VecN>::Scalar; 2]);
+
+fn subdivide<V>(
+    a: Piece<V>,
+    b: Piece<V>,
+    tolerance: V::Scalar,
+    depth: usize,
+    hits: &mut Vec<[V::Scalar; 2]>,
+) where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    if hits.len() >= MAX_HITS {
+        return;
+    }
+    let (a_min, a_max) = control_bounds(&a.0);
+    let (b_min, b_max) = control_bounds(&b.0);
+    let overlaps =
+        (0..2).all(|i| a_min[i] <= b_max[i] + tolerance && b_min[i] <= a_max[i] + tolerance);
+    if !overlaps {
+        return;
+    }
+    if is_flat(&a.0, tolerance) && is_flat(&b.0, tolerance) {
+        if let Some(hit) = intersect_chords(a, b, tolerance) {
+            hits.push(hit);
+        }
+        return;
+    }
+    let a_size = (a_max[0] - a_min[0]).maxx(a_max[1] - a_min[1]);
+    let b_size = (b_max[0] - b_min[0]).maxx(b_max[1] - b_min[1]);
+    let half = V::Scalar::ONE / V::Scalar::TWO;
+    let mid = |[t0, t1]: [V::Scalar; 2]| t0.lerp(t1, half);
+    if (a_size <= tolerance && b_size <= tolerance) || depth >= MAX_DEPTH {
+        hits.push([mid(a.1), mid(b.1)]);
+        return;
+    }
+    let halves = |(curve, range): Piece<V>| {
+        let m = mid(range);
+        let [first, second] = curve.split(half);
+        [(first, [range[0], m]), (second, [m, range[1]])]
+    };
+    if a_size >= b_size {
+        for half in halves(a) {
+            subdivide(half, b, tolerance, depth + 1, hits);
+        }
+    } else {
+        for half in halves(b) {
+            subdivide(a, half, tolerance, depth + 1, hits);
+        }
+    }
+}
+
+/// Get the 2D cross product of two vectors
+fn cross<V>(a: V, b: V) -> V::Scalar
+where
+    V: VecN,
+{
+    a.x() * b.y() - a.y() * b.x()
+}
+
+/// Check if a curve's inner control points are within a distance of the line between its ends
+fn is_flat<V>(curve: &[V; 4], tolerance: V::Scalar) -> bool
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let chord = curve[3].sub(curve[0]);
+    let len2 = chord.x() * chord.x() + chord.y() * chord.y();
+    curve[1..3].iter().all(|p| {
+        let offset = p.sub(curve[0]);
+        if len2.is_zero() {
+            offset.x() * offset.x() + offset.y() * offset.y() <= tolerance * tolerance
+        } else {
+            let dist = cross(chord, offset);
+            dist * dist <= tolerance * tolerance * len2
+        }
+    })
+}
+
+/// Intersect the chords of two flat pieces of curves
+///
+/// Chords that lie along the same line intersect at the middle of their overlap.
+fn intersect_chords<V>(a: Piece<V>, b: Piece<V>, tolerance: V::Scalar) -> Option<[V::Scalar; 2]>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let (a0, da) = (a.0[0], a.0[3].sub(a.0[0]));
+    let (b0, db) = (b.0[0], b.0[3].sub(b.0[0]));
+    let (len_a2, len_b2) = (
+        da.x() * da.x() + da.y() * da.y(),
+        db.x() * db.x() + db.y() * db.y(),
+    );
+    let half = V::Scalar::ONE / V::Scalar::TWO;
+    let at = |[t0, t1]: [V::Scalar; 2], s: V::Scalar| {
+        t0.lerp(t1, s.maxx(V::Scalar::ZERO).minn(V::Scalar::ONE))
+    };
+    if len_a2.is_zero() || len_b2.is_zero() {
+        return Some([at(a.1, half), at(b.1, half)]);
+    }
+    let w = b0.sub(a0);
+    let det = cross(da, db);
+    let (len_a, len_b) = (len_a2.sqrt(), len_b2.sqrt());
+    let (slack_a, slack_b) = (tolerance / len_a, tolerance / len_b);
+    let within = |s: V::Scalar, slack: V::Scalar| s >= -slack && s <= V::Scalar::ONE + slack;
+    if det.abs() > V::Scalar::EPSILON.sqrt() * len_a * len_b {
+        let s = cross(w, db) / det;
+        let u = cross(w, da) / det;
+        return (within(s, slack_a) && within(u, slack_b)).then(|| [at(a.1, s), at(b.1, u)]);
+    }
+    // Parallel chords only meet if they lie along the same line and overlap
+    if cross(w, da).abs() > tolerance * len_a {
+        return None;
+    }
+    let project = |p: V| {
+        let offset = p.sub(a0);
+        (offset.x() * da.x() + offset.y() * da.y()) / len_a2
+    };
+    let (s0, s1) = (project(b0), project(b.0[3]));
+    let start = s0.minn(s1).maxx(V::Scalar::ZERO);
+    let end = s0.maxx(s1).minn(V::Scalar::ONE);
+    if start > end + slack_a {
+        return None;
+    }
+    let s = start.lerp(end, half);
+    let p = a0.add(da.mul(s)).sub(b0);
+    let u = (p.x() * db.x() + p.y() * db.y()) / len_b2;
+    Some([at(a.1, s), at(b.1, u)])
+}
+
+/// Refine the parameters of an intersection of two curves with Newton's method
+fn refine<V>(a: &[V; 4], b: &[V; 4], [mut t, mut u]: [V::Scalar; 2]) -> [V::Scalar; 2]
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let gap = |t: V::Scalar, u: V::Scalar| {
+        let diff = a.point_at(t).sub(b.point_at(u));
+        diff.x() * diff.x() + diff.y() * diff.y()
+    };
+    for _ in 0..4 {
+        let diff = a.point_at(t).sub(b.point_at(u));
+        let (da, db) = (a.tangent_at(t), b.tangent_at(u));
+        let det = db.x() * da.y() - da.x() * db.y();
+        if det.is_zero() {
+            break;
+        }
+        // Solve da * dt - db * du = -diff
+        let dt = (diff.x() * db.y() - db.x() * diff.y()) / det;
+        let du = (diff.x() * da.y() - da.x() * diff.y()) / det;
+        let next_t = (t + dt).maxx(V::Scalar::ZERO).minn(V::Scalar::ONE);
+        let next_u = (u + du).maxx(V::Scalar::ZERO).minn(V::Scalar::ONE);
+        // Nearly parallel tangents can send the step far away
+        if gap(next_t, next_u) > gap(t, u) {
+            break;
+        }
+        (t, u) = (next_t, next_u);
+    }
+    [t, u]
+}
+
+/// Get the bounds of the X and Y dimensions of a curve's control points
+fn control_bounds<V>(curve: &[V; 4]) -> ([V::Scalar; 2], [V::Scalar; 2])
+where
+    V: VecN,
+{
+    let mut min = [curve[0].x(), curve[0].y()];
+    let mut max = min;
+    for p in &curve[1..] {
+        for (i, d) in [p.x(), p.y()].into_iter().enumerate() {
+            min[i] = min[i].minn(d);
+            max[i] = max[i].maxx(d);
+        }
+    }
+    (min, max)
+}
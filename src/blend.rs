@@ -0,0 +1,42 @@
+use crate::{FloatingScalar, Scalar, VecN};
+
+/// Get the sum of some vectors each multiplied by a weight
+pub fn blend<V>(weighted: &[(V, V::Scalar)]) -> V
+where
+    V: VecN + Copy,
+{
+    weighted
+        .iter()
+        .fold(V::ZERO, |acc, &(v, weight)| acc.add(v.mul(weight)))
+}
+
+/// Get the sum of some vectors each multiplied by a weight, divided by the total weight
+///
+/// Returns `None` if the weights sum to zero.
+pub fn blend_normalized<V>(weighted: &[(V, V::Scalar)]) -> Option<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let total = weighted
+        .iter()
+        .fold(V::Scalar::ZERO, |acc, &(_, weight)| acc + weight);
+    if total.is_zero() {
+        None
+    } else {
+        Some(blend(weighted).div(total))
+    }
+}
+
+/// Check if some weights form a convex combination
+///
+/// This is true if every weight is non-negative and they sum to `1` within a small
+/// tolerance. Blending with such weights gives a point in the convex hull of the vectors.
+pub fn is_convex_combination<T>(weights: &[T]) -> bool
+where
+    T: FloatingScalar,
+{
+    let total = weights.iter().fold(T::ZERO, |acc, &w| acc + w);
+    let count = T::from_f64(weights.len().max(1) as f64);
+    weights.iter().all(|&w| w >= T::ZERO) && (total - T::ONE).is_near_zero(count)
+}
@@ -0,0 +1,118 @@
+use crate::{polygon::vec2, FloatingScalar, FloatingVec2, VecN, XVec, YVec};
+
+/// The state of a 2D rigid body
+///
+/// The position is the body's center of mass, and the rotation is in radians
+/// counter-clockwise. A body with an inverse mass and inverse inertia of zero
+/// is not moved by impulses or forces.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RigidBody2<T> {
+    /// The position of the center of mass
+    pub position: [T; 2],
+    /// The rotation in radians
+    pub rotation: T,
+    /// The linear velocity
+    pub velocity: [T; 2],
+    /// The angular velocity in radians per unit of time
+    pub angular_velocity: T,
+    /// The reciprocal of the mass
+    pub inverse_mass: T,
+    /// The reciprocal of the moment of inertia about the center of mass
+    pub inverse_inertia: T,
+}
+
+/// Get the reciprocal of a value, or zero if it is not positive
+fn inverse<T>(x: T) -> T
+where
+    T: FloatingScalar,
+{
+    if x > T::ZERO {
+        T::ONE / x
+    } else {
+        T::ZERO
+    }
+}
+
+impl<T> RigidBody2<T>
+where
+    T: FloatingScalar,
+{
+    /// Create a new body at rest
+    ///
+    /// A mass or inertia that is not positive is treated as infinite.
+    pub fn new<V>(position: V, mass: T, inertia: T) -> Self
+    where
+        V: VecN<Scalar = T>,
+    {
+        RigidBody2 {
+            position: [position.x(), position.y()],
+            rotation: T::ZERO,
+            velocity: [T::ZERO; 2],
+            angular_velocity: T::ZERO,
+            inverse_mass: inverse(mass),
+            inverse_inertia: inverse(inertia),
+        }
+    }
+    /// Create a new body that is not moved by impulses or forces
+    pub fn fixed<V>(position: V) -> Self
+    where
+        V: VecN<Scalar = T>,
+    {
+        Self::new(position, T::ZERO, T::ZERO)
+    }
+    /// Transform a point from the body's local space to world space
+    pub fn world_point<V>(&self, local: V) -> V
+    where
+        V: VecN<Scalar = T> + Copy,
+    {
+        let [x, y] = [local.x(), local.y()].rotated(self.rotation);
+        vec2(x + self.position[0], y + self.position[1])
+    }
+    /// Transform a point from world space to the body's local space
+    pub fn local_point<V>(&self, world: V) -> V
+    where
+        V: VecN<Scalar = T> + Copy,
+    {
+        let offset = [world.x() - self.position[0], world.y() - self.position[1]];
+        let [x, y] = offset.rotated(-self.rotation);
+        vec2(x, y)
+    }
+    /// Get the velocity of a point on the body in world space
+    pub fn velocity_at<V>(&self, point: V) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        let [rx, ry] = [point.x() - self.position[0], point.y() - self.position[1]];
+        vec2(
+            self.velocity[0] - self.angular_velocity * ry,
+            self.velocity[1] + self.angular_velocity * rx,
+        )
+    }
+    /// Apply an impulse at a point on the body in world space
+    ///
+    /// This changes both the linear and angular velocity.
+    pub fn apply_impulse<V>(&mut self, impulse: V, point: V)
+    where
+        V: VecN<Scalar = T>,
+    {
+        let [rx, ry] = [point.x() - self.position[0], point.y() - self.position[1]];
+        let [jx, jy] = [impulse.x(), impulse.y()];
+        self.velocity[0] += jx * self.inverse_mass;
+        self.velocity[1] += jy * self.inverse_mass;
+        self.angular_velocity += (rx * jy - ry * jx) * self.inverse_inertia;
+    }
+    /// Advance the body by a time step with semi-implicit Euler integration
+    ///
+    /// The force and torque are applied for the whole step before the body is moved.
+    pub fn integrate<V>(&mut self, force: V, torque: T, dt: T)
+    where
+        V: VecN<Scalar = T>,
+    {
+        self.velocity[0] += force.x() * self.inverse_mass * dt;
+        self.velocity[1] += force.y() * self.inverse_mass * dt;
+        self.angular_velocity += torque * self.inverse_inertia * dt;
+        self.position[0] += self.velocity[0] * dt;
+        self.position[1] += self.velocity[1] * dt;
+        self.rotation += self.angular_velocity * dt;
+    }
+}
@@ -0,0 +1,410 @@
+use crate::{
+    aabb::corners, aim::basis, invert_rotation, Aabb, FloatingScalar, FloatingVecN, Ray, Vec3, VecN,
+};
+
+/// A camera that orbits around a target point
+///
+/// The Y axis is up. At a yaw and pitch of zero, the camera is on the positive Z side
+/// of the target looking toward negative Z. Positive yaw orbits counter-clockwise when
+/// viewed from above, and positive pitch raises the camera above the target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrbitCamera<T> {
+    /// The point the camera looks at
+    pub target: [T; 3],
+    /// The distance from the target to the camera
+    pub distance: T,
+    /// The angle around the Y axis in radians
+    pub yaw: T,
+    /// The angle above the horizontal plane in radians
+    pub pitch: T,
+}
+
+impl<T> OrbitCamera<T>
+where
+    T: FloatingScalar,
+{
+    /// Create a new orbit camera
+    pub fn new(target: [T; 3], distance: T, yaw: T, pitch: T) -> Self {
+        OrbitCamera {
+            target,
+            distance,
+            yaw,
+            pitch,
+        }
+    }
+    /// Get the unit vector from the target to the camera
+    fn offset_dir(&self) -> [T; 3] {
+        let [cos_yaw, sin_yaw] = self.yaw.angle_as_vector();
+        let [cos_pitch, sin_pitch] = self.pitch.angle_as_vector();
+        [cos_pitch * sin_yaw, sin_pitch, cos_pitch * cos_yaw]
+    }
+    /// Get the position of the camera
+    pub fn eye(&self) -> [T; 3] {
+        self.target.add(self.offset_dir().mul(self.distance))
+    }
+    /// Get the unit vector the camera looks along
+    pub fn forward(&self) -> [T; 3] {
+        self.offset_dir().mul(-T::ONE)
+    }
+    /// Get the unit vector pointing to the right of the view
+    pub fn right(&self) -> [T; 3] {
+        let [cos_yaw, sin_yaw] = self.yaw.angle_as_vector();
+        [cos_yaw, T::ZERO, -sin_yaw]
+    }
+    /// Get the unit vector pointing up in the view
+    pub fn up(&self) -> [T; 3] {
+        self.right().cross(self.forward())
+    }
+    /// Get the right, up, and forward vectors of the camera
+    ///
+    /// This is the same form as the orientation returned by [`look_at`](crate::look_at).
+    pub fn orientation(&self) -> [[T; 3]; 3] {
+        [self.right(), self.up(), self.forward()]
+    }
+    /// Get the rotation and translation that take world space to view space
+    ///
+    /// A point `p` is in view space at `rotate_by(rotation, p) + translation`. The rotation
+    /// is given as the rotated X, Y, and Z axes. In view space, the camera is at the
+    /// origin looking along negative Z, with positive Y up.
+    pub fn view(&self) -> ([[T; 3]; 3], [T; 3]) {
+        let back = self.offset_dir();
+        let axes = [self.right(), self.up(), back];
+        let eye = self.eye();
+        (invert_rotation(axes), axes.map(|axis| -axis.dot(eye)))
+    }
+    /// Rotate the camera around the target
+    ///
+    /// The pitch is kept just short of straight up or down so the view does not flip.
+    pub fn orbit(&mut self, yaw: T, pitch: T) {
+        let limit = T::PI / T::TWO * T::from_f64(0.999);
+        self.yaw += yaw;
+        self.pitch = (self.pitch + pitch).maxx(-limit).minn(limit);
+    }
+    /// Move the target and camera together within the view plane
+    ///
+    /// The offset is in world units along the view's right and up directions.
+    pub fn pan(&mut self, offset: [T; 2]) {
+        let shift = self.right().mul(offset[0]).add(self.up().mul(offset[1]));
+        self.target = self.target.add(shift);
+    }
+    /// Scale the distance to the target
+    ///
+    /// Factors less than `1` move the camera closer.
+    pub fn zoom(&mut self, factor: T) {
+        self.distance *= factor;
+    }
+    /// Scale the distance to the target while keeping a screen point fixed in the view
+    ///
+    /// This is useful for zooming toward the cursor. The screen point is in normalized
+    /// device coordinates, as for [`screen_ray`], and is unprojected onto the plane through
+    /// the target that faces the camera. `fov_y` and `aspect` are the same as for
+    /// [`fit_sphere_distance`]. Factors less than `1` move the camera closer.
+    pub fn zoom_toward(&mut self, screen: [T; 2], fov_y: T, aspect: T, factor: T) {
+        let ray = screen_ray(self.eye(), self.orientation(), fov_y, aspect, screen);
+        let point = ray.at(self.distance);
+        self.target = point.add(self.target.sub(point).mul(factor));
+        self.distance *= factor;
+    }
+}
+
+/// Get the corners of an aabb relative to its center in the basis of a view
+///
+/// The view's up is as close to the Y axis as it can be.
+fn view_corners<T, A>(aabb: &A, direction: [T; 3]) -> [[T; 3]; 8]
+where
+    T: FloatingScalar,
+    A: Aabb<Vector = [T; 3]>,
+{
+    let [right, up, forward] = basis(direction.unit(), [T::ZERO, T::ONE, T::ZERO]);
+    let center = aabb.center();
+    std::array::from_fn(|i| {
+        let corner: [T; 3] = std::array::from_fn(|d| {
+            if i >> d & 1 == 0 {
+                aabb.origin_dim(d)
+            } else {
+                aabb.end_dim(d)
+            }
+        });
+        let offset = corner.sub(center);
+        [offset.dot(right), offset.dot(up), offset.dot(forward)]
+    })
+}
+
+/// Get the distance from a sphere's center a perspective camera must be to frame it
+///
+/// `fov_y` is the vertical field of view in radians, and `aspect` is the width of the
+/// view divided by its height. `margin` scales the space around the sphere, so `1` fits
+/// it exactly and `1.1` leaves some room around it.
+pub fn fit_sphere_distance<T>(radius: T, fov_y: T, aspect: T, margin: T) -> T
+where
+    T: FloatingScalar,
+{
+    let tan = (fov_y / T::TWO).tan();
+    let tan = tan.minn(tan * aspect);
+    radius * margin * (T::ONE + tan * tan).sqrt() / tan
+}
+
+/// Get the distance from an aabb's center a perspective camera looking along a direction
+/// must be to frame it
+///
+/// The Y axis is up. The parameters are the same as for [`fit_sphere_distance`].
+pub fn fit_aabb_distance<T, A>(aabb: A, direction: [T; 3], fov_y: T, aspect: T, margin: T) -> T
+where
+    T: FloatingScalar,
+    A: Aabb<Vector = [T; 3]>,
+{
+    let tan_y = (fov_y / T::TWO).tan();
+    let tan_x = tan_y * aspect;
+    view_corners(&aabb, direction)
+        .into_iter()
+        .fold(T::ZERO, |acc, [x, y, z]| {
+            let needed = (x.abs() * margin / tan_x).maxx(y.abs() * margin / tan_y) - z;
+            acc.maxx(needed)
+        })
+}
+
+/// Get the half width and half height of an orthographic view that frames a sphere
+///
+/// The parameters are the same as for [`fit_sphere_distance`].
+pub fn fit_sphere_ortho<T>(radius: T, aspect: T, margin: T) -> [T; 2]
+where
+    T: FloatingScalar,
+{
+    fit_ortho(radius * margin, radius * margin, aspect)
+}
+
+/// Get the half width and half height of an orthographic view looking along a direction
+/// that frames an aabb
+///
+/// The Y axis is up. The parameters are the same as for [`fit_sphere_distance`].
+pub fn fit_aabb_ortho<T, A>(aabb: A, direction: [T; 3], aspect: T, margin: T) -> [T; 2]
+where
+    T: FloatingScalar,
+    A: Aabb<Vector = [T; 3]>,
+{
+    let [half_width, half_height] = view_corners(&aabb, direction)
+        .into_iter()
+        .fold([T::ZERO; 2], |acc, [x, y, _]| acc.max2([x.abs(), y.abs()]));
+    fit_ortho(half_width * margin, half_height * margin, aspect)
+}
+
+/// Grow the half extents of a view to match an aspect ratio
+fn fit_ortho<T>(half_width: T, half_height: T, aspect: T) -> [T; 2]
+where
+    T: FloatingScalar,
+{
+    if half_width > half_height * aspect {
+        [half_width, half_width / aspect]
+    } else {
+        [half_height * aspect, half_height]
+    }
+}
+
+/// Get the orientation of a quad at a position that faces a camera
+///
+/// The result is the quad's right, up, and normal vectors, which are the columns of its
+/// rotation matrix. The Y axis is up. The normal points toward the camera.
+pub fn billboard<T>(position: [T; 3], camera: [T; 3]) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    let [right, up, back] = basis(position.sub(camera).unit(), [T::ZERO, T::ONE, T::ZERO]);
+    [right, up, back.mul(-T::ONE)]
+}
+
+/// Get the orientation of a quad at a position that turns around an axis to face a camera
+///
+/// The result is the quad's right, up, and normal vectors, which are the columns of its
+/// rotation matrix. The up vector is the unit axis, and the normal points as close to
+/// the camera as it can while staying perpendicular to it.
+pub fn billboard_around<T>(position: [T; 3], camera: [T; 3], axis: [T; 3]) -> [[T; 3]; 3]
+where
+    T: FloatingScalar,
+{
+    let up = axis.unit();
+    let normal = camera.sub(position).reject_from(up);
+    let normal = if normal.squared_mag().is_zero() {
+        // Cameras on the axis see the quad edge-on from any angle, so pick one
+        let side = if up[0].abs() < T::ONE / T::TWO {
+            [T::ONE, T::ZERO, T::ZERO]
+        } else {
+            [T::ZERO, T::ZERO, T::ONE]
+        };
+        side.reject_from(up).unit()
+    } else {
+        normal.unit()
+    };
+    [up.cross(normal), up, normal]
+}
+
+/// Get the view-space depth of a perspective depth buffer value
+///
+/// The depth buffer value is in the `[0, 1]` range used by Direct3D, Metal, Vulkan, and
+/// wgpu, with `0` at the near plane and `1` at the far plane. The result is the distance
+/// in front of the camera along its forward direction. `far` may be infinite.
+pub fn linearize_depth<T>(depth: T, near: T, far: T) -> T
+where
+    T: FloatingScalar,
+{
+    near / (T::ONE - depth * (T::ONE - near / far))
+}
+
+/// Get the perspective depth buffer value of a view-space depth
+///
+/// This is the inverse of [`linearize_depth`].
+pub fn delinearize_depth<T>(linear: T, near: T, far: T) -> T
+where
+    T: FloatingScalar,
+{
+    (T::ONE - near / linear) / (T::ONE - near / far)
+}
+
+/// Get the view-space depth of a reversed-Z perspective depth buffer value
+///
+/// This is like [`linearize_depth`], but `1` is at the near plane and `0` is at the far
+/// plane, which spreads floating point precision more evenly over the depth range.
+pub fn linearize_reversed_depth<T>(depth: T, near: T, far: T) -> T
+where
+    T: FloatingScalar,
+{
+    near / (depth + (T::ONE - depth) * near / far)
+}
+
+/// Get the reversed-Z perspective depth buffer value of a view-space depth
+///
+/// This is the inverse of [`linearize_reversed_depth`].
+pub fn delinearize_reversed_depth<T>(linear: T, near: T, far: T) -> T
+where
+    T: FloatingScalar,
+{
+    (near / linear - near / far) / (T::ONE - near / far)
+}
+
+/// Get the world-space corners of a perspective camera's view frustum
+///
+/// `orientation` is the camera's right, up, and forward vectors, as returned by
+/// [`look_at`](crate::look_at). `fov_y` and `aspect` are the same as for
+/// [`fit_sphere_distance`]. Bit 0 of a corner's index picks the right side, bit 1 the top,
+/// and bit 2 the far plane, so the first four corners are on the near plane.
+pub fn frustum_corners<T>(
+    eye: [T; 3],
+    orientation: [[T; 3]; 3],
+    fov_y: T,
+    aspect: T,
+    near: T,
+    far: T,
+) -> [[T; 3]; 8]
+where
+    T: FloatingScalar,
+{
+    let [right, up, forward] = orientation;
+    let tan_y = (fov_y / T::TWO).tan();
+    let tan_x = tan_y * aspect;
+    std::array::from_fn(|i| {
+        let depth = if i & 4 == 0 { near } else { far };
+        let side = if i & 1 == 0 { -T::ONE } else { T::ONE };
+        let height = if i & 2 == 0 { -T::ONE } else { T::ONE };
+        eye.add(forward.mul(depth))
+            .add(right.mul(side * tan_x * depth))
+            .add(up.mul(height * tan_y * depth))
+    })
+}
+
+/// Get the depths that split a view into cascades for shadow mapping
+///
+/// This uses the practical split scheme, which blends between logarithmic and uniform
+/// splits. A `lambda` of `1` gives logarithmic splits, which keep shadow resolution even
+/// in view, and `0` gives uniform splits. Around `0.5` to `0.9` is typical.
+///
+/// The result has `count + 1` depths, starting at `near` and ending at `far`. Pass each
+/// adjacent pair to [`frustum_corners`] and take their [`bounding`](Aabb::bounding) aabb to
+/// get a cascade's bounds.
+pub fn cascade_splits<T>(near: T, far: T, count: usize, lambda: T) -> Vec<T>
+where
+    T: FloatingScalar,
+{
+    (0..=count)
+        .map(|i| {
+            if i == 0 {
+                return near;
+            } else if i == count {
+                return far;
+            }
+            let t = T::from_f64(i as f64 / count as f64);
+            let uniform = near.lerp(far, t);
+            let log = near * (far / near).powf(t);
+            uniform.lerp(log, lambda)
+        })
+        .collect()
+}
+
+/// Get the ray from a perspective camera through a point on the screen
+///
+/// The parameters are the same as for [`frustum_corners`], and the screen point is in
+/// normalized device coordinates, as for [`screen_aabb`]. The ray starts at the eye, and
+/// its direction is scaled so that `ray.at(depth)` is the point at that distance in front
+/// of the camera.
+pub fn screen_ray<T>(
+    eye: [T; 3],
+    orientation: [[T; 3]; 3],
+    fov_y: T,
+    aspect: T,
+    screen: [T; 2],
+) -> Ray<[T; 3]>
+where
+    T: FloatingScalar,
+{
+    let [right, up, forward] = orientation;
+    let tan_y = (fov_y / T::TWO).tan();
+    let tan_x = tan_y * aspect;
+    let dir = forward
+        .add(right.mul(screen[0] * tan_x))
+        .add(up.mul(screen[1] * tan_y));
+    Ray::new(eye, dir)
+}
+
+/// Get the screen-space bounds of an aabb seen by a perspective camera
+///
+/// The parameters are the same as for [`frustum_corners`]. The result is in normalized
+/// device coordinates, where `-1` and `1` are the edges of the view and Y is up. It may
+/// reach past the edges if the aabb does. Parts of the aabb closer than the near plane are
+/// clipped away. Returns `None` if all of the aabb is closer than the near plane.
+pub fn screen_aabb<T, A>(
+    aabb: A,
+    eye: [T; 3],
+    orientation: [[T; 3]; 3],
+    fov_y: T,
+    aspect: T,
+    near: T,
+) -> Option<[[T; 2]; 2]>
+where
+    T: FloatingScalar,
+    A: Aabb<Vector = [T; 3]>,
+{
+    let [right, up, forward] = orientation;
+    let tan_y = (fov_y / T::TWO).tan();
+    let tan_x = tan_y * aspect;
+    let (min, max) = corners(&aabb);
+    let view: [[T; 3]; 8] = std::array::from_fn(|i| {
+        let corner: [T; 3] = std::array::from_fn(|d| if i >> d & 1 == 0 { min[d] } else { max[d] });
+        let offset = corner.sub(eye);
+        [offset.dot(right), offset.dot(up), offset.dot(forward)]
+    });
+    let in_front = view.into_iter().filter(|v| v[2] >= near);
+    // Where the edges of the aabb cross the near plane
+    let crossings = (0..8)
+        .flat_map(|i| (0..3).map(move |d| (i, i | 1 << d)))
+        .filter(|&(i, j)| i != j)
+        .filter_map(|(i, j)| {
+            let (a, b) = (view[i], view[j]);
+            if (a[2] < near) == (b[2] < near) {
+                return None;
+            }
+            Some(a.lerp(b, (near - a[2]) / (b[2] - a[2])))
+        });
+    Aabb::bounding(
+        in_front
+            .chain(crossings)
+            .map(|[x, y, z]| [x / (z * tan_x), y / (z * tan_y)]),
+    )
+}
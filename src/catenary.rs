@@ -0,0 +1,157 @@
+use crate::{FloatingScalar, FloatingVecN, Scalar, VecN};
+
+/// A catenary in its vertical plane, starting at the origin
+///
+/// Distances are horizontal along `x` and upward along `y`.
+#[derive(Debug, Clone, Copy)]
+struct Curve {
+    /// The catenary parameter, which is the radius of curvature at the lowest point
+    c: f64,
+    /// The horizontal position of the lowest point
+    x0: f64,
+}
+
+impl Curve {
+    /// Find the catenary with a length that ends at `[d, v]`, if it is slack
+    fn new(d: f64, v: f64, length: f64) -> Option<Self> {
+        let taut = (d * d + v * v).sqrt();
+        if d <= f64::EPSILON * taut.max(1.0) || length <= taut {
+            return None;
+        }
+        // Solve sinh(r) / r = k for r = d / 2c
+        let k = (length * length - v * v).sqrt() / d;
+        let f = |r: f64| r.sinh() / r - k;
+        let mut hi = 1.0;
+        while f(hi) < 0.0 {
+            hi *= 2.0;
+        }
+        let mut lo = 0.0;
+        for _ in 0..100 {
+            let mid = (lo + hi) / 2.0;
+            if f(mid) < 0.0 {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let c = d / (2.0 * (lo + hi) / 2.0);
+        let x0 = d / 2.0 - c * (v / length).atanh();
+        Some(Curve { c, x0 })
+    }
+    fn y(&self, x: f64) -> f64 {
+        self.c * (((x - self.x0) / self.c).cosh() - (self.x0 / self.c).cosh())
+    }
+    /// Get the horizontal position that is an arc length along the curve
+    fn x_at_length(&self, s: f64) -> f64 {
+        self.x0 + self.c * (s / self.c - (self.x0 / self.c).sinh()).asinh()
+    }
+}
+
+/// The directions and distances from one anchor to another
+struct Frame<V> {
+    horizontal: V,
+    up: V,
+    distance: f64,
+    rise: f64,
+}
+
+/// Get the directions and distances from one anchor to another
+fn frame<V>(a: V, b: V, down: V) -> Frame<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let up = down.unit().mul(-V::Scalar::ONE);
+    let delta = b.sub(a);
+    let rise = delta.dot(up);
+    let horizontal = delta.sub(up.mul(rise));
+    Frame {
+        horizontal: horizontal.unit(),
+        up,
+        distance: horizontal.mag().to_f64(),
+        rise: rise.to_f64(),
+    }
+}
+
+/// Sample points evenly along a curve from one anchor to another
+fn sample<V>(
+    a: V,
+    b: V,
+    frame: &Frame<V>,
+    curve: Option<Curve>,
+    length: f64,
+    samples: usize,
+) -> Vec<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let last = samples.saturating_sub(1).max(1) as f64;
+    (0..samples)
+        .map(|i| {
+            let t = i as f64 / last;
+            match curve {
+                Some(curve) => {
+                    let x = curve.x_at_length(t * length);
+                    let y = curve.y(x);
+                    let (x, y) = (V::Scalar::from_f64(x), V::Scalar::from_f64(y));
+                    a.add(frame.horizontal.mul(x)).add(frame.up.mul(y))
+                }
+                None => a.lerp(b, V::Scalar::from_f64(t)),
+            }
+        })
+        .collect()
+}
+
+/// Get points along a rope of a length hanging between two anchors
+///
+/// `down` is the direction of gravity. The points are spaced evenly along the rope, and
+/// the first and last are the anchors. If the rope is too short to sag, or one anchor is
+/// directly above the other, the points lie on the line between the anchors.
+pub fn catenary<V>(a: V, b: V, length: V::Scalar, down: V, samples: usize) -> Vec<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let frame = frame(a, b, down);
+    let length = length.to_f64();
+    let curve = Curve::new(frame.distance, frame.rise, length);
+    sample(a, b, &frame, curve, length, samples)
+}
+
+/// Get points along a rope hanging between two anchors with a sag
+///
+/// The sag is how far below the middle of the line between the anchors the rope hangs.
+/// `down` is the direction of gravity. The points are spaced evenly along the rope, and
+/// the first and last are the anchors. If the sag is not positive, or one anchor is
+/// directly above the other, the points lie on the line between the anchors.
+pub fn catenary_with_sag<V>(a: V, b: V, sag: V::Scalar, down: V, samples: usize) -> Vec<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let frame = frame(a, b, down);
+    let (d, v) = (frame.distance, frame.rise);
+    let sag = sag.to_f64();
+    let taut = (d * d + v * v).sqrt();
+    let sag_of = |length: f64| Curve::new(d, v, length).map_or(0.0, |c| v / 2.0 - c.y(d / 2.0));
+    if sag <= 0.0 || Curve::new(d, v, taut * 2.0).is_none() {
+        return sample(a, b, &frame, None, taut, samples);
+    }
+    // The sag grows with the length, so search for the length that gives the sag
+    let mut hi = taut * 2.0;
+    while sag_of(hi) < sag {
+        hi *= 2.0;
+    }
+    let mut lo = taut;
+    for _ in 0..100 {
+        let mid = (lo + hi) / 2.0;
+        if sag_of(mid) < sag {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    let length = (lo + hi) / 2.0;
+    sample(a, b, &frame, Curve::new(d, v, length), length, samples)
+}
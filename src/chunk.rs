@@ -0,0 +1,42 @@
+use crate::{IntegerScalar, VecN};
+
+/// Split a world position into the coordinates of its chunk and its position within it
+///
+/// Chunks are `size` cells across and chunk `[0; N]` starts at the world origin.
+/// Negative positions belong to negative chunks, so local positions are always in
+/// `0..size` for each dimension.
+///
+/// # Panics
+///
+/// Panics if any dimension of the size is not positive.
+pub fn world_to_chunk<T, const N: usize>(pos: [T; N], size: [T; N]) -> ([T; N], [T; N])
+where
+    T: IntegerScalar,
+{
+    assert!(
+        size.iter().all(|&s| s > T::ZERO),
+        "chunk size must be positive"
+    );
+    (
+        pos.zip_with(size, T::div_euclid),
+        pos.zip_with(size, T::rem_euclid),
+    )
+}
+
+/// Get the world position of a position within a chunk
+///
+/// This is the inverse of [`world_to_chunk`].
+pub fn chunk_to_world<T, const N: usize>(chunk: [T; N], local: [T; N], size: [T; N]) -> [T; N]
+where
+    T: IntegerScalar,
+{
+    chunk.mul2(size).add(local)
+}
+
+/// Get the aabb of the world cells in a chunk as an origin and a size
+pub fn chunk_aabb<T, const N: usize>(chunk: [T; N], size: [T; N]) -> [[T; N]; 2]
+where
+    T: IntegerScalar,
+{
+    [chunk_to_world(chunk, [T::ZERO; N], size), size]
+}
@@ -0,0 +1,193 @@
+use crate::{AabbTree, FloatingScalar, Scalar, VecN};
+
+/// The result of [`kmeans`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct KMeans<V> {
+    /// The center of each cluster
+    pub centroids: Vec<V>,
+    /// The index of the cluster that each point belongs to
+    pub assignments: Vec<usize>,
+    /// The number of times the centroids were moved
+    pub iterations: usize,
+}
+
+/// Get the index of the centroid nearest to a point and the distance to it
+fn nearest<V, F>(point: V, centroids: &[V], metric: &F) -> (usize, V::Scalar)
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+    F: Fn(V, V) -> V::Scalar,
+{
+    centroids
+        .iter()
+        .map(|&c| metric(point, c))
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).expect("distance comparison failed"))
+        .expect("there are no centroids")
+}
+
+/// Cluster points with Lloyd's k-means algorithm, starting from initial centroids
+///
+/// `metric` gives the distance between two points. Each centroid is moved to the mean of
+/// its points, which minimizes squared distance, so [`VecN::squared_dist`] gives standard
+/// k-means. Iteration stops when no point changes cluster or after `max_iterations`.
+/// Centroids with no points are left where they are.
+///
+/// # Panics
+///
+/// Panics if there are points but no centroids.
+///
+/// The initial centroids can be chosen with [`kmeans_plus_plus`].
+pub fn kmeans<V, F>(
+    points: &[V],
+    mut centroids: Vec<V>,
+    max_iterations: usize,
+    metric: F,
+) -> KMeans<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+    F: Fn(V, V) -> V::Scalar,
+{
+    let mut assignments = vec![usize::MAX; points.len()];
+    let mut iterations = 0;
+    loop {
+        let mut changed = false;
+        for (point, assignment) in points.iter().zip(&mut assignments) {
+            let (i, _) = nearest(*point, &centroids, &metric);
+            changed |= *assignment != i;
+            *assignment = i;
+        }
+        if !changed || iterations == max_iterations {
+            break;
+        }
+        let mut sums = vec![V::ZERO; centroids.len()];
+        let mut counts = vec![0usize; centroids.len()];
+        for (point, &i) in points.iter().zip(&assignments) {
+            sums[i] = sums[i].add(*point);
+            counts[i] += 1;
+        }
+        for ((centroid, sum), count) in centroids.iter_mut().zip(sums).zip(counts) {
+            if count > 0 {
+                *centroid = sum.div(V::Scalar::from_f64(count as f64));
+            }
+        }
+        iterations += 1;
+    }
+    KMeans {
+        centroids,
+        assignments,
+        iterations,
+    }
+}
+
+/// Choose initial centroids for [`kmeans`] with the k-means++ algorithm
+///
+/// Each centroid after the first random one is a point chosen with probability
+/// proportional to its distance to the nearest centroid chosen so far, so
+/// [`VecN::squared_dist`] gives the standard algorithm. `random` should return uniformly
+/// distributed numbers in `[0, 1)`.
+///
+/// Returns `k` centroids, or fewer if there are fewer than `k` points.
+pub fn kmeans_plus_plus<V, F, R>(points: &[V], k: usize, metric: F, mut random: R) -> Vec<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+    F: Fn(V, V) -> V::Scalar,
+    R: FnMut() -> V::Scalar,
+{
+    let k = k.min(points.len());
+    if k == 0 {
+        return Vec::new();
+    }
+    let n = points.len();
+    let pick = |r: V::Scalar| ((r.to_f64() * n as f64) as usize).min(n - 1);
+    let mut centroids = vec![points[pick(random())]];
+    let mut distances: Vec<V::Scalar> = points.iter().map(|&p| metric(p, centroids[0])).collect();
+    while centroids.len() < k {
+        let total = distances.iter().fold(V::Scalar::ZERO, |acc, &d| acc + d);
+        let index = if total > V::Scalar::ZERO {
+            // Walk the cumulative distances until they pass a random fraction of the total
+            let mut target = random() * total;
+            distances
+                .iter()
+                .position(|&d| {
+                    target -= d;
+                    target < V::Scalar::ZERO
+                })
+                .unwrap_or_else(|| {
+                    distances
+                        .iter()
+                        .rposition(|&d| d > V::Scalar::ZERO)
+                        .expect("some distance is positive")
+                })
+        } else {
+            pick(random())
+        };
+        let centroid = points[index];
+        centroids.push(centroid);
+        for (d, &p) in distances.iter_mut().zip(points) {
+            *d = d.minn(metric(p, centroid));
+        }
+    }
+    centroids
+}
+
+/// Cluster points by density with the DBSCAN algorithm
+///
+/// A point is a core point if at least `min_points` points, including itself, are within
+/// `radius` of it. Clusters are made of core points within `radius` of each other and the
+/// other points within `radius` of them. Neighbors are found with an [`AabbTree`].
+///
+/// Returns the index of the cluster that each point belongs to, or `None` for noise.
+/// Clusters are numbered in the order of their first point.
+pub fn dbscan<T, const N: usize>(
+    points: &[[T; N]],
+    radius: T,
+    min_points: usize,
+) -> Vec<Option<usize>>
+where
+    T: FloatingScalar,
+{
+    // Tree ids are not point indices, so map them back
+    let mut tree = AabbTree::new(T::ZERO);
+    let mut indices = vec![0; 2 * points.len()];
+    for (i, &point) in points.iter().enumerate() {
+        indices[tree.insert([point, [T::ZERO; N]])] = i;
+    }
+    let neighbors = |point: [T; N]| -> Vec<usize> {
+        let ids = tree.query_aabb([point.sub([radius; N]), [radius + radius; N]]);
+        (ids.into_iter().map(|id| indices[id]))
+            .filter(|&i| points[i].squared_dist(point) <= radius * radius)
+            .collect()
+    };
+    let mut labels = vec![None; points.len()];
+    let mut visited = vec![false; points.len()];
+    let mut clusters = 0;
+    for start in 0..points.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut queue = neighbors(points[start]);
+        if queue.len() < min_points {
+            continue;
+        }
+        labels[start] = Some(clusters);
+        while let Some(i) = queue.pop() {
+            if labels[i].is_none() {
+                labels[i] = Some(clusters);
+            }
+            if visited[i] {
+                continue;
+            }
+            visited[i] = true;
+            let found = neighbors(points[i]);
+            if found.len() >= min_points {
+                queue.extend(found);
+            }
+        }
+        clusters += 1;
+    }
+    labels
+}
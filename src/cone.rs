@@ -0,0 +1,85 @@
+use crate::{steer::perpendicular, FloatingScalar, FloatingVecN, Vec3, VecN};
+
+/// A cone of directions given by a unit axis and the cosine of its half angle
+#[derive(Clone, Copy)]
+struct Cap<T> {
+    axis: [T; 3],
+    cos: T,
+}
+
+impl<T> Cap<T>
+where
+    T: FloatingScalar,
+{
+    fn contains(&self, dir: [T; 3]) -> bool {
+        self.axis.dot(dir) >= self.cos - T::EPSILON * T::from_f64(64.0)
+    }
+    fn one(a: [T; 3]) -> Self {
+        Cap {
+            axis: a,
+            cos: T::ONE,
+        }
+    }
+    fn two(a: [T; 3], b: [T; 3]) -> Self {
+        let axis = a.add(b).unit_or(perpendicular(a).unit());
+        Cap {
+            axis,
+            cos: axis.dot(a),
+        }
+    }
+    fn three(a: [T; 3], b: [T; 3], c: [T; 3]) -> Self {
+        // The circle through three directions lies in the plane through them
+        let Some(mut axis) = b.sub(a).cross(c.sub(a)).try_unit() else {
+            // Repeated directions give no plane, so use the widest pair instead
+            return [Cap::two(a, b), Cap::two(b, c), Cap::two(a, c)]
+                .into_iter()
+                .min_by(|x, y| x.cos.partial_cmp(&y.cos).expect("cone comparison failed"))
+                .expect("there are pairs");
+        };
+        if axis.dot(a) < T::ZERO {
+            axis = axis.mul(-T::ONE);
+        }
+        Cap {
+            axis,
+            cos: axis.dot(a),
+        }
+    }
+}
+
+/// Get the narrowest cone around the origin that contains a set of directions
+///
+/// Returns the unit axis and half angle in radians of the cone, or `None` if there are
+/// no nonzero directions. The cone is exact when the directions fit within a hemisphere.
+/// Otherwise, it is a wider cone around their average direction that still contains them.
+pub fn bounding_cone<T>(directions: &[[T; 3]]) -> Option<([T; 3], T)>
+where
+    T: FloatingScalar,
+{
+    let dirs: Vec<[T; 3]> = directions.iter().filter_map(|d| d.try_unit()).collect();
+    let mut cap = Cap::one(*dirs.first()?);
+    for i in 1..dirs.len() {
+        if cap.contains(dirs[i]) {
+            continue;
+        }
+        cap = Cap::one(dirs[i]);
+        for j in 0..i {
+            if cap.contains(dirs[j]) {
+                continue;
+            }
+            cap = Cap::two(dirs[i], dirs[j]);
+            for k in 0..j {
+                if !cap.contains(dirs[k]) {
+                    cap = Cap::three(dirs[i], dirs[j], dirs[k]);
+                }
+            }
+        }
+    }
+    if !dirs.iter().all(|&d| cap.contains(d)) {
+        let sum = dirs.iter().fold([T::ZERO; 3], |acc, &d| acc.add(d));
+        let axis = sum.unit_or(dirs[0]);
+        let cos = dirs.iter().fold(T::ONE, |acc, &d| acc.minn(axis.dot(d)));
+        cap = Cap { axis, cos };
+    }
+    let half_angle = cap.cos.maxx(-T::ONE).minn(T::ONE).acos();
+    Some((cap.axis, half_angle))
+}
@@ -0,0 +1,394 @@
+use crate::{aabb::corners, Aabb, FloatingScalar, FloatingVecN, Obb, Scalar, VecN};
+
+/// The set of contacts between two overlapping shapes
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContactManifold<V>
+where
+    V: VecN,
+{
+    /// The unit normal of the contact, pointing from the first shape to the second
+    ///
+    /// Moving the second shape along the normal by the depth separates the shapes.
+    pub normal: V,
+    /// How far the shapes overlap along the normal
+    pub depth: V::Scalar,
+    /// The contact points
+    pub points: Vec<V>,
+}
+
+/// Get the unit vector along an axis in a direction
+fn axis_normal<V>(axis: usize, positive: bool) -> V
+where
+    V: VecN,
+    V::Scalar: FloatingScalar,
+{
+    let mut normal = V::ZERO;
+    normal.set_dim(
+        axis,
+        if positive {
+            V::Scalar::ONE
+        } else {
+            -V::Scalar::ONE
+        },
+    );
+    normal
+}
+
+/// Get the contact manifold between two aabbs
+///
+/// The normal is along the axis of least overlap. The contact points are the corners
+/// of the overlapping region's face that lies on the surface of `b`, so there are 2 in 2D
+/// and 4 in 3D. Returns `None` if the aabbs do not overlap.
+pub fn aabb_contact<A>(a: A, b: A) -> Option<ContactManifold<A::Vector>>
+where
+    A: Aabb,
+    A::Vector: Copy,
+    <A::Vector as VecN>::Scalar: FloatingScalar,
+{
+    let n = A::Vector::N;
+    let (a_min, a_max) = corners(&a);
+    let (b_min, b_max) = corners(&b);
+    let lo = a_min.max2(b_min);
+    let hi = a_max.min2(b_max);
+    if (0..n).any(|i| lo.dim(i) > hi.dim(i)) {
+        return None;
+    }
+    let axis = (0..n)
+        .min_by(|&i, &j| {
+            let [di, dj] = [i, j].map(|k| hi.dim(k) - lo.dim(k));
+            di.partial_cmp(&dj).expect("overlap comparison failed")
+        })
+        .expect("vectors have dimensions");
+    let depth = hi.dim(axis) - lo.dim(axis);
+    // The normal points toward the side of b's center
+    let positive = b_min.dim(axis) + b_max.dim(axis) >= a_min.dim(axis) + a_max.dim(axis);
+    let face = if positive { lo.dim(axis) } else { hi.dim(axis) };
+    let others: Vec<usize> = (0..n).filter(|&i| i != axis).collect();
+    let points = (0..1usize << others.len())
+        .map(|bits| {
+            let mut point = lo;
+            point.set_dim(axis, face);
+            for (k, &i) in others.iter().enumerate() {
+                if bits & (1 << k) != 0 {
+                    point.set_dim(i, hi.dim(i));
+                }
+            }
+            point
+        })
+        .collect();
+    Some(ContactManifold {
+        normal: axis_normal(axis, positive),
+        depth,
+        points,
+    })
+}
+
+/// Get the contact manifold between a sphere and an aabb
+///
+/// The manifold has a single contact point on the surface of the aabb.
+/// Returns `None` if the shapes do not overlap.
+pub fn sphere_aabb_contact<A>(
+    center: A::Vector,
+    radius: <A::Vector as VecN>::Scalar,
+    aabb: A,
+) -> Option<ContactManifold<A::Vector>>
+where
+    A: Aabb,
+    A::Vector: Copy,
+    <A::Vector as VecN>::Scalar: FloatingScalar,
+{
+    let (min, max) = corners(&aabb);
+    let closest = center.clamp2(min, max);
+    let offset = closest.sub(center);
+    let dist = offset.mag();
+    if !dist.is_zero() {
+        if dist > radius {
+            return None;
+        }
+        return Some(ContactManifold {
+            normal: offset.div(dist),
+            depth: radius - dist,
+            points: vec![closest],
+        });
+    }
+    // The center is inside the aabb, so the sphere is pushed out through the nearest face
+    let (axis, to_face, positive) = (0..A::Vector::N)
+        .flat_map(|i| {
+            [
+                (i, center.dim(i) - min.dim(i), false),
+                (i, max.dim(i) - center.dim(i), true),
+            ]
+        })
+        .min_by(|a, b| a.1.partial_cmp(&b.1).expect("distance comparison failed"))
+        .expect("vectors have dimensions");
+    let mut point = center;
+    point.set_dim(
+        axis,
+        if positive {
+            max.dim(axis)
+        } else {
+            min.dim(axis)
+        },
+    );
+    Some(ContactManifold {
+        normal: axis_normal(axis, !positive),
+        depth: radius + to_face,
+        points: vec![point],
+    })
+}
+
+/// How much less an edge axis must overlap than a face axis to be chosen as the normal
+///
+/// Face contacts give better manifolds, so they win near-ties.
+const EDGE_BIAS: f64 = 0.95;
+
+/// Cross two 3D vectors stored as N-dimensional arrays
+fn cross_3d<T, const N: usize>(u: [T; N], v: [T; N]) -> [T; N]
+where
+    T: Scalar,
+{
+    std::array::from_fn(|k| u[(k + 1) % 3] * v[(k + 2) % 3] - u[(k + 2) % 3] * v[(k + 1) % 3])
+}
+
+/// Get the radius of a box projected onto an axis
+fn projected_radius<T, const N: usize>(obb: &Obb<T, N>, axis: [T; N]) -> T
+where
+    T: FloatingScalar,
+{
+    (0..N).fold(T::ZERO, |acc, i| {
+        acc + obb.half_extents[i] * obb.axes[i].dot(axis).abs()
+    })
+}
+
+/// Get the vertices of a box's face in order around the face
+fn face_vertices<T, const N: usize>(obb: &Obb<T, N>, axis: usize, positive: bool) -> Vec<[T; N]>
+where
+    T: FloatingScalar,
+{
+    let sign = if positive { T::ONE } else { -T::ONE };
+    let center = obb
+        .center
+        .add(obb.axes[axis].mul(sign * obb.half_extents[axis]));
+    let others: Vec<[T; N]> = (0..N)
+        .filter(|&i| i != axis)
+        .map(|i| obb.axes[i].mul(obb.half_extents[i]))
+        .collect();
+    let signs: &[[f64; 2]] = if N == 2 {
+        &[[1.0, 0.0], [-1.0, 0.0]]
+    } else {
+        &[[1.0, 1.0], [-1.0, 1.0], [-1.0, -1.0], [1.0, -1.0]]
+    };
+    signs
+        .iter()
+        .map(|s| {
+            others.iter().zip(s).fold(center, |acc, (&offset, &s)| {
+                acc.add(offset.mul(T::from_f64(s)))
+            })
+        })
+        .collect()
+}
+
+/// Clip a polygon, or an open polyline, to the side of a plane where `normal · p <= offset`
+fn clip<T, const N: usize>(
+    points: &[[T; N]],
+    normal: [T; N],
+    offset: T,
+    closed: bool,
+) -> Vec<[T; N]>
+where
+    T: FloatingScalar,
+{
+    let dist = |p: [T; N]| normal.dot(p) - offset;
+    let mut clipped = Vec::new();
+    if points.is_empty() {
+        return clipped;
+    }
+    if !closed && dist(points[0]) <= T::ZERO {
+        clipped.push(points[0]);
+    }
+    let edges = if closed {
+        points.len()
+    } else {
+        points.len() - 1
+    };
+    for i in 0..edges {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        let (da, db) = (dist(a), dist(b));
+        if (da <= T::ZERO) != (db <= T::ZERO) {
+            clipped.push(a.lerp(b, da / (da - db)));
+        }
+        if db <= T::ZERO {
+            clipped.push(b);
+        }
+    }
+    clipped
+}
+
+/// Reduce a set of coplanar 3D contact points to at most 4 that span the most area
+fn reduce_points<T, const N: usize>(
+    points: Vec<[T; N]>,
+    normal: [T; N],
+    depth: impl Fn([T; N]) -> T,
+) -> Vec<[T; N]>
+where
+    T: FloatingScalar,
+{
+    if points.len() <= 4 {
+        return points;
+    }
+    let best = |score: &dyn Fn([T; N]) -> T| {
+        points
+            .iter()
+            .copied()
+            .max_by(|&a, &b| {
+                score(a)
+                    .partial_cmp(&score(b))
+                    .expect("contact point comparison failed")
+            })
+            .expect("there are contact points")
+    };
+    let first = best(&depth);
+    let second = best(&|p| p.squared_dist(first));
+    let area = |p: [T; N]| cross_3d(second.sub(first), p.sub(first)).dot(normal);
+    let third = best(&area);
+    let fourth = best(&|p| -area(p));
+    vec![first, second, third, fourth]
+}
+
+/// Get the contact manifold between two 2D or 3D oriented boxes
+///
+/// The normal is along the separating axis of least overlap. When that axis is a face
+/// normal, the touching face of the other box is clipped to the first face, giving up to
+/// 2 contact points in 2D and 4 in 3D. When it is the cross product of two edges in 3D,
+/// there is a single contact point. Contact points lie on the surface of `b`.
+/// Returns `None` if the boxes do not overlap.
+///
+/// Using boxes of any other dimension fails to compile.
+pub fn obb_contact<T, const N: usize>(
+    a: &Obb<T, N>,
+    b: &Obb<T, N>,
+) -> Option<ContactManifold<[T; N]>>
+where
+    T: FloatingScalar,
+{
+    const {
+        assert!(
+            N == 2 || N == 3,
+            "obb contacts are only supported in 2D and 3D"
+        )
+    };
+    let offset = b.center.sub(a.center);
+    // Test each separating axis, keeping the one of least overlap
+    let test = |axis: [T; N]| {
+        let overlap =
+            projected_radius(a, axis) + projected_radius(b, axis) - offset.dot(axis).abs();
+        (overlap >= T::ZERO).then_some(overlap)
+    };
+    let mut face: Option<(T, bool, usize)> = None;
+    for (from_a, obb) in [(true, a), (false, b)] {
+        for (i, &axis) in obb.axes.iter().enumerate() {
+            let overlap = test(axis)?;
+            if face.is_none_or(|(best, ..)| overlap < best) {
+                face = Some((overlap, from_a, i));
+            }
+        }
+    }
+    let (face_depth, from_a, face_axis) = face.expect("boxes have axes");
+    let mut edge: Option<(T, [T; N], usize, usize)> = None;
+    if N == 3 {
+        for (i, &u) in a.axes.iter().enumerate() {
+            for (j, &v) in b.axes.iter().enumerate() {
+                let axis = cross_3d(u, v);
+                let len = axis.mag();
+                if len <= T::EPSILON.sqrt() {
+                    continue;
+                }
+                let axis = axis.div(len);
+                let overlap = test(axis)?;
+                if edge.is_none_or(|(best, ..)| overlap < best) {
+                    edge = Some((overlap, axis, i, j));
+                }
+            }
+        }
+    }
+    let orient = |axis: [T; N]| {
+        if offset.dot(axis) < T::ZERO {
+            axis.map(|x| -x)
+        } else {
+            axis
+        }
+    };
+    if let Some((depth, axis, i, j)) = edge {
+        if depth < face_depth * T::from_f64(EDGE_BIAS) {
+            let normal = orient(axis);
+            // Find the edges of each box that are deepest along the normal
+            let support = |obb: &Obb<T, N>, edge_axis: usize, toward: [T; N]| {
+                (0..N)
+                    .filter(|&k| k != edge_axis)
+                    .fold(obb.center, |acc, k| {
+                        let side = if obb.axes[k].dot(toward) < T::ZERO {
+                            -T::ONE
+                        } else {
+                            T::ONE
+                        };
+                        acc.add(obb.axes[k].mul(side * obb.half_extents[k]))
+                    })
+            };
+            let pa = support(a, i, normal);
+            let pb = support(b, j, normal.map(|x| -x));
+            let (u, v) = (a.axes[i], b.axes[j]);
+            // Find the point on b's edge closest to a's edge
+            let w = pa.sub(pb);
+            let uv = u.dot(v);
+            let denom = T::ONE - uv * uv;
+            let s = if denom.is_zero() {
+                T::ZERO
+            } else {
+                (v.dot(w) - uv * u.dot(w)) / denom
+            };
+            let s = s.maxx(-b.half_extents[j]).minn(b.half_extents[j]);
+            return Some(ContactManifold {
+                normal,
+                depth,
+                points: vec![pb.add(v.mul(s))],
+            });
+        }
+    }
+    // Clip the incident face of one box to the reference face of the other
+    let (reference, incident) = if from_a { (a, b) } else { (b, a) };
+    let normal = orient(reference.axes[face_axis]);
+    let outward = if from_a { normal } else { normal.map(|x| -x) };
+    let incident_axis = (0..N)
+        .max_by(|&i, &j| {
+            let [di, dj] = [i, j].map(|k| incident.axes[k].dot(outward).abs());
+            di.partial_cmp(&dj).expect("axis comparison failed")
+        })
+        .expect("boxes have axes");
+    let toward = incident.axes[incident_axis].dot(outward) < T::ZERO;
+    let mut points = face_vertices(incident, incident_axis, toward);
+    for k in (0..N).filter(|&k| k != face_axis) {
+        let axis = reference.axes[k];
+        let center = axis.dot(reference.center);
+        let half = reference.half_extents[k];
+        points = clip(&points, axis, center + half, N == 3);
+        points = clip(&points, axis.map(|x| -x), half - center, N == 3);
+    }
+    let face_offset = outward.dot(reference.center) + reference.half_extents[face_axis];
+    let below = |p: [T; N]| face_offset - outward.dot(p);
+    points.retain(|&p| below(p) >= T::ZERO);
+    let mut points = reduce_points(points, normal, below);
+    if !from_a {
+        // Move points from a's face onto b's reference face
+        for p in &mut points {
+            *p = p.add(outward.mul(below(*p)));
+        }
+    }
+    if points.is_empty() {
+        points.push(b.closest_point(a.center));
+    }
+    Some(ContactManifold {
+        normal,
+        depth: face_depth,
+        points,
+    })
+}
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use crate::{FloatingScalar, Grid, VecN};
+
+fn crossing<T>(a: T, b: T, iso: T) -> T
+where
+    T: FloatingScalar,
+{
+    (iso - a) / (b - a)
+}
+
+/// Get the pairs of face edges that cross the iso level
+///
+/// `low` is whether each of the face's corners is below the iso level, in
+/// counter-clockwise order. Each pair `(k, j)` connects the crossing on edge `j`
+/// to the crossing on edge `k`, where edge `k` connects corners `k` and `k + 1`.
+/// Ambiguous faces are resolved by separating the low corners.
+fn face_segments(low: [bool; 4]) -> Vec<(usize, usize)> {
+    let mut segments = Vec::new();
+    for enter in 0..4 {
+        if low[enter] || !low[(enter + 1) % 4] {
+            continue;
+        }
+        let exit = (1..4)
+            .map(|k| (enter + k) % 4)
+            .find(|&k| low[k] && !low[(k + 1) % 4])
+            .expect("face crossings come in pairs");
+        segments.push((exit, enter));
+    }
+    segments
+}
+
+impl<T> Grid<T, 2>
+where
+    T: FloatingScalar,
+{
+    /// Extract the contour lines where the grid crosses an iso level
+    ///
+    /// This uses the marching squares algorithm. Positions are in grid units, with
+    /// value `[x, y]` at position `[x, y]`. Contours run counter-clockwise around regions
+    /// with values below the iso level. Closed contours end with their first point.
+    pub fn marching_squares<V>(&self, iso: T) -> Vec<Vec<V>>
+    where
+        V: VecN<Scalar = T>,
+    {
+        let [w, h] = self.size();
+        let low = |x: usize, y: usize| self[[x, y]] < iso;
+        // Edges are identified by their starting position and direction
+        let mut next: HashMap<(usize, usize, bool), (usize, usize, bool)> = HashMap::new();
+        for y in 0..h.saturating_sub(1) {
+            for x in 0..w.saturating_sub(1) {
+                let corners = [low(x, y), low(x + 1, y), low(x + 1, y + 1), low(x, y + 1)];
+                let edges = [
+                    (x, y, false),
+                    (x + 1, y, true),
+                    (x, y + 1, false),
+                    (x, y, true),
+                ];
+                for (from, to) in face_segments(corners) {
+                    next.insert(edges[from], edges[to]);
+                }
+            }
+        }
+        let point = |(x, y, vertical): (usize, usize, bool)| {
+            let (x2, y2) = if vertical { (x, y + 1) } else { (x + 1, y) };
+            let t = crossing(self[[x, y]], self[[x2, y2]], iso);
+            let mut v = V::ZERO;
+            v.set_dim(0, T::from_f64(x as f64) + t * T::from_f64((x2 - x) as f64));
+            v.set_dim(1, T::from_f64(y as f64) + t * T::from_f64((y2 - y) as f64));
+            v
+        };
+        let mut ends: Vec<_> = next.values().copied().collect();
+        ends.sort_unstable();
+        let mut starts: Vec<_> = next
+            .keys()
+            .copied()
+            .filter(|e| ends.binary_search(e).is_err())
+            .collect();
+        starts.sort_unstable();
+        let mut rest: Vec<_> = next.keys().copied().collect();
+        rest.sort_unstable();
+        let mut contours = Vec::new();
+        for start in starts.into_iter().chain(rest) {
+            let Some(mut to) = next.remove(&start) else {
+                continue;
+            };
+            let mut contour = vec![point(start), point(to)];
+            while let Some(after) = next.remove(&to) {
+                contour.push(point(after));
+                to = after;
+            }
+            contours.push(contour);
+        }
+        contours
+    }
+}
+
+/// The corners of each cube face, counter-clockwise when viewed from outside
+const CUBE_FACES: [[usize; 4]; 6] = [
+    [0, 4, 6, 2],
+    [1, 3, 7, 5],
+    [0, 1, 5, 4],
+    [2, 6, 7, 3],
+    [0, 2, 3, 1],
+    [4, 5, 7, 6],
+];
+
+/// Get the loops of cube edges that cross the iso level for each corner configuration
+fn cube_loops() -> Vec<Vec<Vec<(usize, usize)>>> {
+    (0..256usize)
+        .map(|case| {
+            let low = |corner: usize| case & (1 << corner) != 0;
+            let mut next = HashMap::new();
+            for face in CUBE_FACES {
+                let edge = |k: usize| {
+                    let [a, b] = [face[k], face[(k + 1) % 4]];
+                    (a.min(b), a.max(b))
+                };
+                for (from, to) in face_segments(face.map(low)) {
+                    next.insert(edge(from), edge(to));
+                }
+            }
+            let mut loops = Vec::new();
+            for corner in 0..8 {
+                for other in 0..8 {
+                    let Some(mut to) = next.remove(&(corner, other)) else {
+                        continue;
+                    };
+                    let mut edges = vec![(corner, other)];
+                    while to != (corner, other) {
+                        edges.push(to);
+                        to = next.remove(&to).expect("cube crossings form loops");
+                    }
+                    loops.push(edges);
+                }
+            }
+            loops
+        })
+        .collect()
+}
+
+impl<T> Grid<T, 3>
+where
+    T: FloatingScalar,
+{
+    /// Extract the triangles of the surface where the grid crosses an iso level
+    ///
+    /// This uses the marching cubes algorithm. Positions are in grid units, with
+    /// value `[x, y, z]` at position `[x, y, z]`. Triangles are wound counter-clockwise
+    /// when viewed from the side with values above the iso level.
+    pub fn marching_cubes<V>(&self, iso: T) -> Vec<[V; 3]>
+    where
+        V: VecN<Scalar = T> + Copy,
+    {
+        let loops = cube_loops();
+        let [w, h, d] = self.size();
+        let mut triangles = Vec::new();
+        for z in 0..d.saturating_sub(1) {
+            for y in 0..h.saturating_sub(1) {
+                for x in 0..w.saturating_sub(1) {
+                    let corner = |i: usize| [x + (i & 1), y + ((i >> 1) & 1), z + ((i >> 2) & 1)];
+                    let case = (0..8).fold(0, |case, i| {
+                        if self[corner(i)] < iso {
+                            case | (1 << i)
+                        } else {
+                            case
+                        }
+                    });
+                    let point = |(a, b): (usize, usize)| {
+                        let [pa, pb] = [corner(a), corner(b)];
+                        let t = crossing(self[pa], self[pb], iso);
+                        let mut v = V::ZERO;
+                        for i in 0..3 {
+                            let start = T::from_f64(pa[i] as f64);
+                            v.set_dim(i, start + t * (T::from_f64(pb[i] as f64) - start));
+                        }
+                        v
+                    };
+                    for edges in &loops[case] {
+                        let points: Vec<V> = edges.iter().map(|&e| point(e)).collect();
+                        for i in 1..points.len() - 1 {
+                            triangles.push([points[0], points[i + 1], points[i]]);
+                        }
+                    }
+                }
+            }
+        }
+        triangles
+    }
+}
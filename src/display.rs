@@ -0,0 +1,101 @@
+use std::fmt;
+
+use crate::{Aabb, VecN};
+
+/// Write the dimensions of a vector in parentheses, separated by commas
+fn write_dims<V>(
+    f: &mut fmt::Formatter,
+    vector: impl Fn(usize) -> V,
+    n: usize,
+    precision: Option<usize>,
+) -> fmt::Result
+where
+    V: fmt::Display,
+{
+    write!(f, "(")?;
+    for i in 0..n {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        match precision.or(f.precision()) {
+            Some(precision) => write!(f, "{:.*}", precision, vector(i))?,
+            None => write!(f, "{}", vector(i))?,
+        }
+    }
+    write!(f, ")")
+}
+
+/// An adapter that displays a vector as `(x, y, z)`
+///
+/// Made with [`VecN::display`]. The number of decimal places can be set with
+/// [`DisplayVec::precision`] or with the formatting precision, as in `{:.2}`.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayVec<'a, V> {
+    vector: &'a V,
+    precision: Option<usize>,
+}
+
+impl<'a, V> DisplayVec<'a, V> {
+    pub(crate) fn new(vector: &'a V) -> Self {
+        DisplayVec {
+            vector,
+            precision: None,
+        }
+    }
+    /// Set the number of decimal places to display
+    pub fn precision(self, precision: usize) -> Self {
+        DisplayVec {
+            precision: Some(precision),
+            ..self
+        }
+    }
+}
+
+impl<V> fmt::Display for DisplayVec<'_, V>
+where
+    V: VecN,
+    V::Scalar: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_dims(f, |i| self.vector.dim(i), V::N, self.precision)
+    }
+}
+
+/// An adapter that displays an aabb as `(x, y)..(x, y)` from its origin to its end
+///
+/// Made with [`Aabb::display_aabb`]. The number of decimal places can be set with
+/// [`DisplayAabb::precision`] or with the formatting precision, as in `{:.2}`.
+#[derive(Debug, Clone, Copy)]
+pub struct DisplayAabb<'a, A> {
+    aabb: &'a A,
+    precision: Option<usize>,
+}
+
+impl<'a, A> DisplayAabb<'a, A> {
+    pub(crate) fn new(aabb: &'a A) -> Self {
+        DisplayAabb {
+            aabb,
+            precision: None,
+        }
+    }
+    /// Set the number of decimal places to display
+    pub fn precision(self, precision: usize) -> Self {
+        DisplayAabb {
+            precision: Some(precision),
+            ..self
+        }
+    }
+}
+
+impl<A> fmt::Display for DisplayAabb<'_, A>
+where
+    A: Aabb,
+    <A::Vector as VecN>::Scalar: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let n = A::Vector::N;
+        write_dims(f, |i| self.aabb.origin_dim(i), n, self.precision)?;
+        write!(f, "..")?;
+        write_dims(f, |i| self.aabb.end_dim(i), n, self.precision)
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{FloatingScalar, Grid};
+
+/// The result of a grid distance transform
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistanceTransform<T, const N: usize> {
+    /// The distance from each position to its nearest seed
+    ///
+    /// Positions with no reachable seed have infinite distance.
+    pub distances: Grid<T, N>,
+    /// The position of the nearest seed to each position
+    pub nearest: Grid<Option<[usize; N]>, N>,
+}
+
+/// Compute the lower envelope of parabolas rooted at each finite value
+fn envelope_1d<T>(f: &[T], d: &mut [T], arg: &mut [usize])
+where
+    T: FloatingScalar,
+{
+    let n = f.len();
+    let sq = |q: usize| T::from_f64((q * q) as f64);
+    let mut v: Vec<usize> = Vec::with_capacity(n);
+    let mut z: Vec<T> = Vec::with_capacity(n + 1);
+    for q in (0..n).filter(|&q| f[q] < T::INFINITY) {
+        let mut s = -T::INFINITY;
+        while let Some(&p) = v.last() {
+            s = ((f[q] + sq(q)) - (f[p] + sq(p))) / T::from_f64((2 * (q - p)) as f64);
+            if s <= z[v.len() - 1] {
+                v.pop();
+                z.pop();
+                s = -T::INFINITY;
+            } else {
+                break;
+            }
+        }
+        v.push(q);
+        z.push(s);
+    }
+    if v.is_empty() {
+        d.fill(T::INFINITY);
+        return;
+    }
+    z.push(T::INFINITY);
+    let mut k = 0;
+    for q in 0..n {
+        let fq = T::from_f64(q as f64);
+        while z[k + 1] < fq {
+            k += 1;
+        }
+        let offset = T::from_f64(q.abs_diff(v[k]) as f64);
+        d[q] = offset * offset + f[v[k]];
+        arg[q] = v[k];
+    }
+}
+
+impl<T, const N: usize> Grid<T, N>
+where
+    T: FloatingScalar,
+{
+    /// Compute the generalized exact distance transform of the grid
+    ///
+    /// Each value is treated as an additive squared-distance cost, so seeds should be 0
+    /// and non-seed positions should be infinite. The resulting distance at each position
+    /// is the square root of the minimum over all positions of the squared distance plus
+    /// the cost. This uses the algorithm by Felzenszwalb and Huttenlocher, which runs
+    /// in linear time.
+    pub fn distance_transform(&self) -> DistanceTransform<T, N> {
+        let size = self.size();
+        let len = self.values().len();
+        let mut dist: Vec<T> = self.values().to_vec();
+        let mut nearest: Vec<Option<[usize; N]>> = (0..len)
+            .map(|i| (dist[i] < T::INFINITY).then(|| self.position_of(i)))
+            .collect();
+        let mut stride = 1;
+        for (dim, &count) in size.iter().enumerate() {
+            let mut f = vec![T::ZERO; count];
+            let mut d = vec![T::ZERO; count];
+            let mut arg = vec![0; count];
+            let mut line_nearest = vec![None; count];
+            for start in (0..len).filter(|&i| self.position_of(i)[dim] == 0) {
+                for k in 0..count {
+                    f[k] = dist[start + k * stride];
+                    line_nearest[k] = nearest[start + k * stride];
+                }
+                envelope_1d(&f, &mut d, &mut arg);
+                for k in 0..count {
+                    let i = start + k * stride;
+                    dist[i] = d[k];
+                    nearest[i] = if d[k] < T::INFINITY {
+                        line_nearest[arg[k]]
+                    } else {
+                        None
+                    };
+                }
+            }
+            stride *= count;
+        }
+        DistanceTransform {
+            distances: Grid::new(size, dist.into_iter().map(T::sqrt).collect())
+                .expect("distance grid has the same size"),
+            nearest: Grid::new(size, nearest).expect("nearest grid has the same size"),
+        }
+    }
+}
+
+/// Get the offsets of neighbors that come before a position in row-major order
+fn preceding_offsets<const N: usize>() -> Vec<[isize; N]> {
+    let count = 3usize.pow(N as u32);
+    (0..count)
+        .map(|mut i| {
+            let mut offset = [0; N];
+            for o in &mut offset {
+                *o = (i % 3) as isize - 1;
+                i /= 3;
+            }
+            offset
+        })
+        .filter(|offset| offset.iter().rev().find(|&&o| o != 0) == Some(&-1))
+        .collect()
+}
+
+impl<const N: usize> Grid<bool, N> {
+    fn seed_costs<T>(&self) -> Grid<T, N>
+    where
+        T: FloatingScalar,
+    {
+        self.map(|&seed| if seed { T::ZERO } else { T::INFINITY })
+    }
+    /// Compute the exact Euclidean distance from each position to the nearest `true` position
+    ///
+    /// This uses the algorithm by Felzenszwalb and Huttenlocher, which runs in linear time.
+    pub fn exact_distance_transform<T>(&self) -> DistanceTransform<T, N>
+    where
+        T: FloatingScalar,
+    {
+        self.seed_costs().distance_transform()
+    }
+    /// Compute an approximate distance from each position to the nearest `true` position
+    ///
+    /// This uses a two-pass chamfer transform where steps to each of the `3^N - 1`
+    /// neighbors cost their Euclidean length. Distances are the lengths of the
+    /// shortest such paths, so they may overestimate the true distance.
+    pub fn chamfer_distance_transform<T>(&self) -> DistanceTransform<T, N>
+    where
+        T: FloatingScalar,
+    {
+        let size = self.size();
+        let mut dist = self.seed_costs::<T>();
+        let mut nearest = self.map(|_| None);
+        for (i, &seed) in self.values().iter().enumerate() {
+            if seed {
+                nearest.values_mut()[i] = Some(self.position_of(i));
+            }
+        }
+        let preceding = preceding_offsets::<N>();
+        let following: Vec<[isize; N]> = preceding.iter().map(|o| o.map(|o| -o)).collect();
+        let len = self.values().len();
+        for (offsets, forward) in [(preceding, true), (following, false)] {
+            let weights: Vec<T> = offsets
+                .iter()
+                .map(|o| T::from_f64(o.iter().filter(|&&o| o != 0).count() as f64).sqrt())
+                .collect();
+            for step in 0..len {
+                let i = if forward { step } else { len - 1 - step };
+                let pos = self.position_of(i);
+                for (offset, &weight) in offsets.iter().zip(&weights) {
+                    let mut neighbor = [0; N];
+                    let in_bounds = (0..N).all(|d| {
+                        let n = pos[d] as isize + offset[d];
+                        neighbor[d] = n as usize;
+                        n >= 0 && (n as usize) < size[d]
+                    });
+                    if !in_bounds {
+                        continue;
+                    }
+                    let candidate = dist[neighbor] + weight;
+                    if candidate < dist[pos] {
+                        dist[pos] = candidate;
+                        nearest[pos] = nearest[neighbor];
+                    }
+                }
+            }
+        }
+        DistanceTransform {
+            distances: dist,
+            nearest,
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use crate::FloatingScalar;
+
+/// Get `n` unit vectors spread evenly over a sphere using a Fibonacci lattice
+///
+/// The points spiral from near the positive Y axis to near the negative Y axis, and each
+/// covers about the same area of the sphere.
+pub fn fibonacci_sphere<T>(n: usize) -> Vec<[T; 3]>
+where
+    T: FloatingScalar,
+{
+    let count = T::from_f64(n as f64);
+    let half = T::ONE / T::TWO;
+    // The golden angle, π(3 - √5)
+    let golden = T::PI * (T::from_f64(3.0) - T::from_f64(5.0).sqrt());
+    (0..n)
+        .map(|i| {
+            let i = T::from_f64(i as f64);
+            let y = T::ONE - (i + half) * T::TWO / count;
+            let r = (T::ONE - y * y).maxx(T::ZERO).sqrt();
+            let [cos, sin] = (i * golden).angle_as_vector();
+            [r * cos, y, r * sin]
+        })
+        .collect()
+}
+
+/// Get `n` unit vectors spread evenly around a circle, starting at an angle
+pub fn circle_points<T>(n: usize, start_angle: T) -> Vec<[T; 2]>
+where
+    T: FloatingScalar,
+{
+    let step = T::TAU / T::from_f64(n as f64);
+    (0..n)
+        .map(|i| (start_angle + T::from_f64(i as f64) * step).angle_as_vector())
+        .collect()
+}
@@ -0,0 +1,133 @@
+use crate::Grid;
+
+impl Grid<u32, 2> {
+    /// Create a Bayer ordered-dithering matrix
+    ///
+    /// The matrix is `2^order` positions on each side and holds each rank from `0` to
+    /// `4^order - 1` once. Dividing a rank plus one half by the number of positions gives
+    /// a threshold in `(0, 1)`.
+    pub fn bayer(order: u32) -> Self {
+        let side = 1usize << order;
+        Grid::from_fn([side, side], |[x, y]| {
+            (0..order).fold(0, |rank, k| {
+                let (x, y) = ((x >> k) & 1, (y >> k) & 1);
+                rank * 4 + (2 * (x ^ y) + y) as u32
+            })
+        })
+    }
+    /// Create a blue noise dithering mask with the void-and-cluster algorithm
+    ///
+    /// The mask is `side` positions on each side, tiles seamlessly, and holds each rank
+    /// from `0` to `side² - 1` once. `sigma` is the width of the Gaussian filter used to
+    /// find clusters and voids, and `1.5` is typical. `random` should return uniformly
+    /// distributed numbers in `[0, 1)`, and is used to place the initial points.
+    ///
+    /// This takes time proportional to the fourth power of `side`, so large masks are slow.
+    pub fn blue_noise<R>(side: usize, sigma: f64, mut random: R) -> Self
+    where
+        R: FnMut() -> f64,
+    {
+        let len = side * side;
+        if len == 0 {
+            return Grid::from_fn([side, side], |_| 0);
+        }
+        // Place about a tenth of the points randomly
+        let mut initial = Energy::new(side, sigma);
+        let count = (len / 10).max(1);
+        while initial.count < count {
+            let i = ((random() * len as f64) as usize).min(len - 1);
+            if !initial.points[i] {
+                initial.toggle(i);
+            }
+        }
+        // Move points from the tightest cluster to the largest void until they are even
+        for _ in 0..len {
+            let cluster = initial.tightest_cluster();
+            initial.toggle(cluster);
+            let void = initial.largest_void();
+            initial.toggle(void);
+            if void == cluster {
+                break;
+            }
+        }
+        let mut ranks = vec![0; len];
+        // Rank the initial points by removing the tightest cluster
+        let mut energy = initial.clone();
+        for rank in (0..count).rev() {
+            let cluster = energy.tightest_cluster();
+            energy.toggle(cluster);
+            ranks[cluster] = rank as u32;
+        }
+        // Rank the other positions by filling the largest void
+        let mut energy = initial;
+        for rank in count..len {
+            let void = energy.largest_void();
+            energy.toggle(void);
+            ranks[void] = rank as u32;
+        }
+        Grid::new([side, side], ranks).expect("rank grid has the right size")
+    }
+}
+
+/// A binary pattern on a torus and the Gaussian-filtered density of its points
+#[derive(Debug, Clone)]
+struct Energy {
+    side: usize,
+    kernel: Vec<f64>,
+    points: Vec<bool>,
+    energy: Vec<f64>,
+    count: usize,
+}
+
+impl Energy {
+    fn new(side: usize, sigma: f64) -> Self {
+        let wrapped = |d: usize| d.min(side - d) as f64;
+        let kernel = (0..side * side)
+            .map(|i| {
+                let (dx, dy) = (wrapped(i % side), wrapped(i / side));
+                (-(dx * dx + dy * dy) / (2.0 * sigma * sigma)).exp()
+            })
+            .collect();
+        Energy {
+            side,
+            kernel,
+            points: vec![false; side * side],
+            energy: vec![0.0; side * side],
+            count: 0,
+        }
+    }
+    /// Add or remove a point
+    fn toggle(&mut self, index: usize) {
+        let sign = if self.points[index] { -1.0 } else { 1.0 };
+        self.points[index] = !self.points[index];
+        if self.points[index] {
+            self.count += 1;
+        } else {
+            self.count -= 1;
+        }
+        let side = self.side;
+        let (px, py) = (index % side, index / side);
+        for (i, energy) in self.energy.iter_mut().enumerate() {
+            let dx = (i % side + side - px) % side;
+            let dy = (i / side + side - py) % side;
+            *energy += sign * self.kernel[dx + dy * side];
+        }
+    }
+    /// Get the index of the point with the most energy
+    fn tightest_cluster(&self) -> usize {
+        self.extreme(true, |a, b| a > b)
+    }
+    /// Get the index of the empty position with the least energy
+    fn largest_void(&self) -> usize {
+        self.extreme(false, |a, b| a < b)
+    }
+    fn extreme(&self, points: bool, better: impl Fn(f64, f64) -> bool) -> usize {
+        let mut best = None;
+        for (i, &energy) in self.energy.iter().enumerate() {
+            if self.points[i] == points && best.is_none_or(|(_, e)| better(energy, e)) {
+                best = Some((i, energy));
+            }
+        }
+        best.expect("there is a position to choose").0
+    }
+}
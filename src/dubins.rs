@@ -0,0 +1,156 @@
+use crate::{arc::wrap_angle, FloatingScalar, Path, Scalar, VecN, XVec, YVec};
+
+/// A kind of segment in a Dubins path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Left,
+    Straight,
+    Right,
+}
+
+use Step::*;
+
+/// The segment kinds of each candidate Dubins path
+const WORDS: [[Step; 3]; 6] = [
+    [Left, Straight, Left],
+    [Right, Straight, Right],
+    [Left, Straight, Right],
+    [Right, Straight, Left],
+    [Right, Left, Right],
+    [Left, Right, Left],
+];
+
+/// Get the lengths of the segments of a Dubins path, scaled to a turning radius of `1`
+///
+/// `d` is the scaled distance between the poses, and `a` and `b` are the start and end
+/// headings relative to the direction between them. Returns `None` if the path is impossible.
+fn word_lengths<T>(word: [Step; 3], d: T, a: T, b: T) -> Option<[T; 3]>
+where
+    T: FloatingScalar,
+{
+    let (sa, sb, ca, cb) = (a.sin(), b.sin(), a.cos(), b.cos());
+    let cab = (a - b).cos();
+    let two = T::TWO;
+    let lengths = match word {
+        [Left, Straight, Left] => {
+            let p2 = two + d * d - two * cab + two * d * (sa - sb);
+            let turn = (cb - ca).atan2(d + sa - sb);
+            [-a + turn, p2.sqrt(), b - turn]
+        }
+        [Right, Straight, Right] => {
+            let p2 = two + d * d - two * cab + two * d * (sb - sa);
+            let turn = (ca - cb).atan2(d - sa + sb);
+            [a - turn, p2.sqrt(), -b + turn]
+        }
+        [Left, Straight, Right] => {
+            let p2 = -two + d * d + two * cab + two * d * (sa + sb);
+            if p2 < T::ZERO {
+                return None;
+            }
+            let p = p2.sqrt();
+            let turn = (-ca - cb).atan2(d + sa + sb) - (-two).atan2(p);
+            [-a + turn, p, -b + turn]
+        }
+        [Right, Straight, Left] => {
+            let p2 = -two + d * d + two * cab - two * d * (sa + sb);
+            if p2 < T::ZERO {
+                return None;
+            }
+            let p = p2.sqrt();
+            let turn = (ca + cb).atan2(d - sa - sb) - two.atan2(p);
+            [a - turn, p, b - turn]
+        }
+        [Right, Left, Right] => {
+            let c = (T::from_f64(6.0) - d * d + two * cab + two * d * (sa - sb)) / T::from_f64(8.0);
+            if c.abs() > T::ONE {
+                return None;
+            }
+            let p = T::TAU - c.acos();
+            let t = a - (ca - cb).atan2(d - sa + sb) + p / two;
+            [t, p, a - b - t + p]
+        }
+        [Left, Right, Left] => {
+            let c = (T::from_f64(6.0) - d * d + two * cab + two * d * (sb - sa)) / T::from_f64(8.0);
+            if c.abs() > T::ONE {
+                return None;
+            }
+            let p = T::TAU - c.acos();
+            let t = -a - (ca - cb).atan2(d + sa - sb) + p / two;
+            [t, p, b - a - t + p]
+        }
+        _ => return None,
+    };
+    let mut i = 0;
+    Some(lengths.map(|l| {
+        let step = word[i];
+        i += 1;
+        if step == Straight {
+            return l;
+        }
+        let l = wrap_angle(l);
+        // Turns that should be no turn at all may be wrapped to full turns
+        if (T::TAU - l).is_near_zero(T::from_f64(64.0)) {
+            T::ZERO
+        } else {
+            l
+        }
+    }))
+}
+
+/// Get the shortest path between two 2D poses for a vehicle with a minimum turning radius
+///
+/// Each pose is a position and a heading in radians, measured counter-clockwise from the
+/// positive X axis. The path is a Dubins path made of up to 3 arcs and lines, and the
+/// vehicle only moves forward along it. Returns `None` if the radius is not positive.
+pub fn dubins_path<V>(
+    start: V,
+    start_heading: V::Scalar,
+    end: V,
+    end_heading: V::Scalar,
+    radius: V::Scalar,
+) -> Option<Path<V::Scalar>>
+where
+    V: VecN,
+    V::Scalar: FloatingScalar,
+{
+    if radius <= V::Scalar::ZERO {
+        return None;
+    }
+    let [dx, dy] = [end.x() - start.x(), end.y() - start.y()];
+    let d = (dx * dx + dy * dy).sqrt() / radius;
+    let theta = if d.is_zero() {
+        V::Scalar::ZERO
+    } else {
+        dy.atan2(dx)
+    };
+    let a = wrap_angle(start_heading - theta);
+    let b = wrap_angle(end_heading - theta);
+    let (word, lengths) = WORDS
+        .into_iter()
+        .filter_map(|word| Some((word, word_lengths(word, d, a, b)?)))
+        .min_by(|(_, a), (_, b)| {
+            let [a, b] = [a, b].map(|l| l[0] + l[1] + l[2]);
+            a.partial_cmp(&b).expect("length comparison failed")
+        })?;
+    let mut path = Path::new([start.x(), start.y()]);
+    let mut heading = start_heading;
+    for (step, length) in word.into_iter().zip(lengths) {
+        if length.is_zero() {
+            continue;
+        }
+        let [x, y]: [V::Scalar; 2] = path.end();
+        let [cos, sin] = heading.angle_as_vector();
+        path = match step {
+            Left => {
+                heading += length;
+                path.arc_around([x - sin * radius, y + cos * radius], length)
+            }
+            Right => {
+                heading -= length;
+                path.arc_around([x + sin * radius, y - cos * radius], -length)
+            }
+            Straight => path.line_to([x + cos * length * radius, y + sin * length * radius]),
+        };
+    }
+    Some(path)
+}
@@ -0,0 +1,115 @@
+use crate::{FloatingScalar, Scalar};
+
+/// Trait for vector math on vectors whose dimensionality is only known at runtime
+///
+/// This is implemented for slices, so it can also be used with `Vec`s. Operations that
+/// take two vectors panic if they have different numbers of dimensions.
+pub trait DynVecN {
+    /// The scalar type
+    type Scalar: Scalar;
+    /// Get the number of dimensions
+    fn dims(&self) -> usize;
+    /// Get the value of a dimension
+    fn dim(&self, dim: usize) -> Self::Scalar;
+    /// Get a mutable reference to the value of a dimension
+    fn dim_mut(&mut self, dim: usize) -> &mut Self::Scalar;
+    /// Add another vector to the vector in place
+    fn add_assign(&mut self, other: &Self) {
+        same_dims(self, other);
+        for i in 0..self.dims() {
+            *self.dim_mut(i) += other.dim(i);
+        }
+    }
+    /// Subtract another vector from the vector in place
+    fn sub_assign(&mut self, other: &Self) {
+        same_dims(self, other);
+        for i in 0..self.dims() {
+            *self.dim_mut(i) -= other.dim(i);
+        }
+    }
+    /// Multiply the vector by a scalar value in place
+    fn mul_assign(&mut self, by: Self::Scalar) {
+        for i in 0..self.dims() {
+            *self.dim_mut(i) *= by;
+        }
+    }
+    /// Divide the vector by a scalar value in place
+    fn div_assign(&mut self, by: Self::Scalar) {
+        for i in 0..self.dims() {
+            *self.dim_mut(i) /= by;
+        }
+    }
+    /// Dot the vector with another
+    fn dot(&self, other: &Self) -> Self::Scalar {
+        same_dims(self, other);
+        (0..self.dims()).fold(Self::Scalar::ZERO, |acc, i| {
+            acc + self.dim(i) * other.dim(i)
+        })
+    }
+    /// Get the squared magnitude of the vector
+    fn squared_mag(&self) -> Self::Scalar {
+        self.dot(self)
+    }
+    /// Get the squared distance between this vector and another
+    fn squared_dist(&self, other: &Self) -> Self::Scalar {
+        same_dims(self, other);
+        (0..self.dims()).fold(Self::Scalar::ZERO, |acc, i| {
+            let d = self.dim(i).abs_diff(other.dim(i));
+            acc + d * d
+        })
+    }
+    /// Get the magnitude of the vector
+    fn mag(&self) -> Self::Scalar
+    where
+        Self::Scalar: FloatingScalar,
+    {
+        self.squared_mag().sqrt()
+    }
+    /// Get the distance between this vector and another
+    fn dist(&self, other: &Self) -> Self::Scalar
+    where
+        Self::Scalar: FloatingScalar,
+    {
+        self.squared_dist(other).sqrt()
+    }
+    /// Scale the vector to a magnitude of `1` in place
+    ///
+    /// The zero vector is left unchanged.
+    fn normalize(&mut self)
+    where
+        Self::Scalar: FloatingScalar,
+    {
+        let mag = self.mag();
+        if !mag.is_zero() {
+            self.div_assign(mag);
+        }
+    }
+}
+
+/// Panic if two vectors have different numbers of dimensions
+fn same_dims<V>(a: &V, b: &V)
+where
+    V: DynVecN + ?Sized,
+{
+    assert_eq!(
+        a.dims(),
+        b.dims(),
+        "vectors have different numbers of dimensions"
+    );
+}
+
+impl<T> DynVecN for [T]
+where
+    T: Scalar,
+{
+    type Scalar = T;
+    fn dims(&self) -> usize {
+        self.len()
+    }
+    fn dim(&self, dim: usize) -> T {
+        self[dim]
+    }
+    fn dim_mut(&mut self, dim: usize) -> &mut T {
+        &mut self[dim]
+    }
+}
@@ -0,0 +1,73 @@
+use crate::FloatingScalar;
+
+/// The maximum number of Jacobi sweeps when decomposing a matrix
+const MAX_SWEEPS: usize = 64;
+
+/// Get the eigenvalues and unit eigenvectors of a symmetric matrix with the Jacobi method
+///
+/// The matrix is given as rows. The eigenvalues are sorted from largest to smallest, and
+/// each eigenvector is at the same index as its eigenvalue. The eigenvectors form a
+/// right-handed basis.
+pub(crate) fn symmetric_eigen<T, const N: usize>(mut a: [[T; N]; N]) -> ([T; N], [[T; N]; N])
+where
+    T: FloatingScalar,
+{
+    let mut v: [[T; N]; N] =
+        std::array::from_fn(|i| std::array::from_fn(|j| if i == j { T::ONE } else { T::ZERO }));
+    let scale = (0..N).fold(T::ZERO, |acc, i| {
+        (0..N).fold(acc, |acc, j| acc + a[i][j] * a[i][j])
+    });
+    for _ in 0..MAX_SWEEPS {
+        let off = (0..N).fold(T::ZERO, |acc, i| {
+            (i + 1..N).fold(acc, |acc, j| acc + a[i][j] * a[i][j])
+        });
+        if off <= scale * T::EPSILON * T::EPSILON {
+            break;
+        }
+        for p in 0..N {
+            for q in p + 1..N {
+                if a[p][q] == T::ZERO {
+                    continue;
+                }
+                // Rotate in the p-q plane to zero the off-diagonal element
+                let theta = (a[q][q] - a[p][p]) / (T::TWO * a[p][q]);
+                let sign = if theta < T::ZERO { -T::ONE } else { T::ONE };
+                let t = sign / (theta.abs() + (theta * theta + T::ONE).sqrt());
+                let c = T::ONE / (t * t + T::ONE).sqrt();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (rp, rq) = (a[p], a[q]);
+                a[p] = std::array::from_fn(|k| c * rp[k] - s * rq[k]);
+                a[q] = std::array::from_fn(|k| s * rp[k] + c * rq[k]);
+                for row in v.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+            }
+        }
+    }
+    let mut order: [usize; N] = std::array::from_fn(|i| i);
+    order.sort_by(|&i, &j| {
+        a[j][j]
+            .partial_cmp(&a[i][i])
+            .expect("eigenvalue comparison failed")
+    });
+    let values = order.map(|k| a[k][k]);
+    let mut vectors: [[T; N]; N] = order.map(|k| std::array::from_fn(|i| v[i][k]));
+    // The rotations keep the basis right-handed, but sorting may not
+    let swaps = (0..N)
+        .flat_map(|i| (i + 1..N).map(move |j| (i, j)))
+        .filter(|&(i, j)| order[i] > order[j])
+        .count();
+    if swaps % 2 == 1 {
+        if let Some(last) = vectors.last_mut() {
+            *last = last.map(|x| -x);
+        }
+    }
+    (values, vectors)
+}
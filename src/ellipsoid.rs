@@ -0,0 +1,136 @@
+use crate::{quadratic_roots, Aabb, FloatingScalar, FloatingVecN, Ray, RayHit, VecN};
+
+/// An N-dimensional ellipsoid
+///
+/// The ellipsoid's local axes are the rows of `axes`, which should be orthonormal.
+/// Each radius is the extent of the ellipsoid along the corresponding local axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ellipsoid<T, const N: usize> {
+    /// The center of the ellipsoid
+    pub center: [T; N],
+    /// The radius along each local axis
+    pub radii: [T; N],
+    /// The local axes
+    pub axes: [[T; N]; N],
+}
+
+/// A 2D ellipse
+pub type Ellipse<T> = Ellipsoid<T, 2>;
+
+impl<T, const N: usize> Ellipsoid<T, N>
+where
+    T: FloatingScalar,
+{
+    /// Create a new axis-aligned ellipsoid
+    pub fn new(center: [T; N], radii: [T; N]) -> Self {
+        let axes =
+            std::array::from_fn(|i| std::array::from_fn(|j| if i == j { T::ONE } else { T::ZERO }));
+        Ellipsoid {
+            center,
+            radii,
+            axes,
+        }
+    }
+    /// Create a new ellipsoid with the given orthonormal local axes
+    pub fn with_axes(center: [T; N], radii: [T; N], axes: [[T; N]; N]) -> Self {
+        Ellipsoid {
+            center,
+            radii,
+            axes,
+        }
+    }
+    fn local_of(&self, v: [T; N]) -> [T; N] {
+        self.axes.map(|axis| axis.dot(v))
+    }
+    fn world_of(&self, v: [T; N]) -> [T; N] {
+        (0..N).fold([T::ZERO; N], |acc, i| acc.add(self.axes[i].mul(v[i])))
+    }
+    /// Check if the ellipsoid contains a point
+    pub fn contains(&self, point: [T; N]) -> bool {
+        let local = self.local_of(point.sub(self.center));
+        local.div2(self.radii).squared_mag() <= T::ONE
+    }
+    /// Get the point on the ellipsoid's surface closest to another point
+    ///
+    /// This is found by bisection, so it is approximate. For points inside the ellipsoid
+    /// on the plane of its shortest axis, a nearby point on the surface may be returned instead.
+    pub fn closest_point(&self, point: [T; N]) -> [T; N] {
+        let local = self.local_of(point.sub(self.center));
+        let squares = self.radii.mul2(self.radii);
+        // The closest point is squares[i] * local[i] / (t + squares[i]) for the
+        // root t of this function, which decreases wherever it is defined
+        let f = |t: T| {
+            (0..N).fold(-T::ONE, |acc, i| {
+                acc + (self.radii[i] * local[i] / (t + squares[i])).square()
+            })
+        };
+        let (mut lo, mut hi) = if local.div2(self.radii).squared_mag() < T::ONE {
+            (-squares.min_dim(), T::ZERO)
+        } else {
+            (T::ZERO, self.radii.max_dim() * local.mag())
+        };
+        for _ in 0..128 {
+            let mid = (lo + hi) / T::TWO;
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            if f(mid) > T::ZERO {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let t = (lo + hi) / T::TWO;
+        let closest = std::array::from_fn(|i| squares[i] * local[i] / (t + squares[i]));
+        self.center.add(self.world_of(closest))
+    }
+    /// Intersect a ray with the ellipsoid
+    ///
+    /// Rays starting inside the ellipsoid hit its surface from the inside.
+    pub fn raycast(&self, ray: Ray<[T; N]>) -> Option<RayHit<[T; N]>> {
+        let origin = self.local_of(ray.origin.sub(self.center)).div2(self.radii);
+        let dir = self.local_of(ray.dir).div2(self.radii);
+        let t = quadratic_roots(
+            dir.squared_mag(),
+            T::TWO * origin.dot(dir),
+            origin.squared_mag() - T::ONE,
+        )
+        .into_iter()
+        .find(|&t| t >= T::ZERO)?;
+        let point = ray.at(t);
+        let local = self.local_of(point.sub(self.center));
+        let gradient = local.div2(self.radii).div2(self.radii);
+        Some(RayHit {
+            t,
+            point,
+            normal: self.world_of(gradient).unit(),
+        })
+    }
+    /// Get the smallest aabb that contains the ellipsoid
+    pub fn aabb<A>(&self) -> A
+    where
+        A: Aabb<Vector = [T; N]>,
+    {
+        let mut aabb = A::ORIGIN_ZERO_SIZE;
+        for j in 0..N {
+            let half = (0..N)
+                .map(|i| (self.radii[i] * self.axes[i][j]).square())
+                .fold(T::ZERO, |acc, s| acc + s)
+                .sqrt();
+            aabb.set_origin_dim(j, self.center[j] - half);
+            aabb.set_size_dim(j, half * T::TWO);
+        }
+        aabb
+    }
+}
+
+impl<T> Ellipsoid<T, 2>
+where
+    T: FloatingScalar,
+{
+    /// Create a new ellipse rotated counter-clockwise by an angle in radians
+    pub fn rotated(center: [T; 2], radii: [T; 2], angle: T) -> Self {
+        let [cos, sin] = angle.angle_as_vector();
+        Self::with_axes(center, radii, [[cos, sin], [-sin, cos]])
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{FloatingScalar, FloatingVecN};
+
+/// Get `1` for non-negative values and `-1` for negative ones
+fn sign_not_zero<T>(x: T) -> T
+where
+    T: FloatingScalar,
+{
+    if x < T::ZERO {
+        -T::ONE
+    } else {
+        T::ONE
+    }
+}
+
+/// Encode a direction as a point on an octahedron unfolded onto the square `[-1, 1]²`
+///
+/// The direction does not need to be normalized. The zero vector encodes to `[0, 0]`.
+pub fn octahedral_encode<T>(direction: [T; 3]) -> [T; 2]
+where
+    T: FloatingScalar,
+{
+    let [x, y, z] = direction;
+    let sum = x.abs() + y.abs() + z.abs();
+    if sum.is_zero() {
+        return [T::ZERO; 2];
+    }
+    let (x, y, z) = (x / sum, y / sum, z / sum);
+    if z < T::ZERO {
+        // Fold the lower half of the octahedron over the corners of the square
+        [
+            (T::ONE - y.abs()) * sign_not_zero(x),
+            (T::ONE - x.abs()) * sign_not_zero(y),
+        ]
+    } else {
+        [x, y]
+    }
+}
+
+/// Decode a unit direction from a point made by [`octahedral_encode`]
+pub fn octahedral_decode<T>([u, v]: [T; 2]) -> [T; 3]
+where
+    T: FloatingScalar,
+{
+    let z = T::ONE - u.abs() - v.abs();
+    let fold = (-z).maxx(T::ZERO);
+    let x = u - fold * sign_not_zero(u);
+    let y = v - fold * sign_not_zero(v);
+    [x, y, z].unit()
+}
+
+/// Encode a direction as equirectangular texture coordinates in `[0, 1]²`
+///
+/// The Y axis is up. `u` goes around the Y axis starting from the negative X axis, and `v`
+/// goes from `0` at the top to `1` at the bottom. The direction does not need to be
+/// normalized. The zero vector encodes to `[0.5, 0.5]`.
+pub fn equirect_encode<T>(direction: [T; 3]) -> [T; 2]
+where
+    T: FloatingScalar,
+{
+    let Some([x, y, z]) = direction.try_unit() else {
+        return [T::ONE / T::TWO; 2];
+    };
+    let u = z.atan2(x) / T::TAU + T::ONE / T::TWO;
+    let v = y.maxx(-T::ONE).minn(T::ONE).acos() / T::PI;
+    [u, v]
+}
+
+/// Decode a unit direction from coordinates made by [`equirect_encode`]
+pub fn equirect_decode<T>([u, v]: [T; 2]) -> [T; 3]
+where
+    T: FloatingScalar,
+{
+    let [cos_phi, sin_phi] = ((u - T::ONE / T::TWO) * T::TAU).angle_as_vector();
+    let [cos_theta, sin_theta] = (v * T::PI).angle_as_vector();
+    [sin_theta * cos_phi, cos_theta, sin_theta * sin_phi]
+}
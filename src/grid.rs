@@ -0,0 +1,103 @@
+use std::ops::{Index, IndexMut};
+
+/// An N-dimensional grid of values
+///
+/// Values are stored in row-major order, with the first dimension varying fastest.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T, const N: usize> {
+    size: [usize; N],
+    values: Vec<T>,
+}
+
+impl<T, const N: usize> Grid<T, N> {
+    /// Create a grid from a size and a list of values
+    ///
+    /// Returns `None` if the number of values does not match the size.
+    pub fn new(size: [usize; N], values: Vec<T>) -> Option<Self> {
+        if size.iter().product::<usize>() == values.len() {
+            Some(Grid { size, values })
+        } else {
+            None
+        }
+    }
+    /// Create a grid by calling a function for each position
+    pub fn from_fn<F>(size: [usize; N], mut f: F) -> Self
+    where
+        F: FnMut([usize; N]) -> T,
+    {
+        let len = size.iter().product();
+        let values = (0..len).map(|i| f(position_of(size, i))).collect();
+        Grid { size, values }
+    }
+    /// Get the size of the grid
+    pub fn size(&self) -> [usize; N] {
+        self.size
+    }
+    /// Get the values of the grid in row-major order
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+    /// Get mutable references to the values of the grid in row-major order
+    pub fn values_mut(&mut self) -> &mut [T] {
+        &mut self.values
+    }
+    /// Take the values of the grid in row-major order
+    pub fn into_values(self) -> Vec<T> {
+        self.values
+    }
+    /// Get the index into the values of a position
+    pub fn index_of(&self, pos: [usize; N]) -> Option<usize> {
+        let mut index = 0;
+        for i in (0..N).rev() {
+            if pos[i] >= self.size[i] {
+                return None;
+            }
+            index = index * self.size[i] + pos[i];
+        }
+        Some(index)
+    }
+    /// Get the position of an index into the values
+    pub fn position_of(&self, index: usize) -> [usize; N] {
+        position_of(self.size, index)
+    }
+    /// Get a reference to the value at a position
+    pub fn get(&self, pos: [usize; N]) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.values[i])
+    }
+    /// Get a mutable reference to the value at a position
+    pub fn get_mut(&mut self, pos: [usize; N]) -> Option<&mut T> {
+        self.index_of(pos).map(move |i| &mut self.values[i])
+    }
+    /// Create a new grid of the same size by mapping each value
+    pub fn map<U, F>(&self, f: F) -> Grid<U, N>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            size: self.size,
+            values: self.values.iter().map(f).collect(),
+        }
+    }
+}
+
+fn position_of<const N: usize>(size: [usize; N], mut index: usize) -> [usize; N] {
+    let mut pos = [0; N];
+    for i in 0..N {
+        pos[i] = index % size[i];
+        index /= size[i];
+    }
+    pos
+}
+
+impl<T, const N: usize> Index<[usize; N]> for Grid<T, N> {
+    type Output = T;
+    fn index(&self, pos: [usize; N]) -> &Self::Output {
+        self.get(pos).expect("grid position out of bounds")
+    }
+}
+
+impl<T, const N: usize> IndexMut<[usize; N]> for Grid<T, N> {
+    fn index_mut(&mut self, pos: [usize; N]) -> &mut Self::Output {
+        self.get_mut(pos).expect("grid position out of bounds")
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{FloatingScalar, FloatingVecN, Grid, Ray, RayHit, Vec3, VecN, XVec, YVec, ZVec};
+
+/// A grid of heights
+///
+/// The height at grid position `[i, j]` is the Z coordinate of the point at
+/// `[i * cell_size[0], j * cell_size[1]]` on the XY plane. Each cell is split into
+/// two triangles along the diagonal from `[i, j]` to `[i + 1, j + 1]`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Heightfield<T> {
+    /// The heights
+    pub heights: Grid<T, 2>,
+    /// The size of each cell along the X and Y axes
+    pub cell_size: [T; 2],
+}
+
+impl<T> Heightfield<T>
+where
+    T: FloatingScalar,
+{
+    /// Create a new heightfield
+    pub fn new(heights: Grid<T, 2>, cell_size: [T; 2]) -> Self {
+        Heightfield { heights, cell_size }
+    }
+    /// Get the position of the vertex at a grid position
+    pub fn vertex<V>(&self, [i, j]: [usize; 2]) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        let mut v = V::ZERO;
+        v.set_x(T::from_f64(i as f64) * self.cell_size[0]);
+        v.set_y(T::from_f64(j as f64) * self.cell_size[1]);
+        v.set_z(self.heights[[i, j]]);
+        v
+    }
+    /// Get the normal at a grid position estimated from neighboring heights
+    pub fn vertex_normal<V>(&self, [i, j]: [usize; 2]) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        let [w, h] = self.heights.size();
+        let slope = |lo: [usize; 2], hi: [usize; 2], step: T| {
+            let steps = T::from_f64((hi[0] + hi[1] - lo[0] - lo[1]) as f64);
+            if steps.is_zero() {
+                T::ZERO
+            } else {
+                (self.heights[hi] - self.heights[lo]) / (steps * step)
+            }
+        };
+        let dx = slope(
+            [i.saturating_sub(1), j],
+            [(i + 1).min(w - 1), j],
+            self.cell_size[0],
+        );
+        let dy = slope(
+            [i, j.saturating_sub(1)],
+            [i, (j + 1).min(h - 1)],
+            self.cell_size[1],
+        );
+        let mut n = V::ZERO;
+        n.set_x(-dx);
+        n.set_y(-dy);
+        n.set_z(T::ONE);
+        n.unit()
+    }
+    /// Intersect a ray with the heightfield
+    ///
+    /// Cells are visited in order along the ray, so only the cells the ray passes
+    /// over are tested. The normal of the hit is interpolated from the vertex normals.
+    /// Returns `None` if the ray's direction is zero.
+    pub fn raycast<V>(&self, ray: Ray<V>) -> Option<RayHit<V>>
+    where
+        V: Vec3<Scalar = T> + Copy,
+    {
+        let [w, h] = self.heights.size();
+        if w < 2 || h < 2 || ray.dir.squared_mag().is_zero() {
+            return None;
+        }
+        let cells = [w - 1, h - 1];
+        let (min_z, max_z) = self
+            .heights
+            .values()
+            .iter()
+            .fold((T::INFINITY, -T::INFINITY), |(lo, hi), &z| {
+                (lo.minn(z), hi.maxx(z))
+            });
+        // Clip the ray to the heightfield's bounds
+        let mut t0 = T::ZERO;
+        let mut t1 = T::INFINITY;
+        let lows = [T::ZERO, T::ZERO, min_z];
+        let highs = [
+            T::from_f64(cells[0] as f64) * self.cell_size[0],
+            T::from_f64(cells[1] as f64) * self.cell_size[1],
+            max_z,
+        ];
+        for i in 0..3 {
+            let (o, d) = (ray.origin.dim(i), ray.dir.dim(i));
+            if d.is_zero() {
+                if o < lows[i] || o > highs[i] {
+                    return None;
+                }
+            } else {
+                let (a, b) = ((lows[i] - o) / d, (highs[i] - o) / d);
+                t0 = t0.maxx(a.minn(b));
+                t1 = t1.minn(a.maxx(b));
+            }
+        }
+        if t0 > t1 {
+            return None;
+        }
+        // Walk the cells with a DDA
+        let start = ray.at(t0);
+        let mut cell = [0; 2];
+        let mut step = [0isize; 2];
+        let mut t_max = [T::INFINITY; 2];
+        let mut t_delta = [T::INFINITY; 2];
+        for i in 0..2 {
+            let size = self.cell_size[i];
+            let c = (start.dim(i) / size).to_f64().floor().max(0.0) as usize;
+            cell[i] = c.min(cells[i] - 1);
+            let d = ray.dir.dim(i);
+            if !d.is_zero() {
+                let edge = if d > T::ZERO { cell[i] + 1 } else { cell[i] };
+                let edge = T::from_f64(edge as f64) * size;
+                step[i] = if d > T::ZERO { 1 } else { -1 };
+                t_max[i] = (edge - ray.origin.dim(i)) / d;
+                t_delta[i] = size / d.abs();
+            }
+        }
+        // Vertical rays only pass over the cell under the origin
+        if step == [0; 2] {
+            return self.raycast_cell(&ray, cell);
+        }
+        loop {
+            if let Some(hit) = self.raycast_cell(&ray, cell) {
+                return Some(hit);
+            }
+            let axis = if t_max[0] < t_max[1] { 0 } else { 1 };
+            if t_max[axis] > t1 {
+                return None;
+            }
+            let next = cell[axis] as isize + step[axis];
+            if next < 0 || next as usize >= cells[axis] {
+                return None;
+            }
+            cell[axis] = next as usize;
+            t_max[axis] += t_delta[axis];
+        }
+    }
+    fn raycast_cell<V>(&self, ray: &Ray<V>, [i, j]: [usize; 2]) -> Option<RayHit<V>>
+    where
+        V: Vec3<Scalar = T> + Copy,
+    {
+        let corners = [[i, j], [i + 1, j], [i + 1, j + 1], [i, j + 1]];
+        [[0, 1, 2], [0, 2, 3]]
+            .into_iter()
+            .filter_map(|tri| {
+                let tri = tri.map(|k| corners[k]);
+                let [t, u, v] = ray.triangle_barycentric(tri.map(|p| self.vertex(p)))?;
+                let [na, nb, nc] = tri.map(|p| self.vertex_normal::<V>(p));
+                let normal = na.mul(T::ONE - u - v).add(nb.mul(u)).add(nc.mul(v)).unit();
+                Some(RayHit {
+                    t,
+                    point: ray.at(t),
+                    normal,
+                })
+            })
+            .min_by(|a, b| {
+                a.t.partial_cmp(&b.t)
+                    .expect("hit distance comparison failed")
+            })
+    }
+}
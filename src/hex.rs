@@ -0,0 +1,178 @@
+use crate::{polygon::vec2, FloatingScalar, VecN, XVec, YVec};
+
+/// The axial offsets of the 6 neighbors of a hex, counter-clockwise from the positive Q direction
+const NEIGHBORS: [[i32; 2]; 6] = [[1, 0], [1, -1], [0, -1], [-1, 0], [-1, 1], [0, 1]];
+
+/// Trait for treating `[i32; 2]` as axial hex grid coordinates
+///
+/// Axial coordinates are `[q, r]`. The equivalent cube coordinates are `[q, r, s]`
+/// where `q + r + s == 0`.
+pub trait Hex: Sized {
+    /// Create axial coordinates from cube coordinates
+    fn from_cube(cube: [i32; 3]) -> Self;
+    /// Get the cube coordinates of the hex
+    fn to_cube(self) -> [i32; 3];
+    /// Get the nearest hex to fractional axial coordinates
+    fn round_hex(q: f64, r: f64) -> Self;
+    /// Get the number of steps between the hex and another
+    fn hex_distance(self, other: Self) -> i32;
+    /// Get the 6 neighbors of the hex
+    ///
+    /// They are ordered counter-clockwise starting from the positive Q direction.
+    fn hex_neighbors(self) -> [Self; 6];
+    /// Get the hexes on a line from the hex to another, including both ends
+    fn hex_line(self, other: Self) -> Vec<Self>;
+    /// Get the hexes within a number of steps of the hex, including itself
+    fn hex_range(self, radius: i32) -> Vec<Self>;
+    /// Get the hexes exactly a number of steps from the hex
+    ///
+    /// A radius of `0` gives just the hex itself.
+    fn hex_ring(self, radius: i32) -> Vec<Self>;
+}
+
+impl Hex for [i32; 2] {
+    fn from_cube([q, r, _]: [i32; 3]) -> Self {
+        [q, r]
+    }
+    fn to_cube(self) -> [i32; 3] {
+        let [q, r] = self;
+        [q, r, -q - r]
+    }
+    fn round_hex(q: f64, r: f64) -> Self {
+        let s = -q - r;
+        let (rq, rr, rs) = (q.round(), r.round(), s.round());
+        let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+        // Fix the coordinate that was rounded the most so the three still sum to zero
+        if dq > dr && dq > ds {
+            [(-rr - rs) as i32, rr as i32]
+        } else if dr > ds {
+            [rq as i32, (-rq - rs) as i32]
+        } else {
+            [rq as i32, rr as i32]
+        }
+    }
+    fn hex_distance(self, other: Self) -> i32 {
+        let [a, b] = [self.to_cube(), other.to_cube()];
+        (0..3).map(|i| (a[i] - b[i]).abs()).sum::<i32>() / 2
+    }
+    fn hex_neighbors(self) -> [Self; 6] {
+        NEIGHBORS.map(|offset| self.add(offset))
+    }
+    fn hex_line(self, other: Self) -> Vec<Self> {
+        let n = self.hex_distance(other);
+        // Nudge the endpoints so points exactly between hexes round consistently
+        let [q0, r0] = [self[0] as f64 + 1e-6, self[1] as f64 + 1e-6];
+        let [q1, r1] = [other[0] as f64 + 1e-6, other[1] as f64 + 1e-6];
+        (0..=n)
+            .map(|i| {
+                let t = if n == 0 { 0.0 } else { i as f64 / n as f64 };
+                Self::round_hex(q0 + (q1 - q0) * t, r0 + (r1 - r0) * t)
+            })
+            .collect()
+    }
+    fn hex_range(self, radius: i32) -> Vec<Self> {
+        (-radius..=radius)
+            .flat_map(|q| {
+                let r_range = (-radius).max(-q - radius)..=radius.min(-q + radius);
+                r_range.map(move |r| self.add([q, r]))
+            })
+            .collect()
+    }
+    fn hex_ring(self, radius: i32) -> Vec<Self> {
+        if radius <= 0 {
+            return vec![self];
+        }
+        let mut hex = self.add(NEIGHBORS[4].mul(radius));
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+        for offset in NEIGHBORS {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex = hex.add(offset);
+            }
+        }
+        ring
+    }
+}
+
+/// The orientation of the hexes in a [`HexLayout`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexOrientation {
+    /// Hexes have a corner at the top, and rows are horizontal
+    PointyTop,
+    /// Hexes have an edge at the top, and columns are vertical
+    FlatTop,
+}
+
+/// A mapping between axial hex coordinates and world positions
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HexLayout<T> {
+    /// The orientation of the hexes
+    pub orientation: HexOrientation,
+    /// The distance from the center of a hex to its corners
+    pub size: T,
+    /// The world position of the center of hex `[0, 0]`
+    pub origin: [T; 2],
+}
+
+impl<T> HexLayout<T>
+where
+    T: FloatingScalar,
+{
+    /// Create a new hex layout
+    pub fn new<V>(orientation: HexOrientation, size: T, origin: V) -> Self
+    where
+        V: VecN<Scalar = T>,
+    {
+        HexLayout {
+            orientation,
+            size,
+            origin: [origin.x(), origin.y()],
+        }
+    }
+    /// Get the world position of the center of a hex
+    pub fn to_world<V>(&self, [q, r]: [i32; 2]) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        let sqrt3 = 3f64.sqrt();
+        let (q, r) = (q as f64, r as f64);
+        let [x, y] = match self.orientation {
+            HexOrientation::PointyTop => [sqrt3 * q + sqrt3 / 2.0 * r, 1.5 * r],
+            HexOrientation::FlatTop => [1.5 * q, sqrt3 / 2.0 * q + sqrt3 * r],
+        };
+        vec2(
+            self.origin[0] + self.size * T::from_f64(x),
+            self.origin[1] + self.size * T::from_f64(y),
+        )
+    }
+    /// Get the hex that contains a world position
+    pub fn from_world<V>(&self, point: V) -> [i32; 2]
+    where
+        V: VecN<Scalar = T>,
+    {
+        let sqrt3 = 3f64.sqrt();
+        let x = ((point.x() - self.origin[0]) / self.size).to_f64();
+        let y = ((point.y() - self.origin[1]) / self.size).to_f64();
+        let (q, r) = match self.orientation {
+            HexOrientation::PointyTop => (sqrt3 / 3.0 * x - y / 3.0, 2.0 / 3.0 * y),
+            HexOrientation::FlatTop => (2.0 / 3.0 * x, -x / 3.0 + sqrt3 / 3.0 * y),
+        };
+        <[i32; 2]>::round_hex(q, r)
+    }
+    /// Get the world positions of the 6 corners of a hex, counter-clockwise
+    pub fn corners<V>(&self, hex: [i32; 2]) -> [V; 6]
+    where
+        V: VecN<Scalar = T>,
+    {
+        let center: [T; 2] = self.to_world(hex);
+        let start = match self.orientation {
+            HexOrientation::PointyTop => 30.0,
+            HexOrientation::FlatTop => 0.0,
+        };
+        std::array::from_fn(|i| {
+            let angle = T::from_f64((start + 60.0 * i as f64).to_radians());
+            let [cos, sin] = angle.angle_as_vector();
+            vec2(center[0] + self.size * cos, center[1] + self.size * sin)
+        })
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{steer::perpendicular, FloatingScalar, FloatingVecN, Scalar, VecN};
+
+/// Solve a two-bone chain so its end reaches toward a target
+///
+/// `joints` are the root, middle, and end joints, and the bone lengths are taken from
+/// them. The root does not move. The chain bends in the plane containing the root, the
+/// target, and `pole`, with the middle joint on the pole's side. If the target is out of
+/// reach, the chain points straight at it.
+///
+/// Returns the new joint positions.
+pub fn two_bone_ik<V>(joints: [V; 3], target: V, pole: V) -> [V; 3]
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let [root, mid, end] = joints;
+    let upper = mid.dist(root);
+    let lower = end.dist(mid);
+    // The direction must be a unit vector even when joints or the target coincide
+    let fallback = end.sub(root).unit_or(mid.sub(root).unit_or(V::axis(0)));
+    let dir = target.sub(root).unit_or(fallback);
+    let dist = target
+        .dist(root)
+        .maxx(upper.abs_diff(lower))
+        .minn(upper + lower);
+    let bend = pole.sub(root).reject_from(dir).unit_or(
+        mid.sub(root)
+            .reject_from(dir)
+            .unit_or(perpendicular(dir).unit()),
+    );
+    // The law of cosines gives the angle between the upper bone and the root-to-end line
+    let cos = if upper.is_zero() || dist.is_zero() {
+        V::Scalar::ONE
+    } else {
+        ((upper * upper + dist * dist - lower * lower) / (V::Scalar::TWO * upper * dist))
+            .maxx(-V::Scalar::ONE)
+            .minn(V::Scalar::ONE)
+    };
+    let sin = (V::Scalar::ONE - cos * cos).maxx(V::Scalar::ZERO).sqrt();
+    let mid = root.add(dir.mul(upper * cos)).add(bend.mul(upper * sin));
+    [root, mid, root.add(dir.mul(dist))]
+}
+
+/// Solve a chain of joints so its end reaches toward a target with FABRIK
+///
+/// `lengths` are the lengths of the bones between consecutive joints. The first joint
+/// does not move. Iteration stops once the end is within `tolerance` of the target or
+/// after `max_iterations`. If the target is out of reach, the chain points straight at it.
+///
+/// Returns whether the end reached the target.
+///
+/// # Panics
+///
+/// Panics if there is not exactly one less length than joints.
+pub fn fabrik<V>(
+    joints: &mut [V],
+    lengths: &[V::Scalar],
+    target: V,
+    tolerance: V::Scalar,
+    max_iterations: usize,
+) -> bool
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    assert_eq!(
+        lengths.len() + 1,
+        joints.len().max(1),
+        "chains need one less length than joints"
+    );
+    let Some(&root) = joints.first() else {
+        return false;
+    };
+    let total = lengths.iter().fold(V::Scalar::ZERO, |acc, &l| acc + l);
+    // Place a joint at a distance from another, keeping its direction if possible
+    let place =
+        |from: V, toward: V, length: V::Scalar| from.add(toward.sub(from).unit().mul(length));
+    if root.dist(target) > total {
+        for i in 1..joints.len() {
+            joints[i] = place(joints[i - 1], target, lengths[i - 1]);
+        }
+        return false;
+    }
+    let last = joints.len() - 1;
+    for _ in 0..max_iterations {
+        if joints[last].dist(target) <= tolerance {
+            return true;
+        }
+        joints[last] = target;
+        for i in (0..last).rev() {
+            joints[i] = place(joints[i + 1], joints[i], lengths[i]);
+        }
+        joints[0] = root;
+        for i in 1..=last {
+            joints[i] = place(joints[i - 1], joints[i], lengths[i - 1]);
+        }
+    }
+    joints[last].dist(target) <= tolerance
+}
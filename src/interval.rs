@@ -0,0 +1,207 @@
+use std::{collections::BTreeMap, ops::Range};
+
+/// A set of integers stored as sorted, disjoint runs
+///
+/// Adjacent and overlapping runs are merged, so each run is as long as possible.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    runs: Vec<Range<i32>>,
+}
+
+impl IntervalSet {
+    /// Create a new empty interval set
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Check if the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+    /// Get the number of integers in the set
+    pub fn len(&self) -> u64 {
+        self.runs
+            .iter()
+            .map(|run| (run.end as i64 - run.start as i64) as u64)
+            .sum()
+    }
+    /// Get the runs of the set in increasing order
+    pub fn runs(&self) -> &[Range<i32>] {
+        &self.runs
+    }
+    /// Check if the set contains an integer
+    pub fn contains(&self, x: i32) -> bool {
+        let i = self.runs.partition_point(|run| run.end <= x);
+        self.runs.get(i).is_some_and(|run| run.start <= x)
+    }
+    /// Add a range of integers to the set
+    pub fn insert(&mut self, range: Range<i32>) {
+        if range.is_empty() {
+            return;
+        }
+        // Find the runs that overlap or touch the range and merge them into it
+        let first = self.runs.partition_point(|run| run.end < range.start);
+        let last = self.runs.partition_point(|run| run.start <= range.end);
+        let merged = if first < last {
+            self.runs[first].start.min(range.start)..self.runs[last - 1].end.max(range.end)
+        } else {
+            range
+        };
+        self.runs.splice(first..last, [merged]);
+    }
+    /// Remove a range of integers from the set
+    pub fn remove(&mut self, range: Range<i32>) {
+        if range.is_empty() {
+            return;
+        }
+        // Find the runs that overlap the range and keep only the parts outside it
+        let first = self.runs.partition_point(|run| run.end <= range.start);
+        let last = self.runs.partition_point(|run| run.start < range.end);
+        if first >= last {
+            return;
+        }
+        let before = self.runs[first].start..range.start;
+        let after = range.end..self.runs[last - 1].end;
+        let kept = [before, after].into_iter().filter(|run| !run.is_empty());
+        self.runs.splice(first..last, kept);
+    }
+    /// Get the union of the set with another
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for run in &other.runs {
+            union.insert(run.clone());
+        }
+        union
+    }
+    /// Get the intersection of the set with another
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut runs = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.runs.get(i), other.runs.get(j)) {
+            let run = a.start.max(b.start)..a.end.min(b.end);
+            if !run.is_empty() {
+                runs.push(run);
+            }
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { runs }
+    }
+}
+
+/// A set of integer cells in N dimensions
+///
+/// Cells are stored as an [`IntervalSet`] along the first dimension for each occupied
+/// row of the other dimensions. Boxes are given as an origin and a size, like an
+/// [`Aabb`](crate::Aabb).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoxSet<const N: usize> {
+    rows: BTreeMap<[i32; N], IntervalSet>,
+}
+
+impl<const N: usize> Default for BoxSet<N> {
+    fn default() -> Self {
+        BoxSet {
+            rows: BTreeMap::new(),
+        }
+    }
+}
+
+impl<const N: usize> BoxSet<N> {
+    /// Create a new empty box set
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Check if the set is empty
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+    /// Get the number of cells in the set
+    pub fn len(&self) -> u64 {
+        self.rows.values().map(IntervalSet::len).sum()
+    }
+    /// Check if the set contains a cell
+    pub fn contains(&self, cell: [i32; N]) -> bool {
+        N > 0
+            && self
+                .rows
+                .get(&row_key(cell))
+                .is_some_and(|row| row.contains(cell[0]))
+    }
+    /// Add the cells in a box to the set
+    pub fn insert(&mut self, [origin, size]: [[i32; N]; 2]) {
+        if N == 0 || size.iter().any(|&s| s <= 0) {
+            return;
+        }
+        for key in row_keys(origin, size) {
+            let row = self.rows.entry(key).or_default();
+            row.insert(origin[0]..origin[0] + size[0]);
+        }
+    }
+    /// Remove the cells in a box from the set
+    pub fn remove(&mut self, [origin, size]: [[i32; N]; 2]) {
+        if N == 0 || size.iter().any(|&s| s <= 0) {
+            return;
+        }
+        for key in row_keys(origin, size) {
+            if let Some(row) = self.rows.get_mut(&key) {
+                row.remove(origin[0]..origin[0] + size[0]);
+                if row.is_empty() {
+                    self.rows.remove(&key);
+                }
+            }
+        }
+    }
+    /// Get the union of the set with another
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for (key, row) in &other.rows {
+            let merged = union
+                .rows
+                .get(key)
+                .map_or_else(|| row.clone(), |r| r.union(row));
+            union.rows.insert(*key, merged);
+        }
+        union
+    }
+    /// Get an iterator over boxes that exactly cover the set
+    ///
+    /// Each box is one cell thick in every dimension but the first.
+    pub fn boxes(&self) -> impl Iterator<Item = [[i32; N]; 2]> + '_ {
+        self.rows.iter().flat_map(|(key, row)| {
+            row.runs().iter().map(move |run| {
+                let mut origin = *key;
+                let mut size = [1; N];
+                origin[0] = run.start;
+                size[0] = run.end - run.start;
+                [origin, size]
+            })
+        })
+    }
+}
+
+/// Get the key of the row that contains a cell
+fn row_key<const N: usize>(mut cell: [i32; N]) -> [i32; N] {
+    cell[0] = 0;
+    cell
+}
+
+/// Get the keys of the rows that a box spans
+fn row_keys<const N: usize>(origin: [i32; N], size: [i32; N]) -> Vec<[i32; N]> {
+    let mut keys = vec![row_key(origin)];
+    for i in 1..N {
+        keys = keys
+            .into_iter()
+            .flat_map(|key| {
+                (0..size[i]).map(move |offset| {
+                    let mut key = key;
+                    key[i] += offset;
+                    key
+                })
+            })
+            .collect();
+    }
+    keys
+}
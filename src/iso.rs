@@ -0,0 +1,90 @@
+use crate::{polygon::vec2, FloatingScalar, VecN, XVec, YVec};
+
+/// A mapping between 2D tile coordinates and isometric screen positions
+///
+/// Increasing the first tile coordinate moves down and to the right on the screen, and
+/// increasing the second moves down and to the left, with Y pointing down the screen.
+/// A tile width twice its height gives the common 2:1 dimetric projection, and a ratio of
+/// `√3` gives a true isometric one.
+///
+/// Tile `[i, j]` covers the tile coordinates from `[i, j]` to `[i + 1, j + 1]`, so its top
+/// corner is at the screen position of `[i, j]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IsoLayout<T> {
+    /// The screen width of a tile
+    pub tile_width: T,
+    /// The screen height of a tile
+    pub tile_height: T,
+    /// The screen position of tile coordinate `[0, 0]`
+    pub origin: [T; 2],
+}
+
+impl<T> IsoLayout<T>
+where
+    T: FloatingScalar,
+{
+    /// Create a new isometric layout
+    pub fn new<V>(tile_width: T, tile_height: T, origin: V) -> Self
+    where
+        V: VecN<Scalar = T>,
+    {
+        IsoLayout {
+            tile_width,
+            tile_height,
+            origin: [origin.x(), origin.y()],
+        }
+    }
+    /// Get the screen position of fractional tile coordinates
+    pub fn to_screen<V>(&self, tile: V) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        let (half_width, half_height) = (self.tile_width / T::TWO, self.tile_height / T::TWO);
+        vec2(
+            self.origin[0] + (tile.x() - tile.y()) * half_width,
+            self.origin[1] + (tile.x() + tile.y()) * half_height,
+        )
+    }
+    /// Get the fractional tile coordinates of a screen position
+    pub fn from_screen<V>(&self, point: V) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        let a = (point.x() - self.origin[0]) / (self.tile_width / T::TWO);
+        let b = (point.y() - self.origin[1]) / (self.tile_height / T::TWO);
+        vec2((a + b) / T::TWO, (b - a) / T::TWO)
+    }
+    /// Get the tile that a screen position is over
+    pub fn pick<V>(&self, point: V) -> [i32; 2]
+    where
+        V: VecN<Scalar = T>,
+    {
+        let tile: [T; 2] = self.from_screen(vec2(point.x(), point.y()));
+        tile.map(|t| t.floor().to_f64() as i32)
+    }
+    /// Get the screen position of the center of a tile
+    pub fn tile_center<V>(&self, [i, j]: [i32; 2]) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        let half = T::ONE / T::TWO;
+        self.to_screen(vec2(
+            T::from_f64(i as f64) + half,
+            T::from_f64(j as f64) + half,
+        ))
+    }
+    /// Get the screen positions of the top, right, bottom, and left corners of a tile
+    pub fn tile_corners<V>(&self, [i, j]: [i32; 2]) -> [V; 4]
+    where
+        V: VecN<Scalar = T>,
+    {
+        let [i, j] = [T::from_f64(i as f64), T::from_f64(j as f64)];
+        [
+            [i, j],
+            [i + T::ONE, j],
+            [i + T::ONE, j + T::ONE],
+            [i, j + T::ONE],
+        ]
+        .map(|[x, y]| self.to_screen(vec2(x, y)))
+    }
+}
@@ -0,0 +1,107 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+use crate::{FloatingScalar, VecN};
+
+/// A wrapper that orders vectors lexicographically
+///
+/// This lets vectors of integers be sorted deterministically or used as
+/// [`BTreeMap`](std::collections::BTreeMap) keys. See [`VecN::lex_cmp`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Lex<V>(pub V);
+
+impl<V> PartialOrd for Lex<V>
+where
+    V: VecN + Eq,
+    V::Scalar: Ord,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V> Ord for Lex<V>
+where
+    V: VecN + Eq,
+    V::Scalar: Ord,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.lex_cmp(&other.0)
+    }
+}
+
+/// A wrapper that gives vectors of floats a total order, equality, and hashing
+///
+/// Dimensions are compared with the IEEE 754 total order, as in [`f64::total_cmp`], and
+/// vectors are ordered lexicographically. This means that `-0.0` and `0.0` are different,
+/// and that `NaN`s are equal to themselves. This lets float vectors be deduplicated in a
+/// [`HashSet`](std::collections::HashSet) or used as [`BTreeMap`](std::collections::BTreeMap)
+/// keys.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OrderedVec<V>(pub V);
+
+impl<V> OrderedVec<V>
+where
+    V: VecN,
+    V::Scalar: FloatingScalar,
+{
+    /// Get the keys of the dimensions, whose integer order is the total order of the floats
+    fn keys(&self) -> impl Iterator<Item = i64> + '_ {
+        (0..V::N).map(|i| {
+            let bits = self.0.dim(i).to_f64().to_bits() as i64;
+            // Flip every bit but the sign of negative numbers so they sort in reverse
+            bits ^ (((bits >> 63) as u64) >> 1) as i64
+        })
+    }
+}
+
+impl<V> PartialEq for OrderedVec<V>
+where
+    V: VecN,
+    V::Scalar: FloatingScalar,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.keys().eq(other.keys())
+    }
+}
+
+impl<V> Eq for OrderedVec<V>
+where
+    V: VecN,
+    V::Scalar: FloatingScalar,
+{
+}
+
+impl<V> PartialOrd for OrderedVec<V>
+where
+    V: VecN,
+    V::Scalar: FloatingScalar,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<V> Ord for OrderedVec<V>
+where
+    V: VecN,
+    V::Scalar: FloatingScalar,
+{
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.keys().cmp(other.keys())
+    }
+}
+
+impl<V> Hash for OrderedVec<V>
+where
+    V: VecN,
+    V::Scalar: FloatingScalar,
+{
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for key in self.keys() {
+            key.hash(state);
+        }
+    }
+}
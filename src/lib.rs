@@ -56,6 +56,20 @@ assert!(aabb.contains([5, 5]));
 assert!(!aabb.contains([5, 6]));
 ```
 
+## Polygons
+
+[`Polygon`] provides operations for treating slices of 2D vectors as simple polygons.
+
+### Example
+
+```
+use ndmath::*;
+
+let square = [[0.0, 0.0], [2.0, 0.0], [2.0, 2.0], [0.0, 2.0]];
+assert_eq!(square.area(), 4.0);
+assert_eq!(square.centroid(), Some([1.0, 1.0]));
+```
+
 ## Named dimension traits
 
 There are traits to provide accessors for named dimensional values.
@@ -98,11 +112,74 @@ assert_eq!(aabb.depth(), 5);
 */
 
 mod aabb;
+mod aim;
+mod align;
+mod arc;
+mod bezier;
+mod blend;
+mod body;
+mod camera;
+mod catenary;
+mod chunk;
+mod cluster;
+mod cone;
+mod contact;
+mod contour;
+mod display;
+mod distance;
+mod distribution;
+mod dither;
+mod dubins;
+mod dynvec;
+mod eigen;
+mod ellipsoid;
+mod encode;
+mod grid;
+mod heightfield;
+mod hex;
+mod ik;
+mod interval;
+mod iso;
+mod lex;
+mod lod;
+mod mass;
+mod matrix;
+mod obb;
+mod packing;
+mod parse;
+mod path;
+mod polygon;
+mod predicates;
+mod ray;
+mod roots;
+mod rounded;
 mod scalar;
+mod sdf;
+mod sh;
+mod shapes;
+mod summed;
+mod toi;
+mod tracked;
+mod tree;
+mod vec2;
+mod verlet;
+mod visibility;
+
+pub mod steer;
 
-use std::ops::Neg;
+use std::{
+    cmp::Ordering,
+    ops::{Index, IndexMut, Neg},
+};
 
-pub use {aabb::*, scalar::*};
+pub use {
+    aabb::*, aim::*, align::*, arc::*, bezier::*, blend::*, body::*, camera::*, catenary::*,
+    chunk::*, cluster::*, cone::*, contact::*, display::*, distance::*, distribution::*, dubins::*,
+    dynvec::*, ellipsoid::*, encode::*, grid::*, heightfield::*, hex::*, ik::*, interval::*,
+    iso::*, lex::*, lod::*, mass::*, matrix::*, obb::*, packing::*, parse::*, path::*, polygon::*,
+    predicates::*, ray::*, roots::*, rounded::*, scalar::*, sdf::*, sh::*, shapes::*, summed::*,
+    toi::*, tracked::*, tree::*, vec2::*, verlet::*, visibility::*,
+};
 
 /// Trait for basic vector math operations
 pub trait VecN: Sized {
@@ -110,6 +187,16 @@ pub trait VecN: Sized {
     const N: usize;
     /// The zero value
     const ZERO: Self;
+    /// The value with every dimension set to `1`
+    const ONE: Self;
+    /// The value with every dimension set to the scalar's minimum
+    ///
+    /// This is the identity of element-wise [`VecN::max2`].
+    const MIN: Self;
+    /// The value with every dimension set to the scalar's maximum
+    ///
+    /// This is the identity of element-wise [`VecN::min2`].
+    const MAX: Self;
     /// The scalar type
     type Scalar: Scalar;
     /// Get the value of a dimension
@@ -120,6 +207,75 @@ pub trait VecN: Sized {
     fn set_dim(&mut self, dim: usize, val: Self::Scalar) {
         *self.dim_mut(dim) = val;
     }
+    /// Get the value of a dimension, or `None` if it is out of range
+    fn try_dim(&self, dim: usize) -> Option<Self::Scalar> {
+        (dim < Self::N).then(|| self.dim(dim))
+    }
+    /// Get a mutable reference to the value of a dimension, or `None` if it is out of range
+    fn try_dim_mut(&mut self, dim: usize) -> Option<&mut Self::Scalar> {
+        if dim < Self::N {
+            Some(self.dim_mut(dim))
+        } else {
+            None
+        }
+    }
+    /// Create a vector by calling a function on each dimension index
+    fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize) -> Self::Scalar,
+    {
+        let mut v = Self::ZERO;
+        for i in 0..Self::N {
+            *v.dim_mut(i) = f(i);
+        }
+        v
+    }
+    /// Create a vector with every dimension set to the same value
+    fn splat(value: Self::Scalar) -> Self {
+        Self::from_fn(|_| value)
+    }
+    /// Create the unit vector along a dimension
+    ///
+    /// # Panics
+    ///
+    /// Panics if the dimension is out of range.
+    fn axis(dim: usize) -> Self {
+        assert!(dim < Self::N, "dimension {} is out of range", dim);
+        let mut v = Self::ZERO;
+        *v.dim_mut(dim) = Self::Scalar::ONE;
+        v
+    }
+    /// Get an iterator over the unit vectors along each dimension
+    fn axes() -> std::iter::Map<std::ops::Range<usize>, fn(usize) -> Self> {
+        (0..Self::N).map(Self::axis)
+    }
+    /// Call a function on each dimension
+    fn map<F>(mut self, mut f: F) -> Self
+    where
+        F: FnMut(Self::Scalar) -> Self::Scalar,
+    {
+        for i in 0..Self::N {
+            *self.dim_mut(i) = f(self.dim(i));
+        }
+        self
+    }
+    /// Call a function on each pair of corresponding dimensions of the vector and another
+    fn zip_with<F>(mut self, other: Self, mut f: F) -> Self
+    where
+        F: FnMut(Self::Scalar, Self::Scalar) -> Self::Scalar,
+    {
+        for i in 0..Self::N {
+            *self.dim_mut(i) = f(self.dim(i), other.dim(i));
+        }
+        self
+    }
+    /// Accumulate a value by calling a function on each dimension in order
+    fn fold<B, F>(&self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Scalar) -> B,
+    {
+        (0..Self::N).fold(init, |acc, i| f(acc, self.dim(i)))
+    }
     /// Add to the vector in place
     fn add_assign(&mut self, other: Self) {
         for i in 0..Self::N {
@@ -213,6 +369,30 @@ pub trait VecN: Sized {
     fn squared_dist(self, other: Self) -> Self::Scalar {
         self.sub(other).squared_mag()
     }
+    /// Get the sum of the absolute values of the dimensions
+    fn manhattan_mag(&self) -> Self::Scalar {
+        self.fold(Self::Scalar::ZERO, |acc, d| acc + d.abs())
+    }
+    /// Get the Manhattan distance between this vector and another
+    ///
+    /// This is the sum of the absolute differences of the dimensions.
+    fn manhattan_dist(self, other: Self) -> Self::Scalar {
+        (0..Self::N).fold(Self::Scalar::ZERO, |acc, i| {
+            acc + self.dim(i).abs_diff(other.dim(i))
+        })
+    }
+    /// Get the largest absolute value of the dimensions
+    fn chebyshev_mag(&self) -> Self::Scalar {
+        self.fold(Self::Scalar::ZERO, |acc, d| acc.maxx(d.abs()))
+    }
+    /// Get the Chebyshev distance between this vector and another
+    ///
+    /// This is the largest absolute difference of the dimensions.
+    fn chebyshev_dist(self, other: Self) -> Self::Scalar {
+        (0..Self::N).fold(Self::Scalar::ZERO, |acc, i| {
+            acc.maxx(self.dim(i).abs_diff(other.dim(i)))
+        })
+    }
     /// Get the minimum dimension
     fn min_dim(&self) -> Self::Scalar {
         (0..Self::N)
@@ -227,10 +407,48 @@ pub trait VecN: Sized {
             .max_by(|a, b| a.partial_cmp(b).expect("dimension comparison failed"))
             .expect("empty vectors have no dimensions")
     }
+    /// Compare the vector to another lexicographically
+    ///
+    /// The first dimension is compared first, and later dimensions break ties.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any compared dimensions are incomparable, such as `NaN`.
+    fn lex_cmp(&self, other: &Self) -> Ordering {
+        (0..Self::N)
+            .map(|i| {
+                self.dim(i)
+                    .partial_cmp(&other.dim(i))
+                    .expect("dimension comparison failed")
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+    /// Get an adapter that displays the vector as `(x, y, z)`
+    fn display(&self) -> DisplayVec<'_, Self> {
+        DisplayVec::new(self)
+    }
+    /// Get the sum of the dimensions
+    fn sum_dims(&self) -> Self::Scalar {
+        self.fold(Self::Scalar::ZERO, |acc, d| acc + d)
+    }
+    /// Get the product of the dimensions
+    fn product_dims(&self) -> Self::Scalar {
+        self.fold(Self::Scalar::ONE, |acc, d| acc * d)
+    }
+    /// Get the mean of the dimensions
+    ///
+    /// For integers, the result is rounded towards zero.
+    /// Panics if the vector has no dimensions.
+    fn mean(&self) -> Self::Scalar {
+        assert!(Self::N > 0, "empty vectors have no dimensions");
+        let n = (0..Self::N).fold(Self::Scalar::ZERO, |acc, _| acc + Self::Scalar::ONE);
+        self.sum_dims() / n
+    }
     /// Dot the vector with another
     fn dot(self, other: Self) -> Self::Scalar {
         (0..Self::N).fold(Self::Scalar::ZERO, |acc, i| {
-            acc + self.dim(i) + other.dim(i)
+            acc + self.dim(i) * other.dim(i)
         })
     }
     /// Linearly interpolate the vector with another in place
@@ -245,6 +463,58 @@ pub trait VecN: Sized {
         self.lerp_assign(other, t);
         self
     }
+    /// Get the absolute value of each dimension
+    fn abs(mut self) -> Self {
+        for i in 0..Self::N {
+            *self.dim_mut(i) = self.dim(i).abs();
+        }
+        self
+    }
+    /// Get the sign of each dimension
+    ///
+    /// Each dimension is `1` if it is positive, `-1` if it is negative, and unchanged if it is zero.
+    fn signum(mut self) -> Self {
+        for i in 0..Self::N {
+            *self.dim_mut(i) = self.dim(i).signum();
+        }
+        self
+    }
+    /// Get the element-wise absolute difference between the vector and another
+    ///
+    /// Unlike `a.sub(b)`, this does not underflow for unsigned scalars.
+    fn abs_diff(mut self, other: Self) -> Self {
+        for i in 0..Self::N {
+            *self.dim_mut(i) = self.dim(i).abs_diff(other.dim(i));
+        }
+        self
+    }
+    /// Get the midpoint between the vector and another
+    ///
+    /// This does not overflow. For integer scalars, each dimension is rounded towards zero.
+    fn midpoint(mut self, other: Self) -> Self {
+        for i in 0..Self::N {
+            *self.dim_mut(i) = self.dim(i).midpoint(other.dim(i));
+        }
+        self
+    }
+    /// Get the element-wise minimum of the vector and another
+    fn min2(mut self, other: Self) -> Self {
+        for i in 0..Self::N {
+            *self.dim_mut(i) = self.dim(i).minn(other.dim(i));
+        }
+        self
+    }
+    /// Get the element-wise maximum of the vector and another
+    fn max2(mut self, other: Self) -> Self {
+        for i in 0..Self::N {
+            *self.dim_mut(i) = self.dim(i).maxx(other.dim(i));
+        }
+        self
+    }
+    /// Clamp each dimension of the vector between those of a minimum and maximum vector
+    fn clamp2(self, min: Self, max: Self) -> Self {
+        self.max2(min).min2(max)
+    }
 }
 
 /// Trait for real-valued vector math operations
@@ -260,7 +530,58 @@ where
     fn dist(self, other: Self) -> Self::Scalar {
         self.squared_dist(other).sqrt()
     }
+    /// Get the Minkowski `p`-norm of the vector
+    ///
+    /// A `p` of `1` gives the Manhattan magnitude, `2` gives the Euclidean magnitude,
+    /// and larger values approach the Chebyshev magnitude.
+    fn minkowski_mag(&self, p: Self::Scalar) -> Self::Scalar {
+        self.fold(Self::Scalar::ZERO, |acc, d| acc + d.abs().powf(p))
+            .powf(Self::Scalar::ONE / p)
+    }
+    /// Get the Minkowski `p`-distance between the vector and another
+    fn minkowski_dist(self, other: Self, p: Self::Scalar) -> Self::Scalar {
+        self.sub(other).minkowski_mag(p)
+    }
+    /// Move the vector toward a target with exponential decay over a time step
+    ///
+    /// See [`FloatingScalar::exp_decay`].
+    fn exp_decay(self, target: Self, lambda: Self::Scalar, dt: Self::Scalar) -> Self {
+        self.lerp(target, Self::Scalar::ONE - (-lambda * dt).exp())
+    }
+    /// Check if every dimension of the vector is neither infinite nor `NaN`
+    fn is_finite(&self) -> bool {
+        (0..Self::N).all(|i| self.dim(i).is_finite())
+    }
+    /// Check if any dimension of the vector is `NaN`
+    fn is_nan_any(&self) -> bool {
+        (0..Self::N).any(|i| self.dim(i).is_nan())
+    }
+    /// Check if any dimension of the vector is `NaN`
+    ///
+    /// This is the same as [`FloatingVecN::is_nan_any`].
+    fn has_nan(&self) -> bool {
+        self.is_nan_any()
+    }
+    /// Check if every dimension of the vector is `NaN`
+    fn is_nan_all(&self) -> bool {
+        (0..Self::N).all(|i| self.dim(i).is_nan())
+    }
+    /// Check if every dimension of the vector is within `epsilon` of another's
+    ///
+    /// There is no feature for the `approx` crate's traits. The orphan rule forbids
+    /// implementing them for arrays outside of that crate, and it already implements them
+    /// for slices, so vectors can be compared with it as `a[..]` and `b[..]`.
+    fn approx_eq(&self, other: &Self, epsilon: Self::Scalar) -> bool {
+        (0..Self::N).all(|i| (self.dim(i) - other.dim(i)).abs() <= epsilon)
+    }
+    /// Check if every dimension of the vector is within `epsilon` of zero
+    fn approx_zero(&self, epsilon: Self::Scalar) -> bool {
+        (0..Self::N).all(|i| self.dim(i).abs() <= epsilon)
+    }
     /// Get the unit vector
+    ///
+    /// Returns the zero vector if the vector is zero. Use [`FloatingVecN::try_unit`]
+    /// to handle that case explicitly.
     fn unit(self) -> Self {
         let mag = self.mag();
         if mag.is_zero() {
@@ -269,6 +590,317 @@ where
             self.div(mag)
         }
     }
+    /// Get the unit vector
+    ///
+    /// Returns `None` if the vector is zero.
+    fn try_unit(self) -> Option<Self> {
+        let mag = self.mag();
+        if mag.is_zero() {
+            None
+        } else {
+            Some(self.div(mag))
+        }
+    }
+    /// Get the unit vector, or a fallback if the vector is zero
+    fn unit_or(self, fallback: Self) -> Self {
+        self.try_unit().unwrap_or(fallback)
+    }
+    /// Get the vector in the same direction with the given magnitude
+    ///
+    /// Returns the zero vector if the vector is zero.
+    fn with_mag(self, mag: Self::Scalar) -> Self {
+        self.unit().mul(mag)
+    }
+    /// Scale the vector so that its magnitude is between a minimum and maximum
+    ///
+    /// Returns the zero vector if the vector is zero.
+    fn clamp_mag(self, min: Self::Scalar, max: Self::Scalar) -> Self {
+        let mag = self.mag();
+        if mag.is_zero() {
+            Self::ZERO
+        } else if mag < min {
+            self.mul(min / mag)
+        } else if mag > max {
+            self.mul(max / mag)
+        } else {
+            self
+        }
+    }
+    /// Scale the vector down so that its magnitude is at most a maximum
+    fn limit(self, max: Self::Scalar) -> Self {
+        let mag = self.mag();
+        if mag > max {
+            self.mul(max / mag)
+        } else {
+            self
+        }
+    }
+    /// Get the component of the vector parallel to another
+    ///
+    /// Returns the zero vector if the other vector is zero.
+    fn project_onto(self, other: Self) -> Self
+    where
+        Self: Copy,
+    {
+        let squared_mag = other.squared_mag();
+        if squared_mag.is_zero() {
+            Self::ZERO
+        } else {
+            other.mul(self.dot(other) / squared_mag)
+        }
+    }
+    /// Get the component of the vector perpendicular to another
+    ///
+    /// Returns the vector itself if the other vector is zero.
+    fn reject_from(self, other: Self) -> Self
+    where
+        Self: Copy,
+    {
+        self.sub(self.project_onto(other))
+    }
+    /// Get the cosine of the angle between the vector and another
+    ///
+    /// This is in the range `[-1, 1]`. Returns `0` if either vector is zero.
+    fn cosine_similarity(self, other: Self) -> Self::Scalar {
+        let mags = self.mag() * other.mag();
+        if mags.is_zero() {
+            Self::Scalar::ZERO
+        } else {
+            (self.dot(other) / mags)
+                .maxx(-Self::Scalar::ONE)
+                .minn(Self::Scalar::ONE)
+        }
+    }
+    /// Get the unsigned angle in radians between the vector and another
+    ///
+    /// Returns `0` if either vector is zero.
+    fn angle_between(self, other: Self) -> Self::Scalar {
+        let mags = self.mag() * other.mag();
+        if mags.is_zero() {
+            Self::Scalar::ZERO
+        } else {
+            (self.dot(other) / mags)
+                .maxx(-Self::Scalar::ONE)
+                .minn(Self::Scalar::ONE)
+                .acos()
+        }
+    }
+    /// Reflect the vector off of a surface with the given unit normal
+    fn reflect(self, normal: Self) -> Self
+    where
+        Self: Copy,
+    {
+        self.sub(normal.mul(Self::Scalar::TWO * self.dot(normal)))
+    }
+    /// Refract the vector through a surface with the given unit normal
+    ///
+    /// `eta` is the ratio of the refractive index on the side the vector comes from
+    /// to that on the other side. The vector should be a unit vector pointing toward
+    /// the surface. Returns `None` on total internal reflection.
+    fn refract(self, normal: Self, eta: Self::Scalar) -> Option<Self>
+    where
+        Self: Copy,
+    {
+        let cos = self.dot(normal);
+        let k = Self::Scalar::ONE - eta * eta * (Self::Scalar::ONE - cos * cos);
+        if k < Self::Scalar::ZERO {
+            None
+        } else {
+            Some(self.mul(eta).sub(normal.mul(eta * cos + k.sqrt())))
+        }
+    }
+    /// Get the unit vector using [`FloatingScalar::rsqrt`]
+    ///
+    /// This is only faster than [`FloatingVecN::unit`] with the `fast-rsqrt` feature enabled.
+    fn normalize_fast(self) -> Self {
+        let squared_mag = self.squared_mag();
+        if squared_mag.is_zero() {
+            Self::ZERO
+        } else {
+            self.mul(squared_mag.rsqrt())
+        }
+    }
+    /// Check if the direction is within a cone around an axis
+    ///
+    /// The cone includes its boundary, and a `half_angle` of π or more includes every direction.
+    /// A zero direction or axis is never in the cone.
+    fn direction_in_cone(self, axis: Self, half_angle: Self::Scalar) -> bool {
+        let mags = self.mag() * axis.mag();
+        if mags.is_zero() || half_angle < Self::Scalar::ZERO {
+            false
+        } else if half_angle >= Self::Scalar::PI {
+            true
+        } else {
+            self.dot(axis) >= half_angle.cos() * mags
+        }
+    }
+    /// Check if the point is within an infinite cone with an apex, axis, and half angle in radians
+    ///
+    /// The apex itself is always within the cone.
+    fn within_cone(self, apex: Self, axis: Self, half_angle: Self::Scalar) -> bool {
+        let offset = self.sub(apex);
+        offset.squared_mag().is_zero() || offset.direction_in_cone(axis, half_angle)
+    }
+    /// Get the element-wise reciprocal
+    fn recip(mut self) -> Self {
+        for i in 0..Self::N {
+            self.set_dim(i, Self::Scalar::ONE / self.dim(i));
+        }
+        self
+    }
+    /// Get the element-wise reciprocal of the square root
+    fn rsqrt(mut self) -> Self {
+        for i in 0..Self::N {
+            self.set_dim(i, self.dim(i).rsqrt());
+        }
+        self
+    }
+    /// Raise each dimension to a floating-point power
+    fn powf(mut self, n: Self::Scalar) -> Self {
+        for i in 0..Self::N {
+            self.set_dim(i, self.dim(i).powf(n));
+        }
+        self
+    }
+    /// Raise each dimension to an integer power
+    fn powi(mut self, n: i32) -> Self {
+        for i in 0..Self::N {
+            self.set_dim(i, self.dim(i).powi(n));
+        }
+        self
+    }
+    /// Get the element-wise exponential
+    fn exp(mut self) -> Self {
+        for i in 0..Self::N {
+            self.set_dim(i, self.dim(i).exp());
+        }
+        self
+    }
+    /// Get the element-wise natural logarithm
+    fn ln(mut self) -> Self {
+        for i in 0..Self::N {
+            self.set_dim(i, self.dim(i).ln());
+        }
+        self
+    }
+    /// Get the element-wise square root
+    fn sqrt(mut self) -> Self {
+        for i in 0..Self::N {
+            self.set_dim(i, self.dim(i).sqrt());
+        }
+        self
+    }
+    /// Round each dimension down
+    fn floor(mut self) -> Self {
+        for i in 0..Self::N {
+            self.set_dim(i, self.dim(i).floor());
+        }
+        self
+    }
+    /// Round each dimension up
+    fn ceil(mut self) -> Self {
+        for i in 0..Self::N {
+            self.set_dim(i, self.dim(i).ceil());
+        }
+        self
+    }
+    /// Round each dimension to the nearest integer, rounding half-way cases away from zero
+    fn round(mut self) -> Self {
+        for i in 0..Self::N {
+            self.set_dim(i, self.dim(i).round());
+        }
+        self
+    }
+    /// Round each dimension toward zero
+    fn trunc(mut self) -> Self {
+        for i in 0..Self::N {
+            self.set_dim(i, self.dim(i).trunc());
+        }
+        self
+    }
+    /// Get the fractional part of each dimension
+    fn fract(mut self) -> Self {
+        for i in 0..Self::N {
+            self.set_dim(i, self.dim(i).fract());
+        }
+        self
+    }
+    /// Round each dimension down and convert the vector to an integer vector
+    ///
+    /// Returns `None` if a dimension does not fit in the integer type
+    /// or the vectors have different numbers of dimensions.
+    fn floor_int<I>(self) -> Option<I>
+    where
+        I: VecN,
+        I::Scalar: TryFrom<i64>,
+    {
+        to_int(self.floor())
+    }
+    /// Round each dimension up and convert the vector to an integer vector
+    ///
+    /// Returns `None` if a dimension does not fit in the integer type
+    /// or the vectors have different numbers of dimensions.
+    fn ceil_int<I>(self) -> Option<I>
+    where
+        I: VecN,
+        I::Scalar: TryFrom<i64>,
+    {
+        to_int(self.ceil())
+    }
+    /// Round each dimension to the nearest integer and convert the vector to an integer vector
+    ///
+    /// Returns `None` if a dimension does not fit in the integer type
+    /// or the vectors have different numbers of dimensions.
+    fn round_int<I>(self) -> Option<I>
+    where
+        I: VecN,
+        I::Scalar: TryFrom<i64>,
+    {
+        to_int(self.round())
+    }
+    /// Round each dimension toward zero and convert the vector to an integer vector
+    ///
+    /// Returns `None` if a dimension does not fit in the integer type
+    /// or the vectors have different numbers of dimensions.
+    fn trunc_int<I>(self) -> Option<I>
+    where
+        I: VecN,
+        I::Scalar: TryFrom<i64>,
+    {
+        to_int(self.trunc())
+    }
+    /// Element-wise step function
+    ///
+    /// Each dimension is `0` if it is less than the corresponding dimension of `edge` and `1` otherwise,
+    /// like GLSL's `step(edge, x)`.
+    fn step(mut self, edge: Self) -> Self {
+        for i in 0..Self::N {
+            let step = if self.dim(i) < edge.dim(i) {
+                Self::Scalar::ZERO
+            } else {
+                Self::Scalar::ONE
+            };
+            self.set_dim(i, step);
+        }
+        self
+    }
+    /// Element-wise smooth Hermite interpolation between `0` and `1`
+    ///
+    /// Like GLSL's `smoothstep(edge0, edge1, x)`, each dimension is `0` at or below `edge0`,
+    /// `1` at or above `edge1`, and smoothly interpolated between.
+    fn smoothstep_vec(mut self, edge0: Self, edge1: Self) -> Self {
+        for i in 0..Self::N {
+            let (e0, e1) = (edge0.dim(i), edge1.dim(i));
+            let t = ((self.dim(i) - e0) / (e1 - e0))
+                .maxx(Self::Scalar::ZERO)
+                .minn(Self::Scalar::ONE);
+            self.set_dim(
+                i,
+                t * t * (Self::Scalar::from_f64(3.0) - Self::Scalar::TWO * t),
+            );
+        }
+        self
+    }
 }
 
 impl<V> FloatingVecN for V
@@ -278,12 +910,144 @@ where
 {
 }
 
+/// Trait for integer vector operations that handle overflow
+pub trait IntegerVecN: VecN
+where
+    Self::Scalar: IntegerScalar,
+{
+    /// Divide each dimension by a scalar value, rounding so that the remainders are non-negative
+    ///
+    /// Unlike [`VecN::div`], this rounds negative values down, which is what is needed to
+    /// find the cell that contains a coordinate.
+    fn div_euclid(self, by: Self::Scalar) -> Self {
+        self.map(|d| d.div_euclid(by))
+    }
+    /// Get the non-negative remainder of dividing each dimension by a scalar value
+    fn rem_euclid(self, by: Self::Scalar) -> Self {
+        self.map(|d| d.rem_euclid(by))
+    }
+    /// Get the element-wise remainder of dividing the vector by another
+    ///
+    /// Like the `%` operator, the remainders have the same sign as the dimensions.
+    fn rem2(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a % b)
+    }
+    /// Shift the bits of each dimension left
+    ///
+    /// Like the `<<` operator, this panics in debug builds if `n` is at least the number of
+    /// bits in the scalar.
+    fn shl(self, n: u32) -> Self {
+        self.map(|d| d << n)
+    }
+    /// Shift the bits of each dimension right
+    ///
+    /// Like the `>>` operator, this is an arithmetic shift for signed scalars and panics in
+    /// debug builds if `n` is at least the number of bits in the scalar.
+    fn shr(self, n: u32) -> Self {
+        self.map(|d| d >> n)
+    }
+    /// Get the element-wise bitwise and of the vector and another
+    fn and(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a & b)
+    }
+    /// Get the element-wise bitwise or of the vector and another
+    fn or(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a | b)
+    }
+    /// Get the element-wise bitwise exclusive or of the vector and another
+    fn xor(self, other: Self) -> Self {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+    /// Add the vector to another, returning `None` if any dimension overflows
+    fn checked_add(self, other: Self) -> Option<Self> {
+        checked(|i| self.dim(i).checked_add(other.dim(i)))
+    }
+    /// Subtract a vector from the one, returning `None` if any dimension overflows
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        checked(|i| self.dim(i).checked_sub(other.dim(i)))
+    }
+    /// Multiply the vector by a scalar value, returning `None` if any dimension overflows
+    fn checked_mul(self, by: Self::Scalar) -> Option<Self> {
+        checked(|i| self.dim(i).checked_mul(by))
+    }
+    /// Add the vector to another, wrapping around on overflow
+    fn wrapping_add(self, other: Self) -> Self {
+        self.zip_with(other, IntegerScalar::wrapping_add)
+    }
+    /// Subtract a vector from the one, wrapping around on overflow
+    fn wrapping_sub(self, other: Self) -> Self {
+        self.zip_with(other, IntegerScalar::wrapping_sub)
+    }
+    /// Multiply the vector by a scalar value, wrapping around on overflow
+    fn wrapping_mul(self, by: Self::Scalar) -> Self {
+        self.map(|d| d.wrapping_mul(by))
+    }
+    /// Add the vector to another, clamping to the bounds of the scalar on overflow
+    fn saturating_add(self, other: Self) -> Self {
+        self.zip_with(other, IntegerScalar::saturating_add)
+    }
+    /// Subtract a vector from the one, clamping to the bounds of the scalar on overflow
+    fn saturating_sub(self, other: Self) -> Self {
+        self.zip_with(other, IntegerScalar::saturating_sub)
+    }
+    /// Multiply the vector by a scalar value, clamping to the bounds of the scalar on overflow
+    fn saturating_mul(self, by: Self::Scalar) -> Self {
+        self.map(|d| d.saturating_mul(by))
+    }
+}
+
+impl<V> IntegerVecN for V
+where
+    V: VecN,
+    V::Scalar: IntegerScalar,
+{
+}
+
+/// Build a vector from a fallible function on each dimension index
+fn checked<V, F>(mut f: F) -> Option<V>
+where
+    V: VecN,
+    F: FnMut(usize) -> Option<V::Scalar>,
+{
+    let mut v = V::ZERO;
+    for i in 0..V::N {
+        *v.dim_mut(i) = f(i)?;
+    }
+    Some(v)
+}
+
+/// Convert a vector of whole numbers to an integer vector
+fn to_int<V, I>(v: V) -> Option<I>
+where
+    V: VecN,
+    V::Scalar: FloatingScalar,
+    I: VecN,
+    I::Scalar: TryFrom<i64>,
+{
+    if V::N != I::N {
+        return None;
+    }
+    let mut int = I::ZERO;
+    for i in 0..V::N {
+        let d = v.dim(i).to_f64();
+        // The upper bound is exclusive because it rounds up to 2^63
+        if !(d >= i64::MIN as f64 && d < i64::MAX as f64) {
+            return None;
+        }
+        int.set_dim(i, I::Scalar::try_from(d as i64).ok()?);
+    }
+    Some(int)
+}
+
 impl<T, const N: usize> VecN for [T; N]
 where
     T: Scalar,
 {
     const N: usize = N;
     const ZERO: Self = [T::ZERO; N];
+    const ONE: Self = [T::ONE; N];
+    const MIN: Self = [T::MIN; N];
+    const MAX: Self = [T::MAX; N];
     type Scalar = T;
     fn dim(&self, dim: usize) -> Self::Scalar {
         self[dim]
@@ -293,6 +1057,97 @@ where
     }
 }
 
+/// Trait for comparing vectors per dimension and selecting dimensions with masks
+pub trait MaskVec: VecN {
+    /// A boolean for each dimension
+    type Mask: Copy + Index<usize, Output = bool> + IndexMut<usize>;
+    /// Create a mask by calling a function on each dimension index
+    fn mask_from_fn<F>(f: F) -> Self::Mask
+    where
+        F: FnMut(usize) -> bool;
+    /// Take each dimension from another vector where the mask is `true`
+    ///
+    /// Dimensions where the mask is `false` are kept from this vector.
+    fn select(mut self, mask: Self::Mask, other: Self) -> Self {
+        for i in 0..Self::N {
+            if mask[i] {
+                *self.dim_mut(i) = other.dim(i);
+            }
+        }
+        self
+    }
+    /// Get a mask of the dimensions that are less than those of another vector
+    fn lt2(&self, other: &Self) -> Self::Mask {
+        Self::mask_from_fn(|i| self.dim(i) < other.dim(i))
+    }
+    /// Get a mask of the dimensions that are less than or equal to those of another vector
+    fn le2(&self, other: &Self) -> Self::Mask {
+        Self::mask_from_fn(|i| self.dim(i) <= other.dim(i))
+    }
+    /// Get a mask of the dimensions that are greater than those of another vector
+    fn gt2(&self, other: &Self) -> Self::Mask {
+        Self::mask_from_fn(|i| self.dim(i) > other.dim(i))
+    }
+    /// Get a mask of the dimensions that are greater than or equal to those of another vector
+    fn ge2(&self, other: &Self) -> Self::Mask {
+        Self::mask_from_fn(|i| self.dim(i) >= other.dim(i))
+    }
+    /// Get a mask of the dimensions that are equal to those of another vector
+    fn eq2(&self, other: &Self) -> Self::Mask {
+        Self::mask_from_fn(|i| self.dim(i) == other.dim(i))
+    }
+}
+
+impl<T, const N: usize> MaskVec for [T; N]
+where
+    T: Scalar,
+{
+    type Mask = [bool; N];
+    fn mask_from_fn<F>(f: F) -> Self::Mask
+    where
+        F: FnMut(usize) -> bool,
+    {
+        std::array::from_fn(f)
+    }
+}
+
+/// Trait for 3D vector operations
+///
+/// This is only implemented for 3D arrays, so vectors of other sizes cannot be crossed.
+pub trait Vec3: VecN {
+    /// Get the cross product of the vector with another
+    fn cross(self, other: Self) -> Self;
+    /// Rotate the vector around an axis through the origin by an angle in radians
+    ///
+    /// The rotation is counter-clockwise when looking down the axis toward the origin.
+    /// A zero axis leaves the vector unchanged.
+    fn rotate_around(self, axis: Self, angle: Self::Scalar) -> Self
+    where
+        Self: Copy,
+        Self::Scalar: FloatingScalar,
+    {
+        let mag = axis.mag();
+        if mag.is_zero() {
+            return self;
+        }
+        let axis = axis.div(mag);
+        let [cos, sin] = angle.angle_as_vector();
+        self.mul(cos)
+            .add(axis.cross(self).mul(sin))
+            .add(axis.mul(axis.dot(self) * (Self::Scalar::ONE - cos)))
+    }
+}
+
+impl<T> Vec3 for [T; 3]
+where
+    T: Scalar,
+{
+    fn cross(self, [x, y, z]: Self) -> Self {
+        let [a, b, c] = self;
+        [b * z - c * y, c * x - a * z, a * y - b * x]
+    }
+}
+
 macro_rules! dim_trait {
     ($doc:literal, $trait:ident, $get:ident, $get_mut:ident, $set:ident, $index:literal) => {
         #[doc = $doc]
@@ -325,3 +1180,133 @@ macro_rules! dim_trait {
 #[rustfmt::skip] dim_trait!("Trait for vectors with a Y dimension", YVec, y, y_mut, set_y, 1);
 #[rustfmt::skip] dim_trait!("Trait for vectors with a Z dimension", ZVec, z, z_mut, set_z, 2);
 #[rustfmt::skip] dim_trait!("Trait for vectors with a W dimension", WVec, w, w_mut, set_w, 3);
+
+/// Trait for adding a dimension to the end of a vector
+pub trait ExtendVec: VecN {
+    /// The vector type with one more dimension
+    type Extended: VecN<Scalar = Self::Scalar>;
+    /// Add a dimension with a value to the end of the vector
+    fn extend(self, value: Self::Scalar) -> Self::Extended;
+}
+
+/// Trait for removing the last dimension of a vector
+pub trait TruncateVec: VecN {
+    /// The vector type with one less dimension
+    type Truncated: VecN<Scalar = Self::Scalar>;
+    /// Remove the last dimension of the vector
+    fn truncate(self) -> Self::Truncated;
+}
+
+/// Trait for converting a vector to one of the same dimension with a different scalar type
+pub trait CastVec: VecN {
+    /// The vector type with the same dimension and a different scalar type
+    type Cast<U: Scalar>: VecN<Scalar = U>;
+    /// Convert the vector to one with a different scalar type
+    ///
+    /// Each dimension is converted with [`CastScalar`]. The dimension is kept, so
+    /// `[i32; 3]` can only be cast to arrays like `[f32; 3]`.
+    fn cast<U>(self) -> Self::Cast<U>
+    where
+        U: Scalar,
+        Self::Scalar: CastScalar<U>;
+}
+
+impl<T, const N: usize> CastVec for [T; N]
+where
+    T: Scalar,
+{
+    type Cast<U: Scalar> = [U; N];
+    fn cast<U>(self) -> [U; N]
+    where
+        U: Scalar,
+        T: CastScalar<U>,
+    {
+        self.map(CastScalar::cast)
+    }
+}
+
+macro_rules! resize_impl {
+    ($small:literal, $large:literal) => {
+        impl<T> ExtendVec for [T; $small]
+        where
+            T: Scalar,
+        {
+            type Extended = [T; $large];
+            fn extend(self, value: T) -> Self::Extended {
+                std::array::from_fn(|i| if i < $small { self[i] } else { value })
+            }
+        }
+
+        impl<T> TruncateVec for [T; $large]
+        where
+            T: Scalar,
+        {
+            type Truncated = [T; $small];
+            fn truncate(self) -> Self::Truncated {
+                std::array::from_fn(|i| self[i])
+            }
+        }
+    };
+}
+
+resize_impl!(1, 2);
+resize_impl!(2, 3);
+resize_impl!(3, 4);
+
+macro_rules! swizzle_methods {
+    ($($name:ident: $len:literal [$($dim:literal),*],)*) => {
+        $(
+            #[doc = concat!("Get the `", stringify!($name), "` swizzle of the vector")]
+            fn $name(&self) -> [Self::Scalar; $len] {
+                [$(self.dim($dim)),*]
+            }
+        )*
+    };
+}
+
+/// Trait for swizzling the named dimensions of a vector into new arrays
+///
+/// Each method takes the named dimensions in order. Like [`XVec`] and the other named
+/// dimension traits, methods that name a dimension the vector does not have will panic.
+pub trait Swizzle: VecN {
+    swizzle_methods! {
+        xy: 2 [0, 1],
+        xz: 2 [0, 2],
+        xw: 2 [0, 3],
+        yx: 2 [1, 0],
+        yz: 2 [1, 2],
+        yw: 2 [1, 3],
+        zx: 2 [2, 0],
+        zy: 2 [2, 1],
+        zw: 2 [2, 3],
+        wx: 2 [3, 0],
+        wy: 2 [3, 1],
+        wz: 2 [3, 2],
+        xyz: 3 [0, 1, 2],
+        xyw: 3 [0, 1, 3],
+        xzy: 3 [0, 2, 1],
+        xzw: 3 [0, 2, 3],
+        xwy: 3 [0, 3, 1],
+        xwz: 3 [0, 3, 2],
+        yxz: 3 [1, 0, 2],
+        yxw: 3 [1, 0, 3],
+        yzx: 3 [1, 2, 0],
+        yzw: 3 [1, 2, 3],
+        ywx: 3 [1, 3, 0],
+        ywz: 3 [1, 3, 2],
+        zxy: 3 [2, 0, 1],
+        zxw: 3 [2, 0, 3],
+        zyx: 3 [2, 1, 0],
+        zyw: 3 [2, 1, 3],
+        zwx: 3 [2, 3, 0],
+        zwy: 3 [2, 3, 1],
+        wxy: 3 [3, 0, 1],
+        wxz: 3 [3, 0, 2],
+        wyx: 3 [3, 1, 0],
+        wyz: 3 [3, 1, 2],
+        wzx: 3 [3, 2, 0],
+        wzy: 3 [3, 2, 1],
+    }
+}
+
+impl<V> Swizzle for V where V: VecN {}
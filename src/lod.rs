@@ -0,0 +1,86 @@
+use crate::{FloatingScalar, Scalar};
+
+/// Bands that map a distance or screen size to a level of detail
+///
+/// Level `0` is the most detailed. A value must pass a band's threshold by the hysteresis
+/// before [`LodBands::select`] switches levels, so objects near a threshold do not flicker
+/// between levels.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LodBands<T> {
+    /// The thresholds in the order they are crossed as detail decreases, negated for sizes
+    thresholds: Vec<T>,
+    hysteresis: T,
+    by_size: bool,
+}
+
+impl<T> LodBands<T>
+where
+    T: Scalar,
+{
+    /// Create bands that lower the detail as distance increases
+    ///
+    /// Distances below the first threshold are level `0`, distances between the first and
+    /// second are level `1`, and so on. The thresholds are sorted.
+    pub fn by_distance(thresholds: impl IntoIterator<Item = T>, hysteresis: T) -> Self {
+        Self::new(thresholds.into_iter().collect(), hysteresis, false)
+    }
+    /// Create bands that lower the detail as screen size decreases
+    ///
+    /// Sizes above the largest threshold are level `0`, sizes between the largest and the
+    /// next are level `1`, and so on. The thresholds are sorted.
+    pub fn by_screen_size(thresholds: impl IntoIterator<Item = T>, hysteresis: T) -> Self {
+        let thresholds = thresholds.into_iter().map(|t| T::ZERO - t).collect();
+        Self::new(thresholds, hysteresis, true)
+    }
+    fn new(mut thresholds: Vec<T>, hysteresis: T, by_size: bool) -> Self {
+        thresholds.sort_by(|a, b| a.partial_cmp(b).expect("threshold comparison failed"));
+        LodBands {
+            thresholds,
+            hysteresis,
+            by_size,
+        }
+    }
+    /// Get the number of levels
+    pub fn levels(&self) -> usize {
+        self.thresholds.len() + 1
+    }
+    fn key(&self, value: T) -> T {
+        if self.by_size {
+            T::ZERO - value
+        } else {
+            value
+        }
+    }
+    /// Get the level for a distance or size, ignoring hysteresis
+    pub fn level(&self, value: T) -> usize {
+        let key = self.key(value);
+        self.thresholds.partition_point(|&t| t <= key)
+    }
+    /// Get the level for a distance or size given the current level
+    ///
+    /// The level only changes once the value is past a threshold by the hysteresis.
+    /// Current levels out of range are clamped.
+    pub fn select(&self, value: T, current: usize) -> usize {
+        let key = self.key(value);
+        let mut level = current.min(self.thresholds.len());
+        while level < self.thresholds.len() && key >= self.thresholds[level] + self.hysteresis {
+            level += 1;
+        }
+        while level > 0 && key < self.thresholds[level - 1] - self.hysteresis {
+            level -= 1;
+        }
+        level
+    }
+}
+
+/// Get the height in pixels of a sphere on the screen of a perspective camera
+///
+/// `fov_y` is the vertical field of view in radians. This uses the small angle
+/// approximation, so it is less accurate for spheres very close to the camera.
+pub fn projected_size<T>(radius: T, distance: T, fov_y: T, viewport_height: T) -> T
+where
+    T: FloatingScalar,
+{
+    let distance = distance.maxx(T::EPSILON);
+    radius / (distance * (fov_y / T::TWO).tan()) * viewport_height
+}
@@ -0,0 +1,251 @@
+use crate::{Aabb, FloatingScalar, FloatingVecN, Vec3, VecN};
+
+/// The mass properties of a solid 3D shape
+///
+/// The inertia tensor is taken about the center of mass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MassProperties<T> {
+    /// The volume of the shape
+    pub volume: T,
+    /// The mass of the shape
+    pub mass: T,
+    /// The center of mass
+    pub center: [T; 3],
+    /// The inertia tensor about the center of mass
+    pub inertia: [[T; 3]; 3],
+}
+
+impl<T> MassProperties<T>
+where
+    T: FloatingScalar,
+{
+    fn diagonal(volume: T, density: T, center: [T; 3], moments: [T; 3]) -> Self {
+        let mut inertia = [[T::ZERO; 3]; 3];
+        for (i, m) in moments.into_iter().enumerate() {
+            inertia[i][i] = m;
+        }
+        MassProperties {
+            volume,
+            mass: volume * density,
+            center,
+            inertia,
+        }
+    }
+    /// Get the mass properties of a solid box with uniform density
+    pub fn aabb<A>(aabb: A, density: T) -> Self
+    where
+        A: Aabb<Vector = [T; 3]>,
+    {
+        let [w, h, d] = [aabb.size_dim(0), aabb.size_dim(1), aabb.size_dim(2)];
+        let volume = w * h * d;
+        let k = volume * density / T::from_f64(12.0);
+        let moments = [
+            k * (h * h + d * d),
+            k * (w * w + d * d),
+            k * (w * w + h * h),
+        ];
+        Self::diagonal(volume, density, aabb.center(), moments)
+    }
+    /// Get the mass properties of a solid sphere with uniform density
+    pub fn sphere(center: [T; 3], radius: T, density: T) -> Self {
+        let volume = T::from_f64(4.0 / 3.0) * T::PI * radius * radius * radius;
+        let moment = T::from_f64(0.4) * volume * density * radius * radius;
+        Self::diagonal(volume, density, center, [moment; 3])
+    }
+    /// Get the mass properties of a solid capsule with uniform density
+    ///
+    /// The capsule is the set of points within `radius` of the segment from `a` to `b`.
+    pub fn capsule(a: [T; 3], b: [T; 3], radius: T, density: T) -> Self {
+        let axis = b.sub(a);
+        let h = axis.mag();
+        let r2 = radius * radius;
+        let cyl_volume = T::PI * r2 * h;
+        let sph_volume = T::from_f64(4.0 / 3.0) * T::PI * r2 * radius;
+        let cyl_mass = cyl_volume * density;
+        let sph_mass = sph_volume * density;
+        let axial = cyl_mass * r2 / T::TWO + sph_mass * T::from_f64(0.4) * r2;
+        let perp = cyl_mass * (h * h / T::from_f64(12.0) + r2 / T::from_f64(4.0))
+            + sph_mass
+                * (T::from_f64(0.4) * r2
+                    + h * h / T::from_f64(4.0)
+                    + T::from_f64(3.0 / 8.0) * h * radius);
+        let dir = axis.unit();
+        let mut inertia = [[T::ZERO; 3]; 3];
+        for (i, row) in inertia.iter_mut().enumerate() {
+            for (j, val) in row.iter_mut().enumerate() {
+                let id = if i == j { perp } else { T::ZERO };
+                *val = id + (axial - perp) * dir[i] * dir[j];
+            }
+        }
+        MassProperties {
+            volume: cyl_volume + sph_volume,
+            mass: cyl_mass + sph_mass,
+            center: a.lerp(b, T::ONE / T::TWO),
+            inertia,
+        }
+    }
+    /// Get the mass properties of a closed triangle mesh with uniform density
+    ///
+    /// Triangles must be wound counter-clockwise when viewed from outside the mesh.
+    ///
+    /// Returns `None` if the mesh encloses no volume, or if it is wound inside out so that
+    /// its volume is negative.
+    pub fn triangle_mesh(triangles: &[[[T; 3]; 3]], density: T) -> Option<Self> {
+        fn subexpressions<T: FloatingScalar>(w0: T, w1: T, w2: T) -> [T; 6] {
+            let temp0 = w0 + w1;
+            let f1 = temp0 + w2;
+            let temp1 = w0 * w0;
+            let temp2 = temp1 + w1 * temp0;
+            let f2 = temp2 + w2 * f1;
+            let f3 = w0 * temp1 + w1 * temp2 + w2 * f2;
+            let g0 = f2 + w0 * (f1 + w0);
+            let g1 = f2 + w1 * (f1 + w1);
+            let g2 = f2 + w2 * (f1 + w2);
+            [f1, f2, f3, g0, g1, g2]
+        }
+        let mut integral = [T::ZERO; 10];
+        for &[[x0, y0, z0], [x1, y1, z1], [x2, y2, z2]] in triangles {
+            let e1 = [x1 - x0, y1 - y0, z1 - z0];
+            let e2 = [x2 - x0, y2 - y0, z2 - z0];
+            let d = [
+                e1[1] * e2[2] - e1[2] * e2[1],
+                e1[2] * e2[0] - e1[0] * e2[2],
+                e1[0] * e2[1] - e1[1] * e2[0],
+            ];
+            let [f1x, f2x, f3x, g0x, g1x, g2x] = subexpressions(x0, x1, x2);
+            let [_, f2y, f3y, g0y, g1y, g2y] = subexpressions(y0, y1, y2);
+            let [_, f2z, f3z, g0z, g1z, g2z] = subexpressions(z0, z1, z2);
+            integral[0] += d[0] * f1x;
+            integral[1] += d[0] * f2x;
+            integral[2] += d[1] * f2y;
+            integral[3] += d[2] * f2z;
+            integral[4] += d[0] * f3x;
+            integral[5] += d[1] * f3y;
+            integral[6] += d[2] * f3z;
+            integral[7] += d[0] * (y0 * g0x + y1 * g1x + y2 * g2x);
+            integral[8] += d[1] * (z0 * g0y + z1 * g1y + z2 * g2y);
+            integral[9] += d[2] * (x0 * g0z + x1 * g1z + x2 * g2z);
+        }
+        let mults = [
+            1.0 / 6.0,
+            1.0 / 24.0,
+            1.0 / 24.0,
+            1.0 / 24.0,
+            1.0 / 60.0,
+            1.0 / 60.0,
+            1.0 / 60.0,
+            1.0 / 120.0,
+            1.0 / 120.0,
+            1.0 / 120.0,
+        ];
+        for (val, mult) in integral.iter_mut().zip(mults) {
+            *val *= T::from_f64(mult);
+        }
+        let volume = integral[0];
+        if volume <= T::ZERO {
+            return None;
+        }
+        let center = [integral[1], integral[2], integral[3]].div(volume);
+        let [cx, cy, cz] = center;
+        let xx = integral[5] + integral[6] - volume * (cy * cy + cz * cz);
+        let yy = integral[4] + integral[6] - volume * (cz * cz + cx * cx);
+        let zz = integral[4] + integral[5] - volume * (cx * cx + cy * cy);
+        let xy = volume * cx * cy - integral[7];
+        let yz = volume * cy * cz - integral[8];
+        let xz = volume * cz * cx - integral[9];
+        let inertia = [[xx, xy, xz], [xy, yy, yz], [xz, yz, zz]];
+        Some(MassProperties {
+            volume,
+            mass: volume * density,
+            center,
+            inertia: inertia.map(|row| row.map(|i| i * density)),
+        })
+    }
+    /// Get the mass properties of the convex hull of some points with uniform density
+    ///
+    /// Returns `None` if the points all lie on one plane.
+    pub fn convex_hull(points: &[[T; 3]], density: T) -> Option<Self> {
+        let triangles: Vec<[[T; 3]; 3]> = hull_faces(points)?
+            .into_iter()
+            .map(|face| face.map(|i| points[i]))
+            .collect();
+        Self::triangle_mesh(&triangles, density)
+    }
+}
+
+/// Get the faces of the convex hull of some points as counter-clockwise triples of indices
+///
+/// Points are added one at a time. Each point replaces the faces it can see with a fan of
+/// faces from their boundary to the point. Returns `None` if the points all lie on one
+/// plane.
+fn hull_faces<T>(points: &[[T; 3]]) -> Option<Vec<[usize; 3]>>
+where
+    T: FloatingScalar,
+{
+    if points.len() < 4 {
+        return None;
+    }
+    let (min, max) = points
+        .iter()
+        .fold((points[0], points[0]), |(min, max), &p| {
+            (min.min2(p), max.max2(p))
+        });
+    let extent = max.sub(min).max_dim();
+    // Plane distances are scaled by the area of the face, so the tolerance is a volume
+    let tolerance = extent * extent * extent * T::EPSILON * T::from_f64(64.0);
+    let normal = |[a, b, c]: [usize; 3]| points[b].sub(points[a]).cross(points[c].sub(points[a]));
+    let height = |face: [usize; 3], p: usize| normal(face).dot(points[p].sub(points[face[0]]));
+    let farthest = |measure: &dyn Fn(usize) -> T| {
+        (0..points.len())
+            .map(|i| (i, measure(i)))
+            .max_by(|a, b| {
+                a.1.partial_cmp(&b.1)
+                    .expect("hull distance comparison failed")
+            })
+            .expect("there are points")
+    };
+    // Start with the largest tetrahedron that is quick to find
+    let (i1, _) = farthest(&|i| points[i].squared_dist(points[0]));
+    let (i2, area) = farthest(&|i| {
+        let [a, b, c] = [points[0], points[i1], points[i]];
+        b.sub(a).cross(c.sub(a)).squared_mag()
+    });
+    let (i3, volume) = farthest(&|i| height([0, i1, i2], i).abs());
+    if area.is_zero() || volume <= tolerance {
+        return None;
+    }
+    let tetra = [0, i1, i2, i3];
+    let mut faces: Vec<[usize; 3]> = [[0, 1, 2], [0, 1, 3], [0, 2, 3], [1, 2, 3]]
+        .into_iter()
+        .map(|face| {
+            let face = face.map(|k| tetra[k]);
+            let opposite = tetra.into_iter().find(|i| !face.contains(i));
+            let opposite = opposite.expect("tetrahedra have an opposite corner");
+            if height(face, opposite) > T::ZERO {
+                [face[0], face[2], face[1]]
+            } else {
+                face
+            }
+        })
+        .collect();
+    for p in 0..points.len() {
+        if tetra[..].contains(&p) {
+            continue;
+        }
+        let (visible, hidden): (Vec<_>, Vec<_>) = faces
+            .into_iter()
+            .partition(|&face| height(face, p) > tolerance);
+        faces = hidden;
+        // The boundary of the visible faces is their edges that no other visible face shares
+        let edges: Vec<[usize; 2]> = visible
+            .iter()
+            .flat_map(|&[a, b, c]| [[a, b], [b, c], [c, a]])
+            .collect();
+        for &[a, b] in &edges {
+            if !edges.contains(&[b, a]) {
+                faces.push([a, b, p]);
+            }
+        }
+    }
+    Some(faces)
+}
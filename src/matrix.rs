@@ -0,0 +1,181 @@
+use crate::FloatingScalar;
+
+/// The number of terms in the Padé approximant used by [`matrix_exp`]
+const PADE_TERMS: usize = 6;
+
+/// The maximum number of iterations of the square root and series in [`matrix_ln`]
+const MAX_ITERATIONS: usize = 64;
+
+/// Get the identity matrix
+fn identity<T, const N: usize>() -> [[T; N]; N]
+where
+    T: FloatingScalar,
+{
+    std::array::from_fn(|i| std::array::from_fn(|j| if i == j { T::ONE } else { T::ZERO }))
+}
+
+/// Multiply two matrices given as rows
+fn mul<T, const N: usize>(a: &[[T; N]; N], b: &[[T; N]; N]) -> [[T; N]; N]
+where
+    T: FloatingScalar,
+{
+    std::array::from_fn(|i| {
+        std::array::from_fn(|j| (0..N).fold(T::ZERO, |acc, k| acc + a[i][k] * b[k][j]))
+    })
+}
+
+/// Combine two matrices element-wise
+fn zip<T, const N: usize>(a: &[[T; N]; N], b: &[[T; N]; N], f: impl Fn(T, T) -> T) -> [[T; N]; N]
+where
+    T: FloatingScalar,
+{
+    std::array::from_fn(|i| std::array::from_fn(|j| f(a[i][j], b[i][j])))
+}
+
+/// Get the largest absolute row sum of a matrix
+fn norm<T, const N: usize>(a: &[[T; N]; N]) -> T
+where
+    T: FloatingScalar,
+{
+    a.iter().fold(T::ZERO, |acc, row| {
+        acc.maxx(row.iter().fold(T::ZERO, |acc, x| acc + x.abs()))
+    })
+}
+
+/// Solve `a * x = b` for `x` with Gaussian elimination
+///
+/// Returns `None` if `a` is singular.
+fn solve<T, const N: usize>(mut a: [[T; N]; N], mut b: [[T; N]; N]) -> Option<[[T; N]; N]>
+where
+    T: FloatingScalar,
+{
+    for col in 0..N {
+        let pivot = (col..N)
+            .max_by(|&i, &j| {
+                a[i][col]
+                    .abs()
+                    .partial_cmp(&a[j][col].abs())
+                    .expect("pivot comparison failed")
+            })
+            .unwrap_or(col);
+        if a[pivot][col].is_zero() {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        for row in 0..N {
+            if row == col {
+                continue;
+            }
+            let factor = a[row][col] / a[col][col];
+            let (a_col, b_col) = (a[col], b[col]);
+            a[row] = std::array::from_fn(|k| a[row][k] - factor * a_col[k]);
+            b[row] = std::array::from_fn(|k| b[row][k] - factor * b_col[k]);
+        }
+    }
+    Some(std::array::from_fn(|i| b[i].map(|x| x / a[i][i])))
+}
+
+/// Get the exponential of a square matrix
+///
+/// This uses scaling and squaring with a Padé approximant. The matrix may be given as rows
+/// or as columns, and the result is in the same form.
+///
+/// Transforms can be interpolated smoothly by interpolating their logarithms from
+/// [`matrix_ln`] and taking the exponential of the result.
+pub fn matrix_exp<T, const N: usize>(a: [[T; N]; N]) -> [[T; N]; N]
+where
+    T: FloatingScalar,
+{
+    // Scale the matrix down until the approximant is accurate
+    let mut squarings = 0;
+    let mut scale = T::ONE;
+    while norm(&a) * scale > T::ONE / T::TWO {
+        scale /= T::TWO;
+        squarings += 1;
+    }
+    let a = a.map(|row| row.map(|x| x * scale));
+    let mut numer = identity();
+    let mut denom = identity();
+    let mut power = identity();
+    let mut c = T::ONE;
+    for k in 1..=PADE_TERMS {
+        c = c * T::from_f64((PADE_TERMS - k + 1) as f64)
+            / T::from_f64(((2 * PADE_TERMS - k + 1) * k) as f64);
+        power = mul(&a, &power);
+        numer = zip(&numer, &power, |n, p| n + c * p);
+        let sign = if k % 2 == 0 { T::ONE } else { -T::ONE };
+        denom = zip(&denom, &power, |d, p| d + sign * c * p);
+    }
+    // The denominator is close to the identity, so it is never singular
+    let mut result = solve(denom, numer).expect("Padé denominator is singular");
+    for _ in 0..squarings {
+        result = mul(&result, &result);
+    }
+    result
+}
+
+/// Get the principal logarithm of a square matrix
+///
+/// This is the inverse of [`matrix_exp`]. It uses inverse scaling and squaring: the matrix
+/// is square rooted until it is close to the identity, and then the logarithm of that is
+/// found with a series. The matrix may be given as rows or as columns, and the result is
+/// in the same form.
+///
+/// Returns `None` if the matrix has no real logarithm, such as when it is singular or has
+/// negative eigenvalues.
+pub fn matrix_ln<T, const N: usize>(a: [[T; N]; N]) -> Option<[[T; N]; N]>
+where
+    T: FloatingScalar,
+{
+    let id = identity();
+    let close = T::ONE / T::from_f64(4.0);
+    // The square root converges quadratically, so this change means the error is tiny
+    let tolerance = T::EPSILON.sqrt();
+    let mut a = a;
+    let mut scale = T::ONE;
+    let mut roots = 0;
+    while norm(&zip(&a, &id, |x, i| x - i)) > close {
+        if roots == MAX_ITERATIONS {
+            return None;
+        }
+        // Take the square root with the Denman–Beavers iteration
+        let (mut y, mut z) = (a, id);
+        let mut converged = false;
+        for _ in 0..MAX_ITERATIONS {
+            let y_inv = solve(y, id)?;
+            let z_inv = solve(z, id)?;
+            let next = zip(&y, &z_inv, |y, z| (y + z) / T::TWO);
+            z = zip(&z, &y_inv, |z, y| (z + y) / T::TWO);
+            let change = norm(&zip(&next, &y, |a, b| a - b));
+            y = next;
+            if change.is_nan() {
+                return None;
+            }
+            if change <= tolerance * norm(&y) {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return None;
+        }
+        a = y;
+        scale *= T::TWO;
+        roots += 1;
+    }
+    // ln(a) = 2 atanh(x) with x = (a + I)⁻¹(a - I), which converges quickly near the identity
+    let x = solve(zip(&a, &id, |a, i| a + i), zip(&a, &id, |a, i| a - i))?;
+    let x2 = mul(&x, &x);
+    let mut power = x;
+    let mut sum = x;
+    for k in 1..MAX_ITERATIONS {
+        power = mul(&power, &x2);
+        let term = power.map(|row| row.map(|p| p / T::from_f64((2 * k + 1) as f64)));
+        sum = zip(&sum, &term, |s, t| s + t);
+        if norm(&term) <= T::EPSILON * norm(&sum) {
+            break;
+        }
+    }
+    Some(sum.map(|row| row.map(|s| s * T::TWO * scale)))
+}
@@ -0,0 +1,176 @@
+use crate::{eigen::symmetric_eigen, Aabb, FloatingScalar, Ray, RayHit, VecN};
+
+/// An N-dimensional oriented bounding box
+///
+/// The box's local axes are the rows of `axes`, which should be orthonormal.
+/// Each half extent is the distance from the center to a face along the corresponding
+/// local axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Obb<T, const N: usize> {
+    /// The center of the box
+    pub center: [T; N],
+    /// The local axes
+    pub axes: [[T; N]; N],
+    /// The half extent along each local axis
+    pub half_extents: [T; N],
+}
+
+impl<T, const N: usize> Obb<T, N>
+where
+    T: FloatingScalar,
+{
+    /// Create a new axis-aligned box
+    pub fn new(center: [T; N], half_extents: [T; N]) -> Self {
+        let axes =
+            std::array::from_fn(|i| std::array::from_fn(|j| if i == j { T::ONE } else { T::ZERO }));
+        Obb {
+            center,
+            axes,
+            half_extents,
+        }
+    }
+    /// Create a new box with the given orthonormal local axes
+    pub fn with_axes(center: [T; N], axes: [[T; N]; N], half_extents: [T; N]) -> Self {
+        Obb {
+            center,
+            axes,
+            half_extents,
+        }
+    }
+    /// Fit a box to some points
+    ///
+    /// The axes are the eigenvectors of the points' covariance, ordered from the direction
+    /// of most spread to least, and form a right-handed basis. The box is then made just
+    /// large enough to contain every point. This is fast and usually tight for elongated
+    /// point sets, but it is not always the smallest possible box.
+    ///
+    /// Returns `None` if there are no points.
+    pub fn fit(points: &[[T; N]]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let count = T::from_f64(points.len() as f64);
+        let mean = points
+            .iter()
+            .fold([T::ZERO; N], |acc, p| acc.add(*p))
+            .div(count);
+        let mut covariance = [[T::ZERO; N]; N];
+        for p in points {
+            let d = p.sub(mean);
+            for (i, row) in covariance.iter_mut().enumerate() {
+                for (j, c) in row.iter_mut().enumerate() {
+                    *c += d[i] * d[j] / count;
+                }
+            }
+        }
+        let (_, axes) = symmetric_eigen(covariance);
+        let mut min = [T::INFINITY; N];
+        let mut max = [-T::INFINITY; N];
+        for p in points {
+            let local = axes.map(|axis| axis.dot(p.sub(mean)));
+            min = min.min2(local);
+            max = max.max2(local);
+        }
+        let mut obb = Obb {
+            center: mean,
+            axes,
+            half_extents: [T::ZERO; N],
+        };
+        obb.center = mean.add(obb.world_of(min.midpoint(max)));
+        // Measure from the final center so that rounding cannot leave a point outside
+        for p in points {
+            let local = obb.local_of(p.sub(obb.center)).map(T::abs);
+            obb.half_extents = obb.half_extents.max2(local);
+        }
+        Some(obb)
+    }
+    fn local_of(&self, v: [T; N]) -> [T; N] {
+        self.axes.map(|axis| axis.dot(v))
+    }
+    fn world_of(&self, v: [T; N]) -> [T; N] {
+        (0..N).fold([T::ZERO; N], |acc, i| acc.add(self.axes[i].mul(v[i])))
+    }
+    /// Check if the box contains a point
+    pub fn contains(&self, point: [T; N]) -> bool {
+        let local = self.local_of(point.sub(self.center));
+        (0..N).all(|i| local[i].abs() <= self.half_extents[i])
+    }
+    /// Get the point in the box closest to another point
+    ///
+    /// Points inside the box are returned unchanged.
+    pub fn closest_point(&self, point: [T; N]) -> [T; N] {
+        let local = self.local_of(point.sub(self.center));
+        let clamped = local
+            .max2(self.half_extents.map(|h| -h))
+            .min2(self.half_extents);
+        self.center.add(self.world_of(clamped))
+    }
+    /// Get the corners of the box
+    ///
+    /// There are 2<sup>N</sup> corners. Bit `i` of a corner's index is set if the corner is
+    /// on the positive side of the box along local axis `i`.
+    pub fn corners(&self) -> Vec<[T; N]> {
+        (0..1usize << N)
+            .map(|bits| {
+                let local = std::array::from_fn(|i| {
+                    if bits >> i & 1 == 1 {
+                        self.half_extents[i]
+                    } else {
+                        -self.half_extents[i]
+                    }
+                });
+                self.center.add(self.world_of(local))
+            })
+            .collect()
+    }
+    /// Intersect a ray with the box
+    ///
+    /// Rays starting inside the box hit its surface from the inside.
+    pub fn raycast(&self, ray: Ray<[T; N]>) -> Option<RayHit<[T; N]>> {
+        let local = Ray::new(
+            self.local_of(ray.origin.sub(self.center)),
+            self.local_of(ray.dir),
+        );
+        let (t0, t1) = local.slab(self.half_extents.map(|h| -h), self.half_extents)?;
+        let inside = (0..N).all(|i| local.origin[i].abs() < self.half_extents[i]);
+        let t = if inside { t1 } else { t0 };
+        let point = local.at(t);
+        // The face hit is the one the point is relatively farthest toward
+        let (axis, _) = (0..N)
+            .map(|i| {
+                let half = self.half_extents[i];
+                let closeness = if half.is_zero() {
+                    T::INFINITY
+                } else {
+                    point[i].abs() / half
+                };
+                (i, closeness)
+            })
+            .max_by(|a, b| a.1.partial_cmp(&b.1).expect("distance comparison failed"))?;
+        let normal = if point[axis] < T::ZERO {
+            self.axes[axis].map(|x| -x)
+        } else {
+            self.axes[axis]
+        };
+        Some(RayHit {
+            t,
+            point: ray.at(t),
+            normal,
+        })
+    }
+    /// Get the smallest aabb that contains the box
+    pub fn aabb<A>(&self) -> A
+    where
+        A: Aabb<Vector = [T; N]>,
+    {
+        let mut aabb = A::ORIGIN_ZERO_SIZE;
+        for j in 0..N {
+            let half = (0..N)
+                .map(|i| (self.half_extents[i] * self.axes[i][j]).abs())
+                .fold(T::ZERO, |acc, s| acc + s);
+            aabb.set_origin_dim(j, self.center[j] - half);
+            aabb.set_size_dim(j, half * T::TWO);
+        }
+        aabb
+    }
+}
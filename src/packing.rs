@@ -0,0 +1,128 @@
+use crate::{polygon::vec2, Aabb, FloatingScalar, Scalar, VecN, ZVec};
+
+/// Get the lower and upper bounds of the centers of spheres with a radius that fit in an aabb
+fn center_bounds<V, A>(bounds: &A, radius: V::Scalar, dims: usize) -> Option<(V, V)>
+where
+    V: VecN,
+    V::Scalar: FloatingScalar,
+    A: Aabb<Vector = V>,
+{
+    let mut min = V::ZERO;
+    let mut max = V::ZERO;
+    for i in 0..dims {
+        min.set_dim(i, bounds.origin_dim(i) + radius);
+        max.set_dim(i, bounds.end_dim(i) - radius);
+        if max.dim(i) < min.dim(i) {
+            return None;
+        }
+    }
+    Some((min, max))
+}
+
+/// Get the number of lattice steps that fit between two values
+fn steps<T>(min: T, max: T, step: T) -> usize
+where
+    T: FloatingScalar,
+{
+    ((max - min) / step).to_f64().floor() as usize + 1
+}
+
+/// Get the centers of circles or spheres in a square or cubic packing inside an aabb
+///
+/// `spacing` is the gap between neighboring spheres. Every sphere is entirely
+/// inside the aabb, and the packing starts at the aabb's origin.
+pub fn square_packing<V, A>(bounds: A, radius: V::Scalar, spacing: V::Scalar) -> Vec<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+    A: Aabb<Vector = V>,
+{
+    let n = V::N;
+    let Some((min, max)) = center_bounds(&bounds, radius, n) else {
+        return Vec::new();
+    };
+    let pitch = radius * V::Scalar::TWO + spacing;
+    let counts: Vec<usize> = (0..n)
+        .map(|i| steps(min.dim(i), max.dim(i), pitch))
+        .collect();
+    let total = counts.iter().product();
+    let mut centers = Vec::with_capacity(total);
+    for mut index in 0..total {
+        let mut center = min;
+        for (i, &count) in counts.iter().enumerate() {
+            let step = V::Scalar::from_f64((index % count) as f64);
+            center.set_dim(i, min.dim(i) + step * pitch);
+            index /= count;
+        }
+        centers.push(center);
+    }
+    centers
+}
+
+/// Get the centers of circles in a hexagonal packing inside an aabb
+///
+/// Only the X and Y dimensions are considered, and other dimensions are zero.
+/// `spacing` is the gap between neighboring circles. Every circle is entirely
+/// inside the aabb, and the packing starts at the aabb's origin.
+pub fn hex_packing<V, A>(bounds: A, radius: V::Scalar, spacing: V::Scalar) -> Vec<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+    A: Aabb<Vector = V>,
+{
+    let Some((min, max)) = center_bounds(&bounds, radius, 2) else {
+        return Vec::new();
+    };
+    let pitch = radius * V::Scalar::TWO + spacing;
+    let half = pitch / V::Scalar::TWO;
+    let row_pitch = pitch * V::Scalar::from_f64(3f64.sqrt() / 2.0);
+    let mut centers = Vec::new();
+    for row in 0..steps(min.dim(1), max.dim(1), row_pitch) {
+        let y = min.dim(1) + V::Scalar::from_f64(row as f64) * row_pitch;
+        let start = if row % 2 == 0 {
+            min.dim(0)
+        } else {
+            min.dim(0) + half
+        };
+        if start > max.dim(0) {
+            continue;
+        }
+        for col in 0..steps(start, max.dim(0), pitch) {
+            let x = start + V::Scalar::from_f64(col as f64) * pitch;
+            centers.push(vec2(x, y));
+        }
+    }
+    centers
+}
+
+/// Get the centers of spheres in a face-centered cubic packing inside an aabb
+///
+/// Only the X, Y, and Z dimensions are considered, and other dimensions are zero.
+/// `spacing` is the gap between neighboring spheres. Every sphere is entirely
+/// inside the aabb, and the packing starts at the aabb's origin.
+pub fn fcc_packing<V, A>(bounds: A, radius: V::Scalar, spacing: V::Scalar) -> Vec<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+    A: Aabb<Vector = V>,
+{
+    let Some((min, max)) = center_bounds(&bounds, radius, 3) else {
+        return Vec::new();
+    };
+    // Points of the cubic lattice whose coordinates have an even sum form an FCC lattice
+    let pitch = radius * V::Scalar::TWO + spacing;
+    let step = pitch / V::Scalar::TWO.sqrt();
+    let counts = [0, 1, 2].map(|i| steps(min.dim(i), max.dim(i), step));
+    let mut centers = Vec::new();
+    for k in 0..counts[2] {
+        for j in 0..counts[1] {
+            for i in ((j + k) % 2..counts[0]).step_by(2) {
+                let offset = |n: usize| V::Scalar::from_f64(n as f64) * step;
+                let mut center: V = vec2(min.dim(0) + offset(i), min.dim(1) + offset(j));
+                center.set_z(min.dim(2) + offset(k));
+                centers.push(center);
+            }
+        }
+    }
+    centers
+}
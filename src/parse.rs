@@ -0,0 +1,71 @@
+use std::str::FromStr;
+
+use crate::{Aabb, VecN};
+
+/// Parse the numbers in a string separated by commas, whitespace, or brackets
+fn parse_dims<T>(s: &str) -> Option<Vec<T>>
+where
+    T: FromStr,
+{
+    s.split(|c: char| c == ',' || c.is_whitespace() || "()[]{}".contains(c))
+        .filter(|part| !part.is_empty())
+        .map(|part| part.parse().ok())
+        .collect()
+}
+
+/// Parse a vector from a string like `1.0, 2.0, 3.0`
+///
+/// The numbers may be separated by commas or whitespace and surrounded by parentheses or
+/// brackets, so the output of [`VecN::display`] can be parsed. Returns `None` if any
+/// number fails to parse or if there are not exactly as many numbers as dimensions.
+pub fn parse_vec<V>(s: &str) -> Option<V>
+where
+    V: VecN,
+    V::Scalar: FromStr,
+{
+    let dims = parse_dims(s)?;
+    if dims.len() != V::N {
+        return None;
+    }
+    let mut vector = V::ZERO;
+    for (i, dim) in dims.into_iter().enumerate() {
+        vector.set_dim(i, dim);
+    }
+    Some(vector)
+}
+
+/// Parse an aabb from a string
+///
+/// The string may be an origin and an end separated by `..`, like `(0, 0)..(2, 3)`, as
+/// made by [`Aabb::display_aabb`]. Otherwise, it is the dimensions of the origin followed
+/// by the dimensions of the size, like `0, 0, 2, 3`. Numbers are separated and surrounded
+/// as in [`parse_vec`]. Returns `None` if the string cannot be parsed.
+pub fn parse_aabb<A>(s: &str) -> Option<A>
+where
+    A: Aabb,
+    <A::Vector as VecN>::Scalar: FromStr,
+{
+    let n = A::Vector::N;
+    let mut aabb = A::ORIGIN_ZERO_SIZE;
+    if let Some((origin, end)) = s.split_once("..") {
+        let origin: A::Vector = parse_vec(origin)?;
+        let end: A::Vector = parse_vec(end)?;
+        for i in 0..n {
+            aabb.set_origin_dim(i, origin.dim(i));
+            aabb.set_size_dim(i, end.dim(i) - origin.dim(i));
+        }
+    } else {
+        let dims = parse_dims(s)?;
+        if dims.len() != 2 * n {
+            return None;
+        }
+        for (i, dim) in dims.into_iter().enumerate() {
+            if i < n {
+                aabb.set_origin_dim(i, dim);
+            } else {
+                aabb.set_size_dim(i - n, dim);
+            }
+        }
+    }
+    Some(aabb)
+}
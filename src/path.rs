@@ -0,0 +1,311 @@
+use crate::{
+    polygon::vec2, CircularArc, CubicBezier, FloatingScalar, FloatingVecN, VecN, XVec, YVec,
+};
+
+/// A single segment of a [`Path`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathSegment<T> {
+    /// A straight line between two points
+    Line([[T; 2]; 2]),
+    /// A circular arc
+    Arc(CircularArc<T>),
+    /// A cubic Bezier curve
+    Bezier([[T; 2]; 4]),
+}
+
+/// The nodes and weights of 5-point Gauss-Legendre quadrature on `[-1, 1]`
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_888_9),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_47),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_47),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_08),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_08),
+];
+
+/// The number of pieces a Bezier curve is split into when measuring its length
+const LENGTH_PIECES: usize = 8;
+
+/// The maximum number of times a Bezier curve is subdivided when flattening
+const MAX_FLATTEN_DEPTH: usize = 16;
+
+/// Get the length of the first part of a Bezier curve up to a parameter
+fn bezier_length<T>(curve: &[[T; 2]; 4], t: T) -> T
+where
+    T: FloatingScalar,
+{
+    let step = t / T::from_f64(LENGTH_PIECES as f64);
+    let half = step / T::TWO;
+    (0..LENGTH_PIECES).fold(T::ZERO, |acc, i| {
+        let mid = step * T::from_f64(i as f64) + half;
+        GAUSS_LEGENDRE.iter().fold(acc, |acc, &(x, w)| {
+            acc + T::from_f64(w) * half * curve.tangent_at(mid + half * T::from_f64(x)).mag()
+        })
+    })
+}
+
+/// Get the parameter along a Bezier curve at a length along it by bisection
+fn bezier_param_at_length<T>(curve: &[[T; 2]; 4], length: T) -> T
+where
+    T: FloatingScalar,
+{
+    let (mut lo, mut hi) = (T::ZERO, T::ONE);
+    for _ in 0..64 {
+        let mid = (lo + hi) / T::TWO;
+        if mid <= lo || mid >= hi {
+            break;
+        }
+        if bezier_length(curve, mid) < length {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    (lo + hi) / T::TWO
+}
+
+/// Add the points of a flattened Bezier curve, excluding its start, to a list
+fn flatten_bezier<T>(curve: [[T; 2]; 4], tolerance: T, depth: usize, points: &mut Vec<[T; 2]>)
+where
+    T: FloatingScalar,
+{
+    let [p0, p1, p2, p3] = curve;
+    let chord = p3.sub(p0);
+    let len = chord.mag();
+    let deviation = |p: [T; 2]| {
+        let d = p.sub(p0);
+        if len.is_zero() {
+            d.mag()
+        } else {
+            (d[0] * chord[1] - d[1] * chord[0]).abs() / len
+        }
+    };
+    if depth >= MAX_FLATTEN_DEPTH || deviation(p1).maxx(deviation(p2)) <= tolerance {
+        points.push(p3);
+        return;
+    }
+    for half in curve.split(T::ONE / T::TWO) {
+        flatten_bezier(half, tolerance, depth + 1, points);
+    }
+}
+
+impl<T> PathSegment<T>
+where
+    T: FloatingScalar,
+{
+    /// Get the start point of the segment
+    pub fn start(&self) -> [T; 2] {
+        match self {
+            PathSegment::Line([a, _]) => *a,
+            PathSegment::Arc(arc) => arc.point_at(T::ZERO),
+            PathSegment::Bezier([a, ..]) => *a,
+        }
+    }
+    /// Get the end point of the segment
+    pub fn end(&self) -> [T; 2] {
+        match self {
+            PathSegment::Line([_, b]) => *b,
+            PathSegment::Arc(arc) => arc.point_at(T::ONE),
+            PathSegment::Bezier([.., b]) => *b,
+        }
+    }
+    /// Get the length of the segment
+    ///
+    /// The length of a Bezier curve is found by numerical integration, so it is approximate.
+    pub fn length(&self) -> T {
+        match self {
+            PathSegment::Line([a, b]) => a.dist(*b),
+            PathSegment::Arc(arc) => arc.length(),
+            PathSegment::Bezier(curve) => bezier_length(curve, T::ONE),
+        }
+    }
+    /// Get the point and unit tangent at a length along the segment
+    fn point_and_tangent(&self, length: T) -> ([T; 2], [T; 2]) {
+        let total = self.length();
+        let t = if total.is_zero() {
+            T::ZERO
+        } else {
+            (length / total).maxx(T::ZERO).minn(T::ONE)
+        };
+        match self {
+            PathSegment::Line([a, b]) => (a.lerp(*b, t), b.sub(*a).unit()),
+            PathSegment::Arc(arc) => {
+                let [cos, sin] = (arc.start_angle + arc.sweep() * t).angle_as_vector();
+                let tangent = if arc.sweep() < T::ZERO {
+                    [sin, -cos]
+                } else {
+                    [-sin, cos]
+                };
+                (arc.point_at(t), tangent)
+            }
+            PathSegment::Bezier(curve) => {
+                let t = bezier_param_at_length(curve, length);
+                (curve.point_at(t), curve.tangent_at(t).unit())
+            }
+        }
+    }
+}
+
+/// A 2D path made of connected lines, arcs, and Bezier curves
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path<T> {
+    start: [T; 2],
+    segments: Vec<PathSegment<T>>,
+}
+
+impl<T> Path<T>
+where
+    T: FloatingScalar,
+{
+    /// Create a new empty path starting at a point
+    pub fn new<V>(start: V) -> Self
+    where
+        V: VecN<Scalar = T>,
+    {
+        Path {
+            start: [start.x(), start.y()],
+            segments: Vec::new(),
+        }
+    }
+    /// Get the segments of the path
+    pub fn segments(&self) -> &[PathSegment<T>] {
+        &self.segments
+    }
+    /// Get the start point of the path
+    pub fn start<V>(&self) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        vec2(self.start[0], self.start[1])
+    }
+    /// Get the end point of the path
+    pub fn end<V>(&self) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        let [x, y] = self.segments.last().map_or(self.start, |s| s.end());
+        vec2(x, y)
+    }
+    /// Add a line from the end of the path to a point
+    pub fn line_to<V>(mut self, point: V) -> Self
+    where
+        V: VecN<Scalar = T>,
+    {
+        let start = self.end();
+        self.segments
+            .push(PathSegment::Line([start, [point.x(), point.y()]]));
+        self
+    }
+    /// Add an arc around a center point from the end of the path
+    ///
+    /// The arc sweeps counter-clockwise by `sweep` radians, or clockwise if it is negative.
+    pub fn arc_around<V>(mut self, center: V, sweep: T) -> Self
+    where
+        V: VecN<Scalar = T>,
+    {
+        let center = [center.x(), center.y()];
+        let offset = self.end::<[T; 2]>().sub(center);
+        let start_angle = offset[1].atan2(offset[0]);
+        self.segments.push(PathSegment::Arc(CircularArc::new(
+            center,
+            offset.mag(),
+            start_angle,
+            start_angle + sweep,
+        )));
+        self
+    }
+    /// Add a cubic Bezier curve from the end of the path to a point
+    pub fn cubic_to<V>(mut self, control1: V, control2: V, point: V) -> Self
+    where
+        V: VecN<Scalar = T>,
+    {
+        let start = self.end();
+        self.segments.push(PathSegment::Bezier([
+            start,
+            [control1.x(), control1.y()],
+            [control2.x(), control2.y()],
+            [point.x(), point.y()],
+        ]));
+        self
+    }
+    /// Get the total length of the path
+    pub fn length(&self) -> T {
+        self.segments
+            .iter()
+            .fold(T::ZERO, |acc, segment| acc + segment.length())
+    }
+    /// Find the segment at a length along the path and the remaining length into it
+    fn segment_at_length(&self, mut length: T) -> Option<(&PathSegment<T>, T)> {
+        let last = self.segments.len().checked_sub(1)?;
+        for (i, segment) in self.segments.iter().enumerate() {
+            let segment_length = segment.length();
+            if length <= segment_length || i == last {
+                return Some((segment, length));
+            }
+            length -= segment_length;
+        }
+        None
+    }
+    /// Get the point at a length along the path
+    ///
+    /// Lengths outside the path are clamped to its ends.
+    pub fn point_at_length<V>(&self, length: T) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        let [x, y] = self
+            .segment_at_length(length)
+            .map_or(self.start, |(segment, length)| {
+                segment.point_and_tangent(length).0
+            });
+        vec2(x, y)
+    }
+    /// Get the unit tangent at a length along the path
+    ///
+    /// Lengths outside the path are clamped to its ends.
+    /// Returns the zero vector if the path is empty.
+    pub fn tangent_at_length<V>(&self, length: T) -> V
+    where
+        V: VecN<Scalar = T>,
+    {
+        let [x, y] = self
+            .segment_at_length(length)
+            .map_or([T::ZERO; 2], |(segment, length)| {
+                segment.point_and_tangent(length).1
+            });
+        vec2(x, y)
+    }
+    /// Get points along the path that are within `tolerance` of it when connected by lines
+    pub fn flatten<V>(&self, tolerance: T) -> Vec<V>
+    where
+        V: VecN<Scalar = T>,
+    {
+        let mut points = vec![self.start];
+        for segment in &self.segments {
+            match segment {
+                PathSegment::Line([_, b]) => points.push(*b),
+                PathSegment::Arc(arc) => {
+                    // The largest angle whose chord is within the tolerance of the arc
+                    let step = if tolerance < arc.radius {
+                        T::TWO * T::from_f64((T::ONE - tolerance / arc.radius).to_f64().acos())
+                    } else {
+                        T::PI
+                    };
+                    // Tiny tolerances round the step to zero, so limit the count like Beziers
+                    let max_count = 1usize << MAX_FLATTEN_DEPTH;
+                    let count = (arc.sweep().abs() / step).to_f64().ceil();
+                    let count = if count > max_count as f64 {
+                        max_count
+                    } else {
+                        count.max(1.0) as usize
+                    };
+                    points.extend(
+                        (1..=count)
+                            .map(|i| arc.point_at::<[T; 2]>(T::from_f64(i as f64 / count as f64))),
+                    );
+                }
+                PathSegment::Bezier(curve) => flatten_bezier(*curve, tolerance, 0, &mut points),
+            }
+        }
+        points.into_iter().map(|[x, y]| vec2(x, y)).collect()
+    }
+}
@@ -0,0 +1,466 @@
+use crate::{FloatingScalar, Scalar, VecN, XVec, YVec};
+
+/// The maximum number of segments in a round join's arc
+const MAX_ROUND_STEPS: usize = 1024;
+
+/// Trait for treating a list of 2D vectors as a simple polygon
+///
+/// Only the X and Y dimensions of the vectors are considered.
+/// The polygon is implicitly closed, so the last vertex connects to the first.
+pub trait Polygon {
+    /// The vector type
+    type Vector: VecN;
+    /// Get the signed area of the polygon
+    ///
+    /// The area is positive if the vertices are in counter-clockwise order.
+    fn signed_area(&self) -> <Self::Vector as VecN>::Scalar;
+    /// Get the area of the polygon
+    fn area(&self) -> <Self::Vector as VecN>::Scalar;
+    /// Get the centroid of the polygon
+    ///
+    /// Returns `None` if the polygon has no area.
+    fn centroid(&self) -> Option<Self::Vector>;
+    /// Get the second moments of area about the polygon's centroid
+    ///
+    /// Returns `[Ixx, Iyy, Ixy]`, where `Ixx` is the moment about the
+    /// horizontal axis through the centroid. Returns `None` if the polygon has no area.
+    fn second_moments(&self) -> Option<[<Self::Vector as VecN>::Scalar; 3]>;
+    /// Get the polar moment of area about the polygon's centroid
+    ///
+    /// Multiplying this by the density gives the moment of inertia for rotation in the plane.
+    fn polar_moment(&self) -> Option<<Self::Vector as VecN>::Scalar> {
+        self.second_moments().map(|[ixx, iyy, _]| ixx + iyy)
+    }
+    /// Check if a point is inside the polygon
+    ///
+    /// This uses the even-odd rule, so self-intersecting polygons have holes where they overlap.
+    fn contains_point(&self, point: Self::Vector) -> bool;
+    /// Get the point on the polygon's boundary closest to another point
+    ///
+    /// Returns `None` if the polygon has no vertices.
+    fn closest_point(&self, point: Self::Vector) -> Option<Self::Vector>;
+    /// Get the signed distance from a point to the polygon's boundary
+    ///
+    /// The distance is negative for points inside the polygon.
+    /// Returns `None` if the polygon has no vertices.
+    fn signed_distance(&self, point: Self::Vector) -> Option<<Self::Vector as VecN>::Scalar>;
+    /// Offset the polygon outward by a distance
+    ///
+    /// Negative distances shrink the polygon. Self-intersections caused by
+    /// offsetting past narrow features are not removed.
+    fn offset(
+        &self,
+        distance: <Self::Vector as VecN>::Scalar,
+        join: LineJoin<<Self::Vector as VecN>::Scalar>,
+    ) -> Vec<Self::Vector>;
+    /// Offset the vertices as an open polyline
+    ///
+    /// Positive distances offset to the right of the direction of travel.
+    /// The ends of the polyline are not capped.
+    fn offset_open(
+        &self,
+        distance: <Self::Vector as VecN>::Scalar,
+        join: LineJoin<<Self::Vector as VecN>::Scalar>,
+    ) -> Vec<Self::Vector>;
+    /// Check if the polygon is convex
+    ///
+    /// Collinear vertices are allowed. Polygons with fewer than 3 vertices are not convex.
+    fn is_convex(&self) -> bool;
+    /// Triangulate the polygon by ear clipping
+    ///
+    /// Returns triples of vertex indices, each wound in the same direction as the polygon.
+    fn triangulate(&self) -> Vec<[usize; 3]>;
+    /// Decompose the polygon into convex pieces
+    ///
+    /// This uses the Hertel-Mehlhorn algorithm, which produces at most
+    /// 4 times the minimum number of pieces.
+    fn convex_decomposition(&self) -> Vec<Vec<Self::Vector>>;
+}
+
+/// The way corners are joined when offsetting a path
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineJoin<T> {
+    /// Extend the edges until they meet
+    ///
+    /// If the ratio of the miter length to the offset distance would exceed
+    /// the limit, the corner is beveled instead.
+    Miter {
+        /// The miter limit
+        limit: T,
+    },
+    /// Connect the ends of the edges with a straight line
+    Bevel,
+    /// Connect the ends of the edges with a circular arc
+    Round {
+        /// The maximum angle in radians between consecutive arc points
+        ///
+        /// Each arc has at most 1024 segments, so steps that are too small, zero, or NaN
+        /// are limited to that.
+        step: T,
+    },
+}
+
+pub(crate) fn vec2<V>(x: V::Scalar, y: V::Scalar) -> V
+where
+    V: VecN,
+{
+    let mut v = V::ZERO;
+    v.set_x(x);
+    v.set_y(y);
+    v
+}
+
+pub(crate) fn cross2<V>(o: V, a: V, b: V) -> V::Scalar
+where
+    V: VecN,
+{
+    (a.x() - o.x()) * (b.y() - o.y()) - (a.y() - o.y()) * (b.x() - o.x())
+}
+
+fn indices_convex<V>(vertices: &[V], indices: &[usize]) -> bool
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let n = indices.len();
+    let mut sign = V::Scalar::ZERO;
+    for i in 0..n {
+        let c = cross2(
+            vertices[indices[i]],
+            vertices[indices[(i + 1) % n]],
+            vertices[indices[(i + 2) % n]],
+        );
+        if c.is_zero() {
+            continue;
+        }
+        if sign == V::Scalar::ZERO {
+            sign = c;
+        } else if (c > V::Scalar::ZERO) != (sign > V::Scalar::ZERO) {
+            return false;
+        }
+    }
+    true
+}
+
+fn right_normal<V>(a: V, b: V) -> Option<[V::Scalar; 2]>
+where
+    V: VecN,
+    V::Scalar: FloatingScalar,
+{
+    let [dx, dy] = [b.x() - a.x(), b.y() - a.y()];
+    let len = (dx * dx + dy * dy).sqrt();
+    if len.is_zero() {
+        None
+    } else {
+        Some([dy / len, -dx / len])
+    }
+}
+
+fn offset_path<V>(
+    vertices: &[V],
+    closed: bool,
+    distance: V::Scalar,
+    join: LineJoin<V::Scalar>,
+) -> Vec<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let mut points: Vec<V> = Vec::with_capacity(vertices.len());
+    for &v in vertices {
+        if points.last().is_none_or(|&p| right_normal(p, v).is_some()) {
+            points.push(v);
+        }
+    }
+    if closed && points.len() > 1 && right_normal(points[points.len() - 1], points[0]).is_none() {
+        points.pop();
+    }
+    let n = points.len();
+    if n < 2 {
+        return Vec::new();
+    }
+    let edge_count = if closed { n } else { n - 1 };
+    let normals: Vec<[V::Scalar; 2]> = (0..edge_count)
+        .filter_map(|i| right_normal(points[i], points[(i + 1) % n]))
+        .collect();
+    let shift =
+        |v: V, [nx, ny]: [V::Scalar; 2]| vec2::<V>(v.x() + nx * distance, v.y() + ny * distance);
+    let mut res = Vec::with_capacity(n);
+    if !closed {
+        res.push(shift(points[0], normals[0]));
+    }
+    let corners = if closed { 0..n } else { 1..n - 1 };
+    for i in corners {
+        let v = points[i];
+        let n0 = normals[(i + edge_count - 1) % edge_count];
+        let n1 = normals[i % edge_count];
+        let cos = n0[0] * n1[0] + n0[1] * n1[1];
+        let sin = n0[0] * n1[1] - n0[1] * n1[0];
+        let p0 = shift(v, n0);
+        let p1 = shift(v, n1);
+        if sin.is_zero() && cos > V::Scalar::ZERO {
+            res.push(p0);
+            continue;
+        }
+        let denom = V::Scalar::ONE + cos;
+        let miter = if denom.is_zero() {
+            None
+        } else {
+            Some(shift(v, [(n0[0] + n1[0]) / denom, (n0[1] + n1[1]) / denom]))
+        };
+        let outer = (sin > V::Scalar::ZERO) == (distance > V::Scalar::ZERO);
+        if !outer {
+            if let Some(m) = miter {
+                res.push(m);
+            } else {
+                res.extend([p0, v, p1]);
+            }
+            continue;
+        }
+        match join {
+            LineJoin::Miter { limit } => match miter {
+                Some(m) if V::Scalar::TWO / denom <= limit * limit => res.push(m),
+                _ => res.extend([p0, p1]),
+            },
+            LineJoin::Bevel => res.extend([p0, p1]),
+            LineJoin::Round { step } => {
+                let radius = distance.abs();
+                let [sx, sy] = [n0[0] * distance, n0[1] * distance];
+                let start = sy.atan2(sx);
+                let sweep = sin.atan2(cos);
+                // Tiny, zero, or NaN steps would never cover the sweep, so limit the count
+                let steps = (sweep.abs() / step).to_f64().ceil();
+                let steps = if steps > MAX_ROUND_STEPS as f64 {
+                    MAX_ROUND_STEPS
+                } else {
+                    steps.max(1.0) as usize
+                };
+                res.push(p0);
+                for k in 1..steps {
+                    let t = V::Scalar::from_f64(k as f64) / V::Scalar::from_f64(steps as f64);
+                    let [cx, cy] = (start + sweep * t).angle_as_vector();
+                    res.push(vec2(v.x() + cx * radius, v.y() + cy * radius));
+                }
+                res.push(p1);
+            }
+        }
+    }
+    if !closed {
+        res.push(shift(points[n - 1], normals[edge_count - 1]));
+    }
+    res
+}
+
+pub(crate) fn edges<V>(vertices: &[V]) -> impl Iterator<Item = (V, V)> + '_
+where
+    V: Copy,
+{
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
+
+impl<V> Polygon for [V]
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    type Vector = V;
+    fn signed_area(&self) -> V::Scalar {
+        edges(self).fold(V::Scalar::ZERO, |acc, (a, b)| {
+            acc + a.x() * b.y() - b.x() * a.y()
+        }) / V::Scalar::TWO
+    }
+    fn area(&self) -> V::Scalar {
+        self.signed_area().abs()
+    }
+    fn centroid(&self) -> Option<V> {
+        let area = self.signed_area();
+        if area.is_zero() {
+            return None;
+        }
+        let [mut x, mut y] = [V::Scalar::ZERO; 2];
+        for (a, b) in edges(self) {
+            let cross = a.x() * b.y() - b.x() * a.y();
+            x += (a.x() + b.x()) * cross;
+            y += (a.y() + b.y()) * cross;
+        }
+        let denom = V::Scalar::from_f64(6.0) * area;
+        let mut centroid = V::ZERO;
+        centroid.set_x(x / denom);
+        centroid.set_y(y / denom);
+        Some(centroid)
+    }
+    fn second_moments(&self) -> Option<[V::Scalar; 3]> {
+        let area = self.signed_area();
+        let centroid = self.centroid()?;
+        let [mut ixx, mut iyy, mut ixy] = [V::Scalar::ZERO; 3];
+        for (a, b) in edges(self) {
+            let [x0, y0, x1, y1] = [a.x(), a.y(), b.x(), b.y()];
+            let cross = x0 * y1 - x1 * y0;
+            ixx += (y0 * y0 + y0 * y1 + y1 * y1) * cross;
+            iyy += (x0 * x0 + x0 * x1 + x1 * x1) * cross;
+            ixy += (x0 * y1 + V::Scalar::TWO * (x0 * y0 + x1 * y1) + x1 * y0) * cross;
+        }
+        let twelve = V::Scalar::from_f64(12.0);
+        let [cx, cy] = [centroid.x(), centroid.y()];
+        let ixx = ixx / twelve - area * cy * cy;
+        let iyy = iyy / twelve - area * cx * cx;
+        let ixy = ixy / (twelve * V::Scalar::TWO) - area * cx * cy;
+        Some(if area < V::Scalar::ZERO {
+            [-ixx, -iyy, -ixy]
+        } else {
+            [ixx, iyy, ixy]
+        })
+    }
+    fn contains_point(&self, point: V) -> bool {
+        edges(self).fold(false, |inside, (a, b)| {
+            if (a.y() > point.y()) != (b.y() > point.y()) {
+                let x = a.x() + (point.y() - a.y()) / (b.y() - a.y()) * (b.x() - a.x());
+                inside != (point.x() < x)
+            } else {
+                inside
+            }
+        })
+    }
+    fn closest_point(&self, point: V) -> Option<V> {
+        edges(self)
+            .map(|(a, b)| {
+                let [ex, ey] = [b.x() - a.x(), b.y() - a.y()];
+                let len2 = ex * ex + ey * ey;
+                let t = if len2.is_zero() {
+                    V::Scalar::ZERO
+                } else {
+                    (((point.x() - a.x()) * ex + (point.y() - a.y()) * ey) / len2)
+                        .maxx(V::Scalar::ZERO)
+                        .minn(V::Scalar::ONE)
+                };
+                let closest: V = vec2(a.x() + ex * t, a.y() + ey * t);
+                let [dx, dy] = [point.x() - closest.x(), point.y() - closest.y()];
+                (dx * dx + dy * dy, closest)
+            })
+            .min_by(|(a, _), (b, _)| a.partial_cmp(b).expect("distance comparison failed"))
+            .map(|(_, closest)| closest)
+    }
+    fn signed_distance(&self, point: V) -> Option<V::Scalar> {
+        let closest = self.closest_point(point)?;
+        let [dx, dy] = [point.x() - closest.x(), point.y() - closest.y()];
+        let dist = (dx * dx + dy * dy).sqrt();
+        Some(if self.contains_point(point) {
+            -dist
+        } else {
+            dist
+        })
+    }
+    fn offset(&self, distance: V::Scalar, join: LineJoin<V::Scalar>) -> Vec<V> {
+        let distance = if self.signed_area() < V::Scalar::ZERO {
+            -distance
+        } else {
+            distance
+        };
+        offset_path(self, true, distance, join)
+    }
+    fn offset_open(&self, distance: V::Scalar, join: LineJoin<V::Scalar>) -> Vec<V> {
+        offset_path(self, false, distance, join)
+    }
+    fn is_convex(&self) -> bool {
+        let n = self.len();
+        if n < 3 || self.signed_area().is_zero() {
+            return false;
+        }
+        let indices: Vec<usize> = (0..n).collect();
+        if !indices_convex(self, &indices) {
+            return false;
+        }
+        // Reject self-intersecting polygons that wind around more than once
+        let turning = (0..n).fold(V::Scalar::ZERO, |acc, i| {
+            let [a, b, c] = [self[i], self[(i + 1) % n], self[(i + 2) % n]];
+            let dot = (b.x() - a.x()) * (c.x() - b.x()) + (b.y() - a.y()) * (c.y() - b.y());
+            acc + cross2(a, b, c).atan2(dot)
+        });
+        (turning.abs() - V::Scalar::TAU).abs() < V::Scalar::ONE
+    }
+    fn triangulate(&self) -> Vec<[usize; 3]> {
+        let ccw = self.signed_area() >= V::Scalar::ZERO;
+        let mut remaining: Vec<usize> = (0..self.len()).collect();
+        if !ccw {
+            remaining.reverse();
+        }
+        let mut triangles = Vec::with_capacity(self.len().saturating_sub(2));
+        while remaining.len() > 3 {
+            let n = remaining.len();
+            let is_ear = |i: usize| {
+                let [a, b, c] = [
+                    remaining[(i + n - 1) % n],
+                    remaining[i],
+                    remaining[(i + 1) % n],
+                ];
+                let [pa, pb, pc] = [self[a], self[b], self[c]];
+                if cross2(pa, pb, pc) <= V::Scalar::ZERO {
+                    return false;
+                }
+                !remaining.iter().any(|&j| {
+                    j != a
+                        && j != b
+                        && j != c
+                        && cross2(pa, pb, self[j]) >= V::Scalar::ZERO
+                        && cross2(pb, pc, self[j]) >= V::Scalar::ZERO
+                        && cross2(pc, pa, self[j]) >= V::Scalar::ZERO
+                })
+            };
+            // Fall back to clipping any vertex if the polygon is degenerate
+            let i = (0..n).find(|&i| is_ear(i)).unwrap_or(0);
+            triangles.push([
+                remaining[(i + n - 1) % n],
+                remaining[i],
+                remaining[(i + 1) % n],
+            ]);
+            remaining.remove(i);
+        }
+        if remaining.len() == 3 {
+            triangles.push([remaining[0], remaining[1], remaining[2]]);
+        }
+        if !ccw {
+            for tri in &mut triangles {
+                tri.reverse();
+            }
+        }
+        triangles
+    }
+    fn convex_decomposition(&self) -> Vec<Vec<V>> {
+        let mut pieces: Vec<Vec<usize>> = self.triangulate().into_iter().map(Vec::from).collect();
+        let shared_edge = |p: &[usize], q: &[usize]| {
+            (0..p.len()).find_map(|i| {
+                let [a, b] = [p[i], p[(i + 1) % p.len()]];
+                (0..q.len())
+                    .find(|&j| q[j] == b && q[(j + 1) % q.len()] == a)
+                    .map(|j| (i, j))
+            })
+        };
+        'merge: loop {
+            for pi in 0..pieces.len() {
+                for qi in pi + 1..pieces.len() {
+                    let (p, q) = (&pieces[pi], &pieces[qi]);
+                    let Some((i, j)) = shared_edge(p, q) else {
+                        continue;
+                    };
+                    let mut merged: Vec<usize> =
+                        p[i + 1..].iter().chain(&p[..=i]).copied().collect();
+                    let q_rot: Vec<usize> = q[j + 1..].iter().chain(&q[..=j]).copied().collect();
+                    merged.extend_from_slice(&q_rot[1..q_rot.len() - 1]);
+                    if indices_convex(self, &merged) {
+                        pieces[pi] = merged;
+                        pieces.swap_remove(qi);
+                        continue 'merge;
+                    }
+                }
+            }
+            break;
+        }
+        pieces
+            .into_iter()
+            .map(|piece| piece.into_iter().map(|i| self[i]).collect())
+            .collect()
+    }
+}
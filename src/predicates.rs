@@ -0,0 +1,345 @@
+use crate::{FloatingScalar, Scalar, VecN, XVec, YVec, ZVec};
+
+/// The orientation of an ordered set of points
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// The points turn counter-clockwise
+    CounterClockwise,
+    /// The points turn clockwise
+    Clockwise,
+    /// The points are collinear or coplanar
+    Degenerate,
+}
+
+/// The location of a point relative to a closed shape
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Location {
+    /// The point is inside the shape
+    Inside,
+    /// The point is on the boundary of the shape
+    Boundary,
+    /// The point is outside the shape
+    Outside,
+}
+
+impl Orientation {
+    fn from_det<T>(det: T, tolerance: T) -> Self
+    where
+        T: FloatingScalar,
+    {
+        if det.abs() <= tolerance {
+            Orientation::Degenerate
+        } else if det > T::ZERO {
+            Orientation::CounterClockwise
+        } else {
+            Orientation::Clockwise
+        }
+    }
+}
+
+impl Location {
+    fn from_det<T>(det: T, tolerance: T) -> Self
+    where
+        T: FloatingScalar,
+    {
+        match Orientation::from_det(det, tolerance) {
+            Orientation::CounterClockwise => Location::Inside,
+            Orientation::Clockwise => Location::Outside,
+            Orientation::Degenerate => Location::Boundary,
+        }
+    }
+}
+
+/// Get the orientation of three 2D points
+///
+/// The points are [`Orientation::Degenerate`] if the sine of the angle at `c`
+/// is within `epsilon`, so the tolerance does not depend on the scale of the points.
+pub fn orient2d<V>(a: V, b: V, c: V, epsilon: V::Scalar) -> Orientation
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let [det, _] = orient2d_terms(a, b, c);
+    let scale = dist2d(a, c) * dist2d(b, c);
+    Orientation::from_det(det, epsilon * scale)
+}
+
+fn dist2d<V>(a: V, b: V) -> V::Scalar
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let [dx, dy] = [a.x() - b.x(), a.y() - b.y()];
+    (dx * dx + dy * dy).sqrt()
+}
+
+fn dist3d<V>(a: V, b: V) -> V::Scalar
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let [dx, dy, dz] = [a.x() - b.x(), a.y() - b.y(), a.z() - b.z()];
+    (dx * dx + dy * dy + dz * dz).sqrt()
+}
+
+fn orient2d_terms<V>(a: V, b: V, c: V) -> [V::Scalar; 2]
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let left = (a.x() - c.x()) * (b.y() - c.y());
+    let right = (a.y() - c.y()) * (b.x() - c.x());
+    [left - right, left.abs() + right.abs()]
+}
+
+/// Get the orientation of four 3D points
+///
+/// The result is [`Orientation::CounterClockwise`] if `a`, `b`, and `c` appear
+/// counter-clockwise when viewed from the side of their plane opposite `d`.
+/// The points are [`Orientation::Degenerate`] if the volume of the parallelepiped
+/// spanned by the other points relative to `d` is within `epsilon` times the
+/// product of the lengths of its edges, so the tolerance does not depend on the
+/// scale of the points.
+pub fn orient3d<V>(a: V, b: V, c: V, d: V, epsilon: V::Scalar) -> Orientation
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let [det, _] = orient3d_terms(a, b, c, d);
+    let scale = dist3d(a, d) * dist3d(b, d) * dist3d(c, d);
+    Orientation::from_det(det, epsilon * scale)
+}
+
+fn orient3d_terms<V>(a: V, b: V, c: V, d: V) -> [V::Scalar; 2]
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let [ax, ay, az] = [a.x() - d.x(), a.y() - d.y(), a.z() - d.z()];
+    let [bx, by, bz] = [b.x() - d.x(), b.y() - d.y(), b.z() - d.z()];
+    let [cx, cy, cz] = [c.x() - d.x(), c.y() - d.y(), c.z() - d.z()];
+    let terms = [
+        [ax, by * cz, -bz * cy],
+        [ay, bz * cx, -bx * cz],
+        [az, bx * cy, -by * cx],
+    ];
+    terms
+        .into_iter()
+        .fold([V::Scalar::ZERO; 2], |[det, permanent], [s, p, q]| {
+            [det + s * (p + q), permanent + s.abs() * (p.abs() + q.abs())]
+        })
+}
+
+/// Get the location of a 2D point relative to the circle through three other points
+///
+/// `a`, `b`, and `c` must be in counter-clockwise order.
+/// The point is on the [`Location::Boundary`] if the determinant is within
+/// `epsilon` times the sum of the magnitudes of its terms. Every term has the
+/// same scale as the determinant, so the tolerance does not depend on the scale
+/// of the points.
+pub fn in_circle<V>(a: V, b: V, c: V, d: V, epsilon: V::Scalar) -> Location
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let [det, permanent] = in_circle_terms(a, b, c, d);
+    Location::from_det(det, epsilon * permanent)
+}
+
+fn in_circle_terms<V>(a: V, b: V, c: V, d: V) -> [V::Scalar; 2]
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let [ax, ay] = [a.x() - d.x(), a.y() - d.y()];
+    let [bx, by] = [b.x() - d.x(), b.y() - d.y()];
+    let [cx, cy] = [c.x() - d.x(), c.y() - d.y()];
+    let terms = [
+        [ax * ax + ay * ay, bx * cy, -cx * by],
+        [bx * bx + by * by, cx * ay, -ax * cy],
+        [cx * cx + cy * cy, ax * by, -bx * ay],
+    ];
+    terms
+        .into_iter()
+        .fold([V::Scalar::ZERO; 2], |[det, permanent], [s, p, q]| {
+            [det + s * (p + q), permanent + s * (p.abs() + q.abs())]
+        })
+}
+
+/// Check if three points of any dimension are collinear
+///
+/// The points are collinear if the sine of the angle at `a` is within `epsilon`.
+/// Coincident points are always collinear.
+pub fn collinear<V>(a: V, b: V, c: V, epsilon: V::Scalar) -> bool
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let ab = b.sub(a);
+    let ac = c.sub(a);
+    let ab2 = ab.squared_mag();
+    let ac2 = ac.squared_mag();
+    let dot = ab.dot(ac);
+    // Lagrange's identity gives the squared magnitude of the wedge product
+    let wedge2 = ab2 * ac2 - dot * dot;
+    wedge2 <= epsilon * epsilon * ab2 * ac2
+}
+
+/// A nonoverlapping expansion of floating point components in increasing order of magnitude
+///
+/// The exact value of the expansion is the sum of its components.
+/// See Shewchuk, "Adaptive Precision Floating-Point Arithmetic and Fast Robust Geometric Predicates".
+#[derive(Clone)]
+struct Expansion<T>(Vec<T>);
+
+fn two_sum<T>(a: T, b: T) -> (T, T)
+where
+    T: FloatingScalar,
+{
+    let x = a + b;
+    let bv = x - a;
+    let av = x - bv;
+    (x, (a - av) + (b - bv))
+}
+
+fn two_product<T>(a: T, b: T) -> (T, T)
+where
+    T: FloatingScalar,
+{
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+impl<T> Expansion<T>
+where
+    T: FloatingScalar,
+{
+    fn diff(a: T, b: T) -> Self {
+        let (x, y) = two_sum(a, -b);
+        Expansion(vec![y, x])
+    }
+    fn grow(&mut self, mut b: T) {
+        for e in &mut self.0 {
+            let (x, y) = two_sum(b, *e);
+            *e = y;
+            b = x;
+        }
+        self.0.push(b);
+        self.0.retain(|e| *e != T::ZERO);
+    }
+    fn add(mut self, other: &Self) -> Self {
+        for &e in &other.0 {
+            self.grow(e);
+        }
+        self
+    }
+    fn neg(mut self) -> Self {
+        for e in &mut self.0 {
+            *e = -*e;
+        }
+        self
+    }
+    fn mul(&self, other: &Self) -> Self {
+        let mut res = Expansion(Vec::new());
+        for &a in &self.0 {
+            for &b in &other.0 {
+                let (x, y) = two_product(a, b);
+                res.grow(y);
+                res.grow(x);
+            }
+        }
+        res
+    }
+    fn sign(&self) -> T {
+        self.0.last().copied().unwrap_or(T::ZERO)
+    }
+}
+
+/// Get the orientation of three 2D points using exact arithmetic
+///
+/// The result is exact for all finite inputs. The determinant is first evaluated with
+/// normal floating point arithmetic, and exact arithmetic is only used if its error
+/// bound cannot rule out the wrong sign.
+pub fn orient2d_exact<V>(a: V, b: V, c: V) -> Orientation
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let [det, permanent] = orient2d_terms(a, b, c);
+    let bound = V::Scalar::from_f64(4.0) * V::Scalar::EPSILON * permanent;
+    if det.abs() > bound {
+        return Orientation::from_det(det, V::Scalar::ZERO);
+    }
+    let acx = Expansion::diff(a.x(), c.x());
+    let acy = Expansion::diff(a.y(), c.y());
+    let bcx = Expansion::diff(b.x(), c.x());
+    let bcy = Expansion::diff(b.y(), c.y());
+    let det = acx.mul(&bcy).add(&acy.mul(&bcx).neg());
+    Orientation::from_det(det.sign(), V::Scalar::ZERO)
+}
+
+/// Get the orientation of four 3D points using exact arithmetic
+///
+/// See [`orient3d`] for the meaning of the result and [`orient2d_exact`] for how
+/// exact arithmetic is used.
+pub fn orient3d_exact<V>(a: V, b: V, c: V, d: V) -> Orientation
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let [det, permanent] = orient3d_terms(a, b, c, d);
+    let bound = V::Scalar::from_f64(8.0) * V::Scalar::EPSILON * permanent;
+    if det.abs() > bound {
+        return Orientation::from_det(det, V::Scalar::ZERO);
+    }
+    let diff = |p: &V| {
+        [
+            Expansion::diff(p.x(), d.x()),
+            Expansion::diff(p.y(), d.y()),
+            Expansion::diff(p.z(), d.z()),
+        ]
+    };
+    let [ax, ay, az] = diff(&a);
+    let [bx, by, bz] = diff(&b);
+    let [cx, cy, cz] = diff(&c);
+    let minor = |p: &Expansion<V::Scalar>,
+                 q: &Expansion<V::Scalar>,
+                 r: &Expansion<V::Scalar>,
+                 s: &Expansion<V::Scalar>| { p.mul(q).add(&r.mul(s).neg()) };
+    let det = ax
+        .mul(&minor(&by, &cz, &bz, &cy))
+        .add(&ay.mul(&minor(&bz, &cx, &bx, &cz)))
+        .add(&az.mul(&minor(&bx, &cy, &by, &cx)));
+    Orientation::from_det(det.sign(), V::Scalar::ZERO)
+}
+
+/// Get the location of a 2D point relative to the circle through three other points
+/// using exact arithmetic
+///
+/// `a`, `b`, and `c` must be in counter-clockwise order.
+/// See [`orient2d_exact`] for how exact arithmetic is used.
+pub fn in_circle_exact<V>(a: V, b: V, c: V, d: V) -> Location
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let [det, permanent] = in_circle_terms(a, b, c, d);
+    let bound = V::Scalar::from_f64(16.0) * V::Scalar::EPSILON * permanent;
+    if det.abs() > bound {
+        return Location::from_det(det, V::Scalar::ZERO);
+    }
+    let diff = |p: &V| [Expansion::diff(p.x(), d.x()), Expansion::diff(p.y(), d.y())];
+    let [ax, ay] = diff(&a);
+    let [bx, by] = diff(&b);
+    let [cx, cy] = diff(&c);
+    let lift = |x: &Expansion<V::Scalar>, y: &Expansion<V::Scalar>| x.mul(x).add(&y.mul(y));
+    let cross = |px: &Expansion<V::Scalar>,
+                 py: &Expansion<V::Scalar>,
+                 qx: &Expansion<V::Scalar>,
+                 qy: &Expansion<V::Scalar>| { px.mul(qy).add(&qx.mul(py).neg()) };
+    let det = lift(&ax, &ay)
+        .mul(&cross(&bx, &by, &cx, &cy))
+        .add(&lift(&bx, &by).mul(&cross(&cx, &cy, &ax, &ay)))
+        .add(&lift(&cx, &cy).mul(&cross(&ax, &ay, &bx, &by)));
+    Location::from_det(det.sign(), V::Scalar::ZERO)
+}
@@ -0,0 +1,223 @@
+use crate::{
+    aabb::corners, quadratic_roots, Aabb, FloatingScalar, FloatingVecN, Scalar, Vec3, VecN,
+};
+
+/// A ray with an origin and a direction
+///
+/// The direction does not need to be normalized. Distances along the ray are
+/// measured in multiples of the direction's length.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray<V> {
+    /// The origin of the ray
+    pub origin: V,
+    /// The direction of the ray
+    pub dir: V,
+}
+
+/// The result of a ray intersection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit<V>
+where
+    V: VecN,
+{
+    /// The distance along the ray of the intersection
+    pub t: V::Scalar,
+    /// The point of intersection
+    pub point: V,
+    /// The unit normal of the surface at the point of intersection
+    pub normal: V,
+}
+
+impl<V> Ray<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    /// Create a new ray
+    pub fn new(origin: V, dir: V) -> Self {
+        Ray { origin, dir }
+    }
+    /// Get the point at a distance along the ray
+    pub fn at(&self, t: V::Scalar) -> V {
+        self.origin.add(self.dir.mul(t))
+    }
+    /// Get the range of distances along the ray that are within a box given by its corners
+    pub(crate) fn slab(&self, min: V, max: V) -> Option<(V::Scalar, V::Scalar)> {
+        let mut t0 = V::Scalar::ZERO;
+        let mut t1 = V::Scalar::INFINITY;
+        for i in 0..V::N {
+            let (o, d) = (self.origin.dim(i), self.dir.dim(i));
+            if d.is_zero() {
+                if o < min.dim(i) || o > max.dim(i) {
+                    return None;
+                }
+            } else {
+                let (a, b) = ((min.dim(i) - o) / d, (max.dim(i) - o) / d);
+                t0 = t0.maxx(a.minn(b));
+                t1 = t1.minn(a.maxx(b));
+            }
+        }
+        (t0 <= t1).then_some((t0, t1))
+    }
+    /// Intersect the ray with a sphere
+    ///
+    /// Rays starting inside the sphere hit its surface from the inside.
+    pub fn intersect_sphere(&self, center: V, radius: V::Scalar) -> Option<RayHit<V>> {
+        let offset = self.origin.sub(center);
+        let t = quadratic_roots(
+            self.dir.squared_mag(),
+            V::Scalar::TWO * offset.dot(self.dir),
+            offset.squared_mag() - radius * radius,
+        )
+        .into_iter()
+        .find(|&t| t >= V::Scalar::ZERO)?;
+        let point = self.at(t);
+        Some(RayHit {
+            t,
+            point,
+            normal: point.sub(center).unit(),
+        })
+    }
+    /// Intersect the ray with an aabb
+    ///
+    /// Rays starting inside the aabb hit its surface from the inside.
+    pub fn intersect_aabb<A>(&self, aabb: A) -> Option<RayHit<V>>
+    where
+        A: Aabb<Vector = V>,
+    {
+        let (min, max) = corners(&aabb);
+        let (t0, t1) = self.slab(min, max)?;
+        let inside = (0..V::N).all(|i| {
+            let o = self.origin.dim(i);
+            o > min.dim(i) && o < max.dim(i)
+        });
+        let t = if inside { t1 } else { t0 };
+        let point = self.at(t);
+        // The face hit is the one the point is relatively farthest toward
+        let center = min.midpoint(max);
+        let axis = (0..V::N)
+            .map(|i| {
+                let half = (max.dim(i) - min.dim(i)) / V::Scalar::TWO;
+                let offset = point.dim(i) - center.dim(i);
+                let closeness = if half.is_zero() {
+                    V::Scalar::INFINITY
+                } else {
+                    offset.abs() / half
+                };
+                (i, offset, closeness)
+            })
+            .max_by(|a, b| a.2.partial_cmp(&b.2).expect("distance comparison failed"))?;
+        let mut normal = V::ZERO;
+        let (i, offset, _) = axis;
+        normal.set_dim(
+            i,
+            if offset < V::Scalar::ZERO {
+                -V::Scalar::ONE
+            } else {
+                V::Scalar::ONE
+            },
+        );
+        Some(RayHit { t, point, normal })
+    }
+    /// Intersect the ray with a capsule
+    ///
+    /// The capsule is the set of points within `radius` of the segment from `a` to `b`.
+    /// Rays starting inside the capsule hit its surface from the inside.
+    pub fn intersect_capsule(&self, a: V, b: V, radius: V::Scalar) -> Option<RayHit<V>> {
+        let axis = b.sub(a);
+        let axis2 = axis.squared_mag();
+        let offset = self.origin.sub(a);
+        // The position along the axis of a point on the ray, scaled by the squared axis length
+        let along = |t: V::Scalar| axis.dot(offset) + t * axis.dot(self.dir);
+        let r2 = radius * radius;
+        let mut candidates = Vec::new();
+        if !axis2.is_zero() {
+            // The parts of the ray's origin and direction perpendicular to the axis
+            let w = offset.reject_from(axis);
+            let u = self.dir.reject_from(axis);
+            let body = quadratic_roots(u.dot(u), V::Scalar::TWO * u.dot(w), w.dot(w) - r2);
+            candidates.extend(body.into_iter().filter(|&t| {
+                let y = along(t);
+                y >= V::Scalar::ZERO && y <= axis2
+            }));
+        }
+        for (cap, outside) in [(a, false), (b, true)] {
+            let o = self.origin.sub(cap);
+            let hits = quadratic_roots(
+                self.dir.squared_mag(),
+                V::Scalar::TWO * o.dot(self.dir),
+                o.squared_mag() - r2,
+            );
+            candidates.extend(hits.into_iter().filter(|&t| {
+                let y = along(t);
+                if outside {
+                    y >= axis2
+                } else {
+                    y <= V::Scalar::ZERO
+                }
+            }));
+        }
+        let t = candidates
+            .into_iter()
+            .filter(|&t| t >= V::Scalar::ZERO)
+            .min_by(|a, b| a.partial_cmp(b).expect("distance comparison failed"))?;
+        let point = self.at(t);
+        let s = if axis2.is_zero() {
+            V::Scalar::ZERO
+        } else {
+            (along(t) / axis2)
+                .maxx(V::Scalar::ZERO)
+                .minn(V::Scalar::ONE)
+        };
+        Some(RayHit {
+            t,
+            point,
+            normal: point.sub(a.add(axis.mul(s))).unit(),
+        })
+    }
+}
+
+impl<V> Ray<V>
+where
+    V: Vec3 + Copy,
+    V::Scalar: FloatingScalar,
+{
+    /// Get the distance and barycentric coordinates of the intersection with a 3D triangle
+    pub(crate) fn triangle_barycentric(&self, [a, b, c]: [V; 3]) -> Option<[V::Scalar; 3]> {
+        let e1 = b.sub(a);
+        let e2 = c.sub(a);
+        let p = self.dir.cross(e2);
+        let det = e1.dot(p);
+        if det.is_zero() {
+            return None;
+        }
+        let s = self.origin.sub(a);
+        let u = s.dot(p) / det;
+        if u < V::Scalar::ZERO || u > V::Scalar::ONE {
+            return None;
+        }
+        let q = s.cross(e1);
+        let v = self.dir.dot(q) / det;
+        if v < V::Scalar::ZERO || u + v > V::Scalar::ONE {
+            return None;
+        }
+        let t = e2.dot(q) / det;
+        if t < V::Scalar::ZERO {
+            return None;
+        }
+        Some([t, u, v])
+    }
+    /// Intersect the ray with a 3D triangle
+    ///
+    /// Both sides of the triangle are hit. The normal faces the side the vertices
+    /// appear counter-clockwise from.
+    pub fn intersect_triangle(&self, triangle: [V; 3]) -> Option<RayHit<V>> {
+        let [t, ..] = self.triangle_barycentric(triangle)?;
+        let [a, b, c] = triangle;
+        Some(RayHit {
+            t,
+            point: self.at(t),
+            normal: b.sub(a).cross(c.sub(a)).unit(),
+        })
+    }
+}
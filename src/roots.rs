@@ -0,0 +1,148 @@
+use crate::FloatingScalar;
+
+/// Get the real roots of the quadratic `a * x² + b * x + c`
+///
+/// The roots are sorted in ascending order, and repeated roots are only returned once.
+/// If `a` is zero, the roots of the linear equation `b * x + c` are returned instead.
+pub fn quadratic_roots<T>(a: T, b: T, c: T) -> Vec<T>
+where
+    T: FloatingScalar,
+{
+    if a.is_near_zero(b.abs().maxx(c.abs())) {
+        return if b.is_zero() {
+            Vec::new()
+        } else {
+            vec![-c / b]
+        };
+    }
+    let disc = b * b - T::from_f64(4.0) * a * c;
+    if disc < T::ZERO {
+        return Vec::new();
+    }
+    if disc.is_near_zero(b * b) {
+        return vec![-b / (T::TWO * a)];
+    }
+    // Avoid cancellation by never subtracting nearly equal values
+    let sqrt = disc.sqrt();
+    let q = if b < T::ZERO {
+        (sqrt - b) / T::TWO
+    } else {
+        -(b + sqrt) / T::TWO
+    };
+    let (r1, r2) = (q / a, c / q);
+    vec![r1.minn(r2), r1.maxx(r2)]
+}
+
+/// Get the real roots of the cubic `a * x³ + b * x² + c * x + d`
+///
+/// The roots are sorted in ascending order, and repeated roots are only returned once.
+/// If `a` is zero, the roots of the quadratic `b * x² + c * x + d` are returned instead.
+pub fn cubic_roots<T>(a: T, b: T, c: T, d: T) -> Vec<T>
+where
+    T: FloatingScalar,
+{
+    if a.is_near_zero(b.abs().maxx(c.abs()).maxx(d.abs())) {
+        return quadratic_roots(b, c, d);
+    }
+    // Substitute x = t - b / 3 to get the depressed cubic t³ + p * t + q
+    let three = T::from_f64(3.0);
+    let (b, c, d) = (b / a, c / a, d / a);
+    let shift = b / three;
+    let p = c - b * shift;
+    let q = T::TWO * shift * shift * shift - shift * c + d;
+    let half_q = q / T::TWO;
+    let third_p = p / three;
+    let cubed = third_p * third_p * third_p;
+    let disc = half_q * half_q + cubed;
+    // The discriminant loses precision to cancellation, so repeated roots need some slack
+    let slack = T::from_f64(64.0) * (half_q * half_q + cubed.abs());
+    let mut roots = if disc.is_near_zero(slack) {
+        if p.is_near_zero(shift.abs().maxx(T::ONE)) {
+            vec![T::ZERO]
+        } else {
+            vec![three * q / p, -three * q / (T::TWO * p)]
+        }
+    } else if disc > T::ZERO {
+        let sqrt = disc.sqrt();
+        vec![(sqrt - half_q).cbrt() - (sqrt + half_q).cbrt()]
+    } else {
+        let r = (-third_p).sqrt();
+        let cos = (-half_q / (r * r * r)).maxx(-T::ONE).minn(T::ONE);
+        let phi = (T::ONE - cos * cos).sqrt().atan2(cos);
+        (0..3)
+            .map(|k| T::TWO * r * ((phi + T::TAU * T::from_f64(k as f64)) / three).cos())
+            .collect()
+    };
+    for root in &mut roots {
+        *root -= shift;
+        // Polish the root with a Newton step
+        let x = *root;
+        let f = ((x + b) * x + c) * x + d;
+        let df = (three * x + T::TWO * b) * x + c;
+        if !df.is_zero() {
+            *root = x - f / df;
+        }
+    }
+    roots.sort_by(|a, b| a.partial_cmp(b).expect("root comparison failed"));
+    roots.dedup();
+    roots
+}
+
+/// Get the real roots of the quartic `a * x⁴ + b * x³ + c * x² + d * x + e`
+///
+/// The roots are sorted in ascending order, and repeated roots are only returned once.
+/// If `a` is zero, the roots of the cubic `b * x³ + c * x² + d * x + e` are returned instead.
+pub fn quartic_roots<T>(a: T, b: T, c: T, d: T, e: T) -> Vec<T>
+where
+    T: FloatingScalar,
+{
+    if a.is_near_zero(b.abs().maxx(c.abs()).maxx(d.abs()).maxx(e.abs())) {
+        return cubic_roots(b, c, d, e);
+    }
+    // Substitute x = y - b / 4 to get the depressed quartic y⁴ + p * y² + q * y + r
+    let four = T::from_f64(4.0);
+    let (b, c, d, e) = (b / a, c / a, d / a, e / a);
+    let shift = b / four;
+    let shift2 = shift * shift;
+    let p = c - T::from_f64(6.0) * shift2;
+    let q = d - T::TWO * c * shift + T::from_f64(8.0) * shift2 * shift;
+    let r = e - d * shift + c * shift2 - T::from_f64(3.0) * shift2 * shift2;
+    let mut roots = Vec::with_capacity(4);
+    if q.is_near_zero(p.abs().maxx(r.abs()).maxx(T::ONE)) {
+        // Biquadratic in y²
+        for z in quadratic_roots(T::ONE, p, r) {
+            if z >= T::ZERO {
+                let y = z.sqrt();
+                roots.extend([y, -y]);
+            }
+        }
+    } else {
+        // Ferrari's method with a positive root of the resolvent cubic
+        let m = cubic_roots(T::ONE, p, p * p / four - r, -q * q / T::from_f64(8.0))
+            .into_iter()
+            .fold(T::ZERO, T::maxx);
+        if m <= T::ZERO {
+            return Vec::new();
+        }
+        let s = (T::TWO * m).sqrt();
+        let k = q / (T::TWO * s);
+        roots.extend(quadratic_roots(T::ONE, s, p / T::TWO + m - k));
+        roots.extend(quadratic_roots(T::ONE, -s, p / T::TWO + m + k));
+    }
+    for root in &mut roots {
+        *root -= shift;
+        // Polish the root with Newton steps
+        for _ in 0..2 {
+            let x = *root;
+            let f = (((x + b) * x + c) * x + d) * x + e;
+            let df = ((four * x + T::from_f64(3.0) * b) * x + T::TWO * c) * x + d;
+            if df.is_zero() {
+                break;
+            }
+            *root = x - f / df;
+        }
+    }
+    roots.sort_by(|a, b| a.partial_cmp(b).expect("root comparison failed"));
+    roots.dedup();
+    roots
+}
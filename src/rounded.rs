@@ -0,0 +1,151 @@
+use crate::{polygon::vec2, Aabb, FloatingScalar, Scalar, VecN, XVec, YVec};
+
+/// A 2D rectangle with rounded corners
+///
+/// The corner radii are in order starting from the corner at the aabb's origin,
+/// then along the X axis, then diagonally opposite the origin, then along the Y axis.
+/// Radii larger than half the rectangle's smaller side are clamped to it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundedRect<T> {
+    /// The aabb of the rectangle
+    pub aabb: [[T; 2]; 2],
+    /// The radius of each corner
+    pub radii: [T; 4],
+}
+
+impl<T> RoundedRect<T>
+where
+    T: FloatingScalar,
+{
+    /// Create a new rounded rectangle
+    pub fn new<A>(aabb: A, radii: [T; 4]) -> Self
+    where
+        A: Aabb<Vector = [T; 2]>,
+    {
+        RoundedRect {
+            aabb: [
+                [aabb.origin_dim(0), aabb.origin_dim(1)],
+                [aabb.size_dim(0), aabb.size_dim(1)],
+            ],
+            radii,
+        }
+    }
+    /// Create a new rounded rectangle with the same radius at every corner
+    pub fn uniform<A>(aabb: A, radius: T) -> Self
+    where
+        A: Aabb<Vector = [T; 2]>,
+    {
+        Self::new(aabb, [radius; 4])
+    }
+    fn radius(&self, corner: usize) -> T {
+        let [w, h] = self.aabb[1];
+        self.radii[corner]
+            .maxx(T::ZERO)
+            .minn(w.abs().minn(h.abs()) / T::TWO)
+    }
+    /// Get the signed distance from a point to the rectangle's boundary
+    ///
+    /// The distance is negative for points inside the rectangle.
+    pub fn signed_distance<V>(&self, point: V) -> T
+    where
+        V: VecN<Scalar = T>,
+    {
+        let center = self.aabb.center();
+        let half = self.aabb[1].map(|s| s.abs() / T::TWO);
+        let [dx, dy] = [point.x() - center[0], point.y() - center[1]];
+        let corner = match (dx >= T::ZERO, dy >= T::ZERO) {
+            (false, false) => 0,
+            (true, false) => 1,
+            (true, true) => 2,
+            (false, true) => 3,
+        };
+        let r = self.radius(corner);
+        let qx = dx.abs() - half[0] + r;
+        let qy = dy.abs() - half[1] + r;
+        let outside = [qx.maxx(T::ZERO), qy.maxx(T::ZERO)];
+        qx.maxx(qy).minn(T::ZERO) + (outside[0].square() + outside[1].square()).sqrt() - r
+    }
+    /// Check if the rectangle contains a point
+    pub fn contains<V>(&self, point: V) -> bool
+    where
+        V: VecN<Scalar = T>,
+    {
+        self.signed_distance(point) <= T::ZERO
+    }
+    /// Get a polygon approximating the rectangle with its vertices in counter-clockwise order
+    ///
+    /// `step` is the maximum angle in radians between consecutive points on a corner's arc.
+    pub fn to_polygon<V>(&self, step: T) -> Vec<V>
+    where
+        V: VecN<Scalar = T>,
+    {
+        let [[x, y], [w, h]] = self.aabb;
+        let [min_x, max_x] = [x.minn(x + w), x.maxx(x + w)];
+        let [min_y, max_y] = [y.minn(y + h), y.maxx(y + h)];
+        let quarter = T::PI / T::TWO;
+        let segments = if step > T::ZERO {
+            (quarter / step).to_f64().ceil().max(1.0) as usize
+        } else {
+            1
+        };
+        let mut polygon = Vec::new();
+        let corners = [
+            ([T::ONE, T::ONE], [min_x, min_y]),
+            ([-T::ONE, T::ONE], [max_x, min_y]),
+            ([-T::ONE, -T::ONE], [max_x, max_y]),
+            ([T::ONE, -T::ONE], [min_x, max_y]),
+        ];
+        for (i, ([sx, sy], [cx, cy])) in corners.into_iter().enumerate() {
+            let r = self.radius(i);
+            if r.is_zero() {
+                polygon.push(vec2(cx, cy));
+                continue;
+            }
+            let [ax, ay] = [cx + sx * r, cy + sy * r];
+            // Corners are visited counter-clockwise, starting with the arc from 180 to 270 degrees
+            let start = T::PI + quarter * T::from_f64(i as f64);
+            for k in 0..=segments {
+                let [ux, uy] =
+                    (start + quarter * T::from_f64(k as f64 / segments as f64)).angle_as_vector();
+                polygon.push(vec2(ax + ux * r, ay + uy * r));
+            }
+        }
+        polygon
+    }
+}
+
+/// Get points evenly spaced in angle around a superellipse
+///
+/// The superellipse is the curve `|x / a|^n + |y / b|^n = 1`, where `[a, b]` are the radii
+/// and `n` is the exponent. An exponent of 2 gives an ellipse, and larger exponents
+/// approach a rectangle. The points are in counter-clockwise order.
+pub fn superellipse<V>(
+    center: V,
+    radii: [V::Scalar; 2],
+    exponent: V::Scalar,
+    count: usize,
+) -> Vec<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let power = V::Scalar::TWO / exponent;
+    let shape = |c: V::Scalar| {
+        let magnitude = c.abs().powf(power);
+        if c < V::Scalar::ZERO {
+            -magnitude
+        } else {
+            magnitude
+        }
+    };
+    (0..count)
+        .map(|i| {
+            let angle = V::Scalar::TAU * V::Scalar::from_f64(i as f64 / count as f64);
+            let [cos, sin] = angle.angle_as_vector();
+            let mut point = center;
+            point.set_x(center.x() + radii[0] * shape(cos));
+            point.set_y(center.y() + radii[1] * shape(sin));
+            point
+        })
+        .collect()
+}
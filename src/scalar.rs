@@ -20,8 +20,47 @@ pub trait Scalar:
     const ONE: Self;
     /// The value of 2
     const TWO: Self;
+    /// The smallest finite value
+    const MIN: Self;
+    /// The largest finite value
+    const MAX: Self;
     /// Get the absolute value of the number
     fn abs(self) -> Self;
+    /// Get the sign of the number
+    ///
+    /// This is `1` for positive numbers, `-1` for negative numbers, and the number itself
+    /// for zero.
+    fn signum(self) -> Self {
+        if self > Self::ZERO {
+            Self::ONE
+        } else if self < Self::ZERO {
+            Self::ZERO - Self::ONE
+        } else {
+            self
+        }
+    }
+    /// Get the absolute difference between this `Scalar` and another
+    ///
+    /// Unlike `(a - b).abs()`, this does not underflow for unsigned integers.
+    /// For signed integers, the difference must still fit in the type.
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+    /// Get the midpoint between this `Scalar` and another
+    ///
+    /// This does not overflow. For integers, the result is rounded towards zero.
+    ///
+    /// The default implementation halves each number before adding them. It does not
+    /// overflow either, but for integers of opposite signs it may round away from zero.
+    fn midpoint(self, other: Self) -> Self {
+        let (a, b) = (self / Self::TWO, other / Self::TWO);
+        let rest = (self - a * Self::TWO) + (other - b * Self::TWO);
+        a + b + rest / Self::TWO
+    }
     /// Get the max of this `Scalar` and another
     ///
     /// This function is named to not conflict with the
@@ -52,6 +91,11 @@ macro_rules! scalar_unsigned_impl {
             const ZERO: Self = 0;
             const ONE: Self = 1;
             const TWO: Self = 2;
+            const MIN: Self = <$type>::MIN;
+            const MAX: Self = <$type>::MAX;
+            fn midpoint(self, other: Self) -> Self {
+                <$type>::midpoint(self, other)
+            }
             fn abs(self) -> Self {
                 self
             }
@@ -65,6 +109,11 @@ macro_rules! scalar_signed_impl {
             const ZERO: Self = 0;
             const ONE: Self = 1;
             const TWO: Self = 2;
+            const MIN: Self = <$type>::MIN;
+            const MAX: Self = <$type>::MAX;
+            fn midpoint(self, other: Self) -> Self {
+                <$type>::midpoint(self, other)
+            }
             fn abs(self) -> Self {
                 self.abs()
             }
@@ -78,6 +127,11 @@ macro_rules! scalar_float_impl {
             const ZERO: Self = 0.0;
             const ONE: Self = 1.0;
             const TWO: Self = 2.0;
+            const MIN: Self = <$type>::MIN;
+            const MAX: Self = <$type>::MAX;
+            fn midpoint(self, other: Self) -> Self {
+                <$type>::midpoint(self, other)
+            }
             fn abs(self) -> Self {
                 self.abs()
             }
@@ -102,16 +156,219 @@ scalar_signed_impl!(isize);
 scalar_float_impl!(f32);
 scalar_float_impl!(f64);
 
+/// Trait for integer scalar numbers
+pub trait IntegerScalar:
+    Scalar
+    + Rem<Self, Output = Self>
+    + BitAnd<Self, Output = Self>
+    + BitOr<Self, Output = Self>
+    + BitXor<Self, Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+{
+    /// Divide, rounding so that the remainder is non-negative
+    fn div_euclid(self, other: Self) -> Self;
+    /// Get the non-negative remainder of a division
+    fn rem_euclid(self, other: Self) -> Self;
+    /// Add, returning `None` on overflow
+    fn checked_add(self, other: Self) -> Option<Self>;
+    /// Subtract, returning `None` on overflow
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    /// Multiply, returning `None` on overflow
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// Add, wrapping around on overflow
+    fn wrapping_add(self, other: Self) -> Self;
+    /// Subtract, wrapping around on overflow
+    fn wrapping_sub(self, other: Self) -> Self;
+    /// Multiply, wrapping around on overflow
+    fn wrapping_mul(self, other: Self) -> Self;
+    /// Add, clamping to the bounds of the type on overflow
+    fn saturating_add(self, other: Self) -> Self;
+    /// Subtract, clamping to the bounds of the type on overflow
+    fn saturating_sub(self, other: Self) -> Self;
+    /// Multiply, clamping to the bounds of the type on overflow
+    fn saturating_mul(self, other: Self) -> Self;
+}
+
+macro_rules! integer_scalar_impl {
+    ($($type:ty),*) => {
+        $(impl IntegerScalar for $type {
+            fn div_euclid(self, other: Self) -> Self {
+                <$type>::div_euclid(self, other)
+            }
+            fn rem_euclid(self, other: Self) -> Self {
+                <$type>::rem_euclid(self, other)
+            }
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$type>::checked_add(self, other)
+            }
+            fn checked_sub(self, other: Self) -> Option<Self> {
+                <$type>::checked_sub(self, other)
+            }
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$type>::checked_mul(self, other)
+            }
+            fn wrapping_add(self, other: Self) -> Self {
+                <$type>::wrapping_add(self, other)
+            }
+            fn wrapping_sub(self, other: Self) -> Self {
+                <$type>::wrapping_sub(self, other)
+            }
+            fn wrapping_mul(self, other: Self) -> Self {
+                <$type>::wrapping_mul(self, other)
+            }
+            fn saturating_add(self, other: Self) -> Self {
+                <$type>::saturating_add(self, other)
+            }
+            fn saturating_sub(self, other: Self) -> Self {
+                <$type>::saturating_sub(self, other)
+            }
+            fn saturating_mul(self, other: Self) -> Self {
+                <$type>::saturating_mul(self, other)
+            }
+        })*
+    };
+}
+
+integer_scalar_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Trait for converting a scalar to another scalar type
+///
+/// This works like an `as` cast. Floats are truncated towards zero and saturated when
+/// cast to integers, and `NaN` becomes `0`.
+pub trait CastScalar<T>: Scalar {
+    /// Convert the scalar to another type
+    fn cast(self) -> T;
+}
+
+macro_rules! cast_scalar_impl {
+    ($($from:ty),*) => {
+        $(cast_scalar_impl!(
+            @from $from;
+            u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+        );)*
+    };
+    (@from $from:ty; $($to:ty),*) => {
+        $(impl CastScalar<$to> for $from {
+            fn cast(self) -> $to {
+                self as $to
+            }
+        })*
+    };
+}
+
+cast_scalar_impl!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
 /// Trait for floating-point scalar numbers
-pub trait FloatingScalar: Scalar {
+pub trait FloatingScalar: Scalar + Neg<Output = Self> {
     /// The value of Tau, or 2π
     const TAU: Self;
     /// The value of π
     const PI: Self;
     /// The epsilon value
     const EPSILON: Self;
+    /// The value of positive infinity
+    const INFINITY: Self;
+    /// Convert an `f64` to the scalar
+    fn from_f64(f: f64) -> Self;
+    /// Convert the scalar to an `f64`
+    fn to_f64(self) -> f64;
     /// Get the sqare root of the scalar
     fn sqrt(self) -> Self;
+    /// Get the reciprocal of the square root of the scalar
+    ///
+    /// With the `fast-rsqrt` feature enabled, this uses a bit-level approximation
+    /// refined by one Newton step, which has a relative error of about 0.2%.
+    fn rsqrt(self) -> Self {
+        Self::ONE / self.sqrt()
+    }
+    /// Get the cube root of the scalar
+    fn cbrt(self) -> Self {
+        self.signum() * self.abs().powf(Self::ONE / (Self::TWO + Self::ONE))
+    }
+    /// Raise the scalar to a floating-point power
+    ///
+    /// The default implementation computes `exp(n * ln(self))`, so it is `NaN` for
+    /// negative scalars.
+    fn powf(self, n: Self) -> Self {
+        (n * self.ln()).exp()
+    }
+    /// Raise the scalar to an integer power
+    fn powi(self, n: i32) -> Self {
+        let (mut base, mut exp, mut result) = (self, n.unsigned_abs(), Self::ONE);
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        if n < 0 {
+            Self::ONE / result
+        } else {
+            result
+        }
+    }
+    /// Get `e` raised to the power of the scalar
+    ///
+    /// The default implementation computes this as an `f64`.
+    fn exp(self) -> Self {
+        Self::from_f64(self.to_f64().exp())
+    }
+    /// Get the natural logarithm of the scalar
+    ///
+    /// The default implementation computes this as an `f64`.
+    fn ln(self) -> Self {
+        Self::from_f64(self.to_f64().ln())
+    }
+    /// Get the largest integer less than or equal to the scalar
+    fn floor(self) -> Self {
+        let trunc = self.trunc();
+        if trunc > self {
+            trunc - Self::ONE
+        } else {
+            trunc
+        }
+    }
+    /// Get the smallest integer greater than or equal to the scalar
+    fn ceil(self) -> Self {
+        let trunc = self.trunc();
+        if trunc < self {
+            trunc + Self::ONE
+        } else {
+            trunc
+        }
+    }
+    /// Get the nearest integer to the scalar, rounding half-way cases away from zero
+    fn round(self) -> Self {
+        let trunc = self.trunc();
+        if (self - trunc).abs() * Self::TWO >= Self::ONE {
+            trunc + self.signum()
+        } else {
+            trunc
+        }
+    }
+    /// Get the integer part of the scalar
+    ///
+    /// The default implementation computes this as an `f64`.
+    fn trunc(self) -> Self {
+        Self::from_f64(self.to_f64().trunc())
+    }
+    /// Check if the scalar is `NaN`
+    #[allow(clippy::eq_op)]
+    fn is_nan(self) -> bool {
+        self != self
+    }
+    /// Check if the scalar is neither infinite nor `NaN`
+    fn is_finite(self) -> bool {
+        !self.is_nan() && self.abs() != Self::INFINITY
+    }
+    /// Get the fractional part of the scalar
+    ///
+    /// This has the same sign as the scalar.
+    fn fract(self) -> Self {
+        self - self.trunc()
+    }
     /// Square the scalar
     fn square(self) -> Self {
         self * self
@@ -124,12 +381,30 @@ pub trait FloatingScalar: Scalar {
     fn tan(self) -> Self {
         self.sin() / self.cos()
     }
+    /// Get the arccosine
+    fn acos(self) -> Self {
+        (Self::ONE - self * self).sqrt().atan2(self)
+    }
     /// Get the four-quadrant arctangent
     fn atan2(self, other: Self) -> Self;
+    /// Compute `self * a + b` with only one rounding error
+    ///
+    /// The default implementation rounds twice.
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
     /// Linear interpolate the scalar with another
     fn lerp(self, other: Self, t: Self) -> Self {
         (Self::ONE - t) * self + t * other
     }
+    /// Move the scalar toward a target with exponential decay over a time step
+    ///
+    /// `lambda` is the decay rate, so the remaining distance shrinks by a factor of `e`
+    /// every `1 / lambda` units of time. Unlike interpolating by a fixed factor each frame,
+    /// this gives the same motion at any frame rate.
+    fn exp_decay(self, target: Self, lambda: Self, dt: Self) -> Self {
+        target + (self - target) * (-lambda * dt).exp()
+    }
     /// Get the unit vector corresponding to an angle in radians defined by the scalar
     fn angle_as_vector(self) -> [Self; 2] {
         [self.cos(), self.sin()]
@@ -145,26 +420,86 @@ pub trait FloatingScalar: Scalar {
 }
 
 macro_rules! floating_scalar_impl {
-    ($type:ty, $pi:expr, $epsilon:expr) => {
+    ($type:ty, $pi:expr, $epsilon:expr, $magic:expr) => {
         impl FloatingScalar for $type {
             const PI: Self = $pi;
             const TAU: Self = $pi * 2.0;
             const EPSILON: Self = $epsilon;
+            const INFINITY: Self = <$type>::INFINITY;
+            fn from_f64(f: f64) -> Self {
+                f as Self
+            }
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
             fn sqrt(self) -> Self {
                 Self::sqrt(self)
             }
+            #[cfg(not(feature = "fast-rsqrt"))]
+            fn rsqrt(self) -> Self {
+                1.0 / Self::sqrt(self)
+            }
+            #[cfg(feature = "fast-rsqrt")]
+            fn rsqrt(self) -> Self {
+                let y = Self::from_bits($magic - (self.to_bits() >> 1));
+                y * (1.5 - 0.5 * self * y * y)
+            }
+            fn cbrt(self) -> Self {
+                Self::cbrt(self)
+            }
+            fn powf(self, n: Self) -> Self {
+                Self::powf(self, n)
+            }
+            fn powi(self, n: i32) -> Self {
+                Self::powi(self, n)
+            }
+            fn exp(self) -> Self {
+                Self::exp(self)
+            }
+            fn ln(self) -> Self {
+                Self::ln(self)
+            }
+            fn floor(self) -> Self {
+                Self::floor(self)
+            }
+            fn ceil(self) -> Self {
+                Self::ceil(self)
+            }
+            fn round(self) -> Self {
+                Self::round(self)
+            }
+            fn trunc(self) -> Self {
+                Self::trunc(self)
+            }
+            fn is_nan(self) -> bool {
+                Self::is_nan(self)
+            }
+            fn is_finite(self) -> bool {
+                Self::is_finite(self)
+            }
             fn cos(self) -> Self {
                 Self::cos(self)
             }
             fn sin(self) -> Self {
                 Self::sin(self)
             }
+            fn acos(self) -> Self {
+                Self::acos(self)
+            }
             fn atan2(self, other: Self) -> Self {
                 self.atan2(other)
             }
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                Self::mul_add(self, a, b)
+            }
         }
     };
 }
 
-floating_scalar_impl!(f32, std::f32::consts::PI, std::f32::EPSILON);
-floating_scalar_impl!(f64, std::f64::consts::PI, std::f64::EPSILON);
+floating_scalar_impl!(f32, std::f32::consts::PI, f32::EPSILON, 0x5f37_5a86);
+floating_scalar_impl!(
+    f64,
+    std::f64::consts::PI,
+    f64::EPSILON,
+    0x5fe6_eb50_c7b5_37a9
+);
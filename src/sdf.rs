@@ -0,0 +1,233 @@
+use crate::{quartic_roots, FloatingScalar, FloatingVecN, Ray, RayHit, Scalar, VecN};
+
+/// The maximum number of steps taken by [`sphere_trace`]
+const MAX_STEPS: usize = 512;
+
+/// Find the first point along a ray where a signed distance function is zero
+///
+/// This steps along the ray by the distance to the surface until it is within
+/// `tolerance` of it. If the ray starts inside the surface, the point where it
+/// exits is found instead. Returns `None` if no surface is found within `max_t`.
+pub fn sphere_trace<V, F>(
+    ray: Ray<V>,
+    max_t: V::Scalar,
+    tolerance: V::Scalar,
+    sdf: F,
+) -> Option<V::Scalar>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+    F: Fn(V) -> V::Scalar,
+{
+    let speed = ray.dir.mag();
+    if speed.is_zero() {
+        return None;
+    }
+    let mut t = V::Scalar::ZERO;
+    for _ in 0..MAX_STEPS {
+        let dist = sdf(ray.at(t)).abs();
+        if dist <= tolerance {
+            return Some(t);
+        }
+        t += dist / speed;
+        if t > max_t {
+            return None;
+        }
+    }
+    None
+}
+
+/// Estimate the normal of a signed distance function's surface from its gradient
+///
+/// The gradient is estimated with central differences of size `h`.
+pub fn sdf_normal<V, F>(point: V, h: V::Scalar, sdf: F) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+    F: Fn(V) -> V::Scalar,
+{
+    let mut gradient = V::ZERO;
+    for i in 0..V::N {
+        let mut offset = V::ZERO;
+        offset.set_dim(i, h);
+        gradient.set_dim(i, sdf(point.add(offset)) - sdf(point.sub(offset)));
+    }
+    gradient.unit()
+}
+
+/// Sphere trace a shape within its bounds and estimate the normal at the hit
+fn trace_bounded<T, F>(ray: Ray<[T; 3]>, min: [T; 3], max: [T; 3], sdf: F) -> Option<RayHit<[T; 3]>>
+where
+    T: FloatingScalar,
+    F: Fn([T; 3]) -> T,
+{
+    let (t0, t1) = ray.slab(min, max)?;
+    let scale = max.sub(min).max_dim();
+    let tolerance = scale * T::EPSILON.sqrt();
+    let start = Ray::new(ray.at(t0), ray.dir);
+    let t = t0 + sphere_trace(start, t1 - t0, tolerance, &sdf)?;
+    let point = ray.at(t);
+    Some(RayHit {
+        t,
+        point,
+        normal: sdf_normal(point, tolerance, sdf),
+    })
+}
+
+/// A torus around an axis parallel to Z
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Torus<T> {
+    /// The center of the torus
+    pub center: [T; 3],
+    /// The distance from the center to the middle of the tube
+    pub major_radius: T,
+    /// The radius of the tube
+    pub minor_radius: T,
+}
+
+impl<T> Torus<T>
+where
+    T: FloatingScalar,
+{
+    /// Create a new torus
+    pub fn new(center: [T; 3], major_radius: T, minor_radius: T) -> Self {
+        Torus {
+            center,
+            major_radius,
+            minor_radius,
+        }
+    }
+    /// Get the signed distance from a point to the torus's surface
+    pub fn signed_distance(&self, point: [T; 3]) -> T {
+        let [x, y, z] = point.sub(self.center);
+        let ring = (x * x + y * y).sqrt() - self.major_radius;
+        (ring * ring + z * z).sqrt() - self.minor_radius
+    }
+    /// Intersect a ray with the torus
+    ///
+    /// The intersection is found by solving a quartic equation.
+    pub fn raycast(&self, ray: Ray<[T; 3]>) -> Option<RayHit<[T; 3]>> {
+        let o = ray.origin.sub(self.center);
+        let d = ray.dir;
+        let (r2, s2) = (self.major_radius.square(), self.minor_radius.square());
+        let dd = d.dot(d);
+        let od = o.dot(d);
+        let k = o.dot(o) - r2 - s2;
+        let four = T::from_f64(4.0);
+        let t = quartic_roots(
+            dd * dd,
+            four * dd * od,
+            T::TWO * dd * k + four * od * od + four * r2 * d[2] * d[2],
+            four * od * k + T::from_f64(8.0) * r2 * o[2] * d[2],
+            k * k - four * r2 * (s2 - o[2] * o[2]),
+        )
+        .into_iter()
+        .find(|&t| t >= T::ZERO)?;
+        let point = ray.at(t);
+        let [x, y, z] = point.sub(self.center);
+        let ring = [x, y, T::ZERO].unit().mul(self.major_radius);
+        Some(RayHit {
+            t,
+            point,
+            normal: [x, y, z].sub(ring).unit(),
+        })
+    }
+}
+
+/// A box with rounded edges and corners
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RoundedBox<T> {
+    /// The outer bounds of the box
+    pub aabb: [[T; 3]; 2],
+    /// The radius of the edges and corners
+    pub radius: T,
+}
+
+impl<T> RoundedBox<T>
+where
+    T: FloatingScalar,
+{
+    /// Create a new rounded box
+    pub fn new(aabb: [[T; 3]; 2], radius: T) -> Self {
+        RoundedBox { aabb, radius }
+    }
+    fn bounds(&self) -> ([T; 3], [T; 3]) {
+        let [origin, size] = self.aabb;
+        let end = origin.add(size);
+        (
+            std::array::from_fn(|i| origin[i].minn(end[i])),
+            std::array::from_fn(|i| origin[i].maxx(end[i])),
+        )
+    }
+    /// Get the signed distance from a point to the box's surface
+    pub fn signed_distance(&self, point: [T; 3]) -> T {
+        let (min, max) = self.bounds();
+        let center = min.midpoint(max);
+        let half = max.sub(min).div(T::TWO);
+        let r = self.radius.minn(half.min_dim());
+        let q: [T; 3] = std::array::from_fn(|i| (point[i] - center[i]).abs() - half[i] + r);
+        q.map(|q| q.maxx(T::ZERO)).mag() + q.max_dim().minn(T::ZERO) - r
+    }
+    /// Intersect a ray with the box by sphere tracing
+    pub fn raycast(&self, ray: Ray<[T; 3]>) -> Option<RayHit<[T; 3]>> {
+        let (min, max) = self.bounds();
+        trace_bounded(ray, min, max, |p| self.signed_distance(p))
+    }
+}
+
+/// A solid cone with a circular base
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Cone<T> {
+    /// The tip of the cone
+    pub apex: [T; 3],
+    /// The center of the base
+    pub base: [T; 3],
+    /// The radius of the base
+    pub radius: T,
+}
+
+impl<T> Cone<T>
+where
+    T: FloatingScalar,
+{
+    /// Create a new cone
+    pub fn new(apex: [T; 3], base: [T; 3], radius: T) -> Self {
+        Cone { apex, base, radius }
+    }
+    /// Get the signed distance from a point to the cone's surface
+    pub fn signed_distance(&self, point: [T; 3]) -> T {
+        let axis = self.base.sub(self.apex);
+        let axis2 = axis.squared_mag();
+        let offset = point.sub(self.apex);
+        // The position along the axis as a fraction of its length
+        let along = offset.dot(axis) / axis2;
+        let x = (offset.squared_mag() - along * along * axis2)
+            .maxx(T::ZERO)
+            .sqrt();
+        let half = T::ONE / T::TWO;
+        // Distance to the base cap
+        let cap_x = (x - if along < half { T::ZERO } else { self.radius }).maxx(T::ZERO);
+        let cap_y = (along - half).abs() - half;
+        // Distance to the slanted side
+        let r = self.radius;
+        let f = ((r * x + along * axis2) / (r * r + axis2))
+            .maxx(T::ZERO)
+            .minn(T::ONE);
+        let side_x = x - f * r;
+        let side_y = along - f;
+        let sign = if side_x < T::ZERO && cap_y < T::ZERO {
+            -T::ONE
+        } else {
+            T::ONE
+        };
+        let cap = cap_x * cap_x + cap_y * cap_y * axis2;
+        let side = side_x * side_x + side_y * side_y * axis2;
+        sign * cap.minn(side).sqrt()
+    }
+    /// Intersect a ray with the cone by sphere tracing
+    pub fn raycast(&self, ray: Ray<[T; 3]>) -> Option<RayHit<[T; 3]>> {
+        let min = std::array::from_fn(|i| self.apex[i].minn(self.base[i] - self.radius));
+        let max = std::array::from_fn(|i| self.apex[i].maxx(self.base[i] + self.radius));
+        trace_bounded(ray, min, max, |p| self.signed_distance(p))
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{FloatingScalar, FloatingVecN, Scalar, VecN};
+
+/// Evaluate the real spherical harmonics basis up to band 2 for a direction
+///
+/// The 9 values are ordered by band, and within each band from `m = -l` to `m = l`.
+/// The direction is normalized first.
+pub fn sh_basis<T>(direction: [T; 3]) -> [T; 9]
+where
+    T: FloatingScalar,
+{
+    let [x, y, z] = direction.unit();
+    let c = T::from_f64;
+    [
+        c(0.282_094_791_773_878_1),
+        c(0.488_602_511_902_919_9) * y,
+        c(0.488_602_511_902_919_9) * z,
+        c(0.488_602_511_902_919_9) * x,
+        c(1.092_548_430_592_079_2) * x * y,
+        c(1.092_548_430_592_079_2) * y * z,
+        c(0.315_391_565_252_520_05) * (c(3.0) * z * z - T::ONE),
+        c(1.092_548_430_592_079_2) * x * z,
+        c(0.546_274_215_296_039_6) * (x * x - y * y),
+    ]
+}
+
+/// Project values sampled in directions onto spherical harmonics coefficients
+///
+/// The directions should be uniformly distributed over the sphere. The values can be any
+/// vectors, such as colors. Returns zero coefficients if there are no samples.
+pub fn sh_project<V>(samples: &[([V::Scalar; 3], V)]) -> [V; 9]
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let mut coefficients = [V::ZERO; 9];
+    if samples.is_empty() {
+        return coefficients;
+    }
+    for &(direction, value) in samples {
+        for (coefficient, basis) in coefficients.iter_mut().zip(sh_basis(direction)) {
+            *coefficient = coefficient.add(value.mul(basis));
+        }
+    }
+    // Each sample covers an equal share of the sphere's surface
+    let weight = V::Scalar::TAU * V::Scalar::TWO / V::Scalar::from_f64(samples.len() as f64);
+    coefficients.map(|c| c.mul(weight))
+}
+
+/// Reconstruct the value in a direction from spherical harmonics coefficients
+pub fn sh_evaluate<V>(coefficients: &[V; 9], direction: [V::Scalar; 3]) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    coefficients
+        .iter()
+        .zip(sh_basis(direction))
+        .fold(V::ZERO, |acc, (&c, basis)| acc.add(c.mul(basis)))
+}
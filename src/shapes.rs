@@ -0,0 +1,406 @@
+use crate::{
+    aabb::corners, Aabb, AabbTree, FloatingScalar, FloatingVecN, Ray, RayHit, Scalar, Vec3, VecN,
+};
+
+/// A shape that can be put in a [`ShapeSet`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape<T, const N: usize> {
+    /// A sphere
+    Sphere {
+        /// The center of the sphere
+        center: [T; N],
+        /// The radius of the sphere
+        radius: T,
+    },
+    /// An aabb given by its origin and size
+    Aabb([[T; N]; 2]),
+    /// A triangle
+    ///
+    /// In 2D, raycasts hit the triangle's edges. Raycasts never hit triangles in more
+    /// than 3 dimensions.
+    Triangle([[T; N]; 3]),
+    /// The set of points within a radius of a segment
+    Capsule {
+        /// The start of the segment
+        a: [T; N],
+        /// The end of the segment
+        b: [T; N],
+        /// The radius around the segment
+        radius: T,
+    },
+}
+
+impl<T, const N: usize> Shape<T, N>
+where
+    T: FloatingScalar,
+{
+    /// Intersect a ray with the shape
+    pub fn raycast(&self, ray: Ray<[T; N]>) -> Option<RayHit<[T; N]>> {
+        match *self {
+            Shape::Sphere { center, radius } => ray.intersect_sphere(center, radius),
+            Shape::Aabb(aabb) => ray.intersect_aabb(aabb),
+            Shape::Triangle(triangle) if N == 2 => raycast_triangle_2d(ray, triangle),
+            Shape::Triangle(triangle) => {
+                if N != 3 {
+                    return None;
+                }
+                let ray = Ray::new(to_3d(ray.origin), to_3d(ray.dir));
+                let hit = ray.intersect_triangle(triangle.map(to_3d))?;
+                Some(RayHit {
+                    t: hit.t,
+                    point: from_3d(hit.point),
+                    normal: from_3d(hit.normal),
+                })
+            }
+            Shape::Capsule { a, b, radius } => ray.intersect_capsule(a, b, radius),
+        }
+    }
+    /// Get the smallest aabb that contains the shape as its minimum and maximum corners
+    pub fn bounds(&self) -> [[T; N]; 2] {
+        let (min, max) = match *self {
+            Shape::Sphere { center, radius } => (center.sub([radius; N]), center.add([radius; N])),
+            Shape::Aabb(aabb) => corners(&aabb),
+            Shape::Triangle([a, b, c]) => (a.min2(b).min2(c), a.max2(b).max2(c)),
+            Shape::Capsule { a, b, radius } => (
+                a.min2(b).sub([radius.abs(); N]),
+                a.max2(b).add([radius.abs(); N]),
+            ),
+        };
+        [min, max]
+    }
+    /// Get the point in the shape closest to another point
+    ///
+    /// Points inside the shape are their own closest points.
+    pub fn closest_point(&self, point: [T; N]) -> [T; N] {
+        match *self {
+            Shape::Sphere { center, radius } => around(center, radius, point),
+            Shape::Aabb(aabb) => {
+                let (min, max) = corners(&aabb);
+                point.clamp2(min, max)
+            }
+            Shape::Triangle(triangle) => closest_on_triangle(triangle, point),
+            Shape::Capsule { a, b, radius } => {
+                around(closest_on_segment(a, b, point), radius, point)
+            }
+        }
+    }
+    /// Check if the shape contains a point
+    ///
+    /// Triangles have no interior, so they only contain points exactly on them.
+    pub fn contains(&self, point: [T; N]) -> bool {
+        self.closest_point(point).squared_dist(point) <= T::ZERO
+    }
+    /// Check if the shape overlaps a sphere
+    pub fn overlaps_sphere(&self, center: [T; N], radius: T) -> bool {
+        self.closest_point(center).squared_dist(center) <= radius * radius
+    }
+    /// Check if the shape overlaps an aabb
+    pub fn overlaps_aabb<A>(&self, aabb: A) -> bool
+    where
+        A: Aabb<Vector = [T; N]>,
+    {
+        let (min, max) = corners(&aabb);
+        match *self {
+            Shape::Sphere { center, radius } => {
+                center.clamp2(min, max).squared_dist(center) <= radius * radius
+            }
+            Shape::Aabb(other) => {
+                let (other_min, other_max) = corners(&other);
+                (0..N).all(|i| other_min[i] <= max[i] && min[i] <= other_max[i])
+            }
+            Shape::Triangle(triangle) => triangle_overlaps_box(triangle, min, max),
+            Shape::Capsule { a, b, radius } => {
+                // The squared distance from the box to a point on the segment is convex
+                let dist = |s: T| {
+                    let p = a.lerp(b, s);
+                    p.clamp2(min, max).squared_dist(p)
+                };
+                let ratio = T::from_f64(0.618_033_988_749_895);
+                let (mut lo, mut hi) = (T::ZERO, T::ONE);
+                for _ in 0..64 {
+                    let m1 = hi - (hi - lo) * ratio;
+                    let m2 = lo + (hi - lo) * ratio;
+                    if dist(m1) <= dist(m2) {
+                        hi = m2;
+                    } else {
+                        lo = m1;
+                    }
+                }
+                dist((lo + hi) / T::TWO)
+                    .minn(dist(T::ZERO))
+                    .minn(dist(T::ONE))
+                    <= radius * radius
+            }
+        }
+    }
+}
+
+/// Intersect a ray with the edges of a 2D triangle
+///
+/// The normal points away from the triangle.
+fn raycast_triangle_2d<T, const N: usize>(
+    ray: Ray<[T; N]>,
+    triangle: [[T; N]; 3],
+) -> Option<RayHit<[T; N]>>
+where
+    T: FloatingScalar,
+{
+    let perp = |v: [T; N]| {
+        let mut p = [T::ZERO; N];
+        p[0] = v[1];
+        p[1] = -v[0];
+        p
+    };
+    let centroid = triangle[0]
+        .add(triangle[1])
+        .add(triangle[2])
+        .div(T::from_f64(3.0));
+    (0..3)
+        .filter_map(|i| {
+            let (a, b) = (triangle[i], triangle[(i + 1) % 3]);
+            let edge = b.sub(a);
+            let denom = perp(edge).dot(ray.dir);
+            if denom.is_zero() {
+                return None;
+            }
+            let offset = a.sub(ray.origin);
+            let t = perp(edge).dot(offset) / denom;
+            let s = perp(ray.dir).dot(offset) / denom;
+            if t < T::ZERO || s < T::ZERO || s > T::ONE {
+                return None;
+            }
+            let mut normal = perp(edge).unit();
+            if normal.dot(a.sub(centroid)) < T::ZERO {
+                normal = normal.mul(-T::ONE);
+            }
+            Some(RayHit {
+                t,
+                point: ray.at(t),
+                normal,
+            })
+        })
+        .min_by(|a, b| a.t.partial_cmp(&b.t).expect("distance comparison failed"))
+}
+
+/// Convert a vector to 3D, dropping or zeroing dimensions
+fn to_3d<T, const N: usize>(v: [T; N]) -> [T; 3]
+where
+    T: FloatingScalar,
+{
+    std::array::from_fn(|i| if i < N { v[i] } else { T::ZERO })
+}
+
+/// Convert a 3D vector to N dimensions, dropping or zeroing dimensions
+fn from_3d<T, const N: usize>(v: [T; 3]) -> [T; N]
+where
+    T: FloatingScalar,
+{
+    std::array::from_fn(|i| if i < 3 { v[i] } else { T::ZERO })
+}
+
+/// Get the closest point to another in a ball
+fn around<T, const N: usize>(center: [T; N], radius: T, point: [T; N]) -> [T; N]
+where
+    T: FloatingScalar,
+{
+    let offset = point.sub(center);
+    if offset.squared_mag() <= radius * radius {
+        point
+    } else {
+        center.add(offset.with_mag(radius))
+    }
+}
+
+/// Get the closest point to another on a segment
+fn closest_on_segment<T, const N: usize>(a: [T; N], b: [T; N], point: [T; N]) -> [T; N]
+where
+    T: FloatingScalar,
+{
+    let axis = b.sub(a);
+    let len2 = axis.squared_mag();
+    if len2.is_zero() {
+        return a;
+    }
+    let t = (point.sub(a).dot(axis) / len2).maxx(T::ZERO).minn(T::ONE);
+    a.add(axis.mul(t))
+}
+
+/// Get the closest point to another on a triangle
+fn closest_on_triangle<T, const N: usize>([a, b, c]: [[T; N]; 3], p: [T; N]) -> [T; N]
+where
+    T: FloatingScalar,
+{
+    let (ab, ac, ap) = (b.sub(a), c.sub(a), p.sub(a));
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= T::ZERO && d2 <= T::ZERO {
+        return a;
+    }
+    let bp = p.sub(b);
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= T::ZERO && d4 <= d3 {
+        return b;
+    }
+    let cp = p.sub(c);
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= T::ZERO && d5 <= d6 {
+        return c;
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= T::ZERO && d1 >= T::ZERO && d3 <= T::ZERO {
+        return a.add(ab.mul(d1 / (d1 - d3)));
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= T::ZERO && d2 >= T::ZERO && d6 <= T::ZERO {
+        return a.add(ac.mul(d2 / (d2 - d6)));
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= T::ZERO && d4 - d3 >= T::ZERO && d5 - d6 >= T::ZERO {
+        return b.add(c.sub(b).mul((d4 - d3) / (d4 - d3 + d5 - d6)));
+    }
+    let sum = va + vb + vc;
+    if sum.is_zero() {
+        // Degenerate triangles are treated as their longest edge
+        return [(a, b), (b, c), (c, a)]
+            .map(|(s, e)| closest_on_segment(s, e, p))
+            .into_iter()
+            .min_by(|x, y| {
+                x.squared_dist(p)
+                    .partial_cmp(&y.squared_dist(p))
+                    .expect("distance comparison failed")
+            })
+            .expect("triangles have edges");
+    }
+    a.add(ab.mul(vb / sum)).add(ac.mul(vc / sum))
+}
+
+/// Check if a triangle overlaps a box given by its corners with the separating axis test
+fn triangle_overlaps_box<T, const N: usize>(triangle: [[T; N]; 3], min: [T; N], max: [T; N]) -> bool
+where
+    T: FloatingScalar,
+{
+    let center = min.midpoint(max);
+    let half = max.sub(min).div(T::TWO);
+    let edges = [
+        triangle[1].sub(triangle[0]),
+        triangle[2].sub(triangle[1]),
+        triangle[0].sub(triangle[2]),
+    ];
+    let box_axes: [[T; N]; N] =
+        std::array::from_fn(|i| std::array::from_fn(|j| if i == j { T::ONE } else { T::ZERO }));
+    let mut axes = box_axes.to_vec();
+    if N == 3 {
+        let cross = |a: [T; N], b: [T; N]| from_3d(to_3d(a).cross(to_3d(b)));
+        axes.push(cross(edges[0], edges[1]));
+        for edge in edges {
+            axes.extend(box_axes.map(|axis| cross(edge, axis)));
+        }
+    } else if N == 2 {
+        axes.extend(edges.map(|edge| {
+            let mut axis = [T::ZERO; N];
+            axis[0] = -edge[1];
+            axis[1] = edge[0];
+            axis
+        }));
+    }
+    axes.into_iter().all(|axis| {
+        let [p0, p1, p2] = triangle.map(|p| p.sub(center).dot(axis));
+        let radius = (0..N).fold(T::ZERO, |acc, i| acc + half[i] * axis[i].abs());
+        p0.minn(p1).minn(p2) <= radius && p0.maxx(p1).maxx(p2) >= -radius
+    })
+}
+
+/// A collection of shapes that can be queried together
+///
+/// Each shape is identified by the index it was added at. The shapes' bounds are kept in
+/// an [`AabbTree`], so queries only test the shapes whose bounds are nearby.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShapeSet<T, const N: usize> {
+    shapes: Vec<Shape<T, N>>,
+    tree: AabbTree<T, N>,
+    /// The shape id of each tree id
+    ids: Vec<usize>,
+}
+
+impl<T, const N: usize> Default for ShapeSet<T, N>
+where
+    T: Scalar,
+{
+    fn default() -> Self {
+        ShapeSet {
+            shapes: Vec::new(),
+            tree: AabbTree::new(T::ZERO),
+            ids: Vec::new(),
+        }
+    }
+}
+
+impl<T, const N: usize> ShapeSet<T, N>
+where
+    T: FloatingScalar,
+{
+    /// Create a new empty shape set
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Add a shape to the set and get its id
+    pub fn push(&mut self, shape: Shape<T, N>) -> usize {
+        let id = self.shapes.len();
+        let [min, max] = shape.bounds();
+        let tree_id = self.tree.insert([min, max.sub(min)]);
+        if self.ids.len() <= tree_id {
+            self.ids.resize(tree_id + 1, 0);
+        }
+        self.ids[tree_id] = id;
+        self.shapes.push(shape);
+        id
+    }
+    /// Get the shapes in the set in order of their ids
+    pub fn shapes(&self) -> &[Shape<T, N>] {
+        &self.shapes
+    }
+    /// Get the shape with an id
+    pub fn get(&self, id: usize) -> Option<&Shape<T, N>> {
+        self.shapes.get(id)
+    }
+    /// Get the ids of the shapes that contain a point
+    pub fn query_point(&self, point: [T; N]) -> Vec<usize> {
+        self.query(self.tree.query_point(point), |shape| shape.contains(point))
+    }
+    /// Get the ids of the shapes that overlap a sphere
+    pub fn query_sphere(&self, center: [T; N], radius: T) -> Vec<usize> {
+        let bounds = [center.sub([radius; N]), [radius + radius; N]];
+        let candidates = self.tree.query_aabb(bounds);
+        self.query(candidates, |shape| shape.overlaps_sphere(center, radius))
+    }
+    /// Get the ids of the shapes that overlap an aabb
+    pub fn query_aabb<A>(&self, aabb: A) -> Vec<usize>
+    where
+        A: Aabb<Vector = [T; N]>,
+    {
+        let (min, max) = corners(&aabb);
+        let bounds = [min, max.sub(min)];
+        let candidates = self.tree.query_aabb(bounds);
+        self.query(candidates, |shape| shape.overlaps_aabb(bounds))
+    }
+    /// Get the sorted ids of the candidate shapes that pass a test
+    fn query<F>(&self, candidates: Vec<usize>, f: F) -> Vec<usize>
+    where
+        F: Fn(&Shape<T, N>) -> bool,
+    {
+        let mut ids: Vec<usize> = (candidates.into_iter().map(|id| self.ids[id]))
+            .filter(|&id| f(&self.shapes[id]))
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+    /// Get the nearest intersection of a ray with a shape in the set, along with the shape's id
+    pub fn raycast(&self, ray: Ray<[T; N]>) -> Option<(usize, RayHit<[T; N]>)> {
+        let mut candidates: Vec<usize> = self.tree.query_ray(ray);
+        candidates.iter_mut().for_each(|id| *id = self.ids[*id]);
+        candidates.sort_unstable();
+        candidates
+            .into_iter()
+            .filter_map(|id| Some((id, self.shapes[id].raycast(ray)?)))
+            .min_by(|(_, a), (_, b)| a.t.partial_cmp(&b.t).expect("distance comparison failed"))
+    }
+}
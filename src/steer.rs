@@ -0,0 +1,317 @@
+//! Steering behaviors for moving agents
+//!
+//! Each function returns a steering force, which is the difference between the velocity
+//! the agent wants and the velocity it has. The force is usually clamped and scaled by
+//! the caller before being applied.
+
+use crate::{AabbTree, FloatingScalar, FloatingVecN, Scalar, VecN};
+
+/// Get the force that steers toward a target at full speed
+pub fn seek<V>(position: V, velocity: V, target: V, max_speed: V::Scalar) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    target.sub(position).unit().mul(max_speed).sub(velocity)
+}
+
+/// Get the force that steers away from a target at full speed
+pub fn flee<V>(position: V, velocity: V, target: V, max_speed: V::Scalar) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    position.sub(target).unit().mul(max_speed).sub(velocity)
+}
+
+/// Get the force that steers toward a target and slows to a stop on it
+///
+/// The agent slows down linearly once it is within `slowing_radius` of the target.
+pub fn arrive<V>(
+    position: V,
+    velocity: V,
+    target: V,
+    max_speed: V::Scalar,
+    slowing_radius: V::Scalar,
+) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let offset = target.sub(position);
+    let dist = offset.mag();
+    let speed = if dist < slowing_radius {
+        max_speed * dist / slowing_radius
+    } else {
+        max_speed
+    };
+    offset.unit().mul(speed).sub(velocity)
+}
+
+/// Predict where a moving target will be by the time the agent reaches it at full speed
+fn predict<V>(position: V, target: V, target_velocity: V, max_speed: V::Scalar) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    if max_speed > V::Scalar::ZERO {
+        target.add(target_velocity.mul(position.dist(target) / max_speed))
+    } else {
+        target
+    }
+}
+
+/// Get the force that steers toward where a moving target will be
+///
+/// The target's position is predicted from the time it would take to reach it at full speed.
+pub fn pursue<V>(position: V, velocity: V, target: V, target_velocity: V, max_speed: V::Scalar) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let predicted = predict(position, target, target_velocity, max_speed);
+    seek(position, velocity, predicted, max_speed)
+}
+
+/// Get the force that steers away from where a moving target will be
+///
+/// The target's position is predicted the same way as in [`pursue`].
+pub fn evade<V>(position: V, velocity: V, target: V, target_velocity: V, max_speed: V::Scalar) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let predicted = predict(position, target, target_velocity, max_speed);
+    flee(position, velocity, predicted, max_speed)
+}
+
+/// Get a force that steers toward a point on a circle ahead of the agent at full speed
+///
+/// The circle is `distance` ahead of the agent along its velocity, and the point is
+/// `radius` from its center in the direction of `offset`. Varying `offset` slightly
+/// and randomly each step gives a natural wandering motion.
+///
+/// An agent that is not moving heads in the direction of `offset`. Returns the zero
+/// vector if both the velocity and `offset` are zero.
+pub fn wander<V>(
+    velocity: V,
+    distance: V::Scalar,
+    radius: V::Scalar,
+    offset: V,
+    max_speed: V::Scalar,
+) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let jitter = offset.try_unit();
+    let Some(heading) = velocity.try_unit().or(jitter) else {
+        return V::ZERO;
+    };
+    let target = heading
+        .mul(distance)
+        .add(jitter.map_or(V::ZERO, |jitter| jitter.mul(radius)));
+    let desired = target.try_unit().unwrap_or(heading);
+    desired.mul(max_speed).sub(velocity)
+}
+
+/// Get the force that steers around the nearest spherical obstacle ahead of the agent
+///
+/// Obstacles are pairs of a center and a radius. Only obstacles within `look_ahead` along
+/// the agent's velocity are considered, and the agent's own radius should be included in
+/// the obstacles' radii. The force points away from the obstacle's center and has a
+/// magnitude of `max_force`. Returns the zero vector if nothing is in the way.
+pub fn avoid_obstacles<V>(
+    position: V,
+    velocity: V,
+    obstacles: &[(V, V::Scalar)],
+    look_ahead: V::Scalar,
+    max_force: V::Scalar,
+) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let dir = velocity.unit();
+    obstacles
+        .iter()
+        .filter_map(|&(center, radius)| {
+            let along = center.sub(position).dot(dir);
+            if along < V::Scalar::ZERO || along > look_ahead + radius {
+                return None;
+            }
+            let closest = position.add(dir.mul(along.minn(look_ahead)));
+            let away = closest.sub(center);
+            (away.squared_mag() <= radius * radius).then_some((along, away))
+        })
+        .min_by(|(a, _), (b, _)| a.partial_cmp(b).expect("distance comparison failed"))
+        .map_or(V::ZERO, |(_, away)| {
+            // Obstacles dead ahead are avoided in an arbitrary direction perpendicular to the path
+            let away = if away.squared_mag().is_zero() {
+                perpendicular(dir)
+            } else {
+                away
+            };
+            away.unit().mul(max_force)
+        })
+}
+
+/// Get some vector perpendicular to a nonzero vector
+pub(crate) fn perpendicular<V>(v: V) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    if V::N < 2 {
+        return V::ZERO;
+    }
+    // Swap the largest dimension with another and negate it
+    let i = (0..V::N)
+        .max_by(|&a, &b| {
+            v.dim(a)
+                .abs()
+                .partial_cmp(&v.dim(b).abs())
+                .expect("dimension comparison failed")
+        })
+        .expect("vectors have dimensions");
+    let j = (i + 1) % V::N;
+    let mut p = V::ZERO;
+    p.set_dim(i, -v.dim(j));
+    p.set_dim(j, v.dim(i));
+    p
+}
+
+/// Get the force that steers away from crowding neighbors
+///
+/// Each neighbor pushes away with a strength inversely proportional to its distance.
+/// Neighbors at the agent's exact position are ignored.
+pub fn separation<V>(position: V, neighbors: &[V]) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    neighbors.iter().fold(V::ZERO, |acc, &neighbor| {
+        let away = position.sub(neighbor);
+        let squared_dist = away.squared_mag();
+        if squared_dist.is_zero() {
+            acc
+        } else {
+            acc.add(away.div(squared_dist))
+        }
+    })
+}
+
+/// Get the force that steers toward the average velocity of neighbors
+///
+/// Returns the zero vector if there are no neighbors.
+pub fn alignment<V>(velocity: V, neighbor_velocities: &[V]) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    mean(neighbor_velocities).map_or(V::ZERO, |mean| mean.sub(velocity))
+}
+
+/// Get the force that steers toward the average position of neighbors
+///
+/// Returns the zero vector if there are no neighbors.
+pub fn cohesion<V>(position: V, neighbors: &[V]) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    mean(neighbors).map_or(V::ZERO, |mean| mean.sub(position))
+}
+
+/// A set of agent positions that can be searched for neighbors
+///
+/// Positions are stored in an [`AabbTree`], so finding the neighbors of every agent in a
+/// flock does not need to compare every pair. The results can be used to gather the
+/// positions and velocities passed to [`separation`], [`alignment`], and [`cohesion`].
+#[derive(Debug, Clone)]
+pub struct Neighborhood<T, const N: usize> {
+    tree: AabbTree<T, N>,
+    ids: Vec<usize>,
+    indices: Vec<usize>,
+    positions: Vec<[T; N]>,
+}
+
+impl<T, const N: usize> Neighborhood<T, N>
+where
+    T: FloatingScalar,
+{
+    /// Create a new neighborhood of agents at some positions
+    ///
+    /// Agents can move up to `margin` before they need to be moved in the tree.
+    pub fn new(positions: &[[T; N]], margin: T) -> Self {
+        // Tree ids are not agent indices, so map them back
+        let mut tree = AabbTree::new(margin);
+        let mut indices = vec![0; 2 * positions.len()];
+        let ids = (positions.iter().enumerate())
+            .map(|(i, &position)| {
+                let id = tree.insert([position, [T::ZERO; N]]);
+                indices[id] = i;
+                id
+            })
+            .collect();
+        Neighborhood {
+            tree,
+            ids,
+            indices,
+            positions: positions.to_vec(),
+        }
+    }
+    /// Get the number of agents
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+    /// Check if there are no agents
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+    /// Get the position of an agent
+    pub fn position(&self, agent: usize) -> [T; N] {
+        self.positions[agent]
+    }
+    /// Move an agent to a new position
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no agent with the index.
+    pub fn update(&mut self, agent: usize, position: [T; N]) {
+        self.positions[agent] = position;
+        self.tree.update(self.ids[agent], [position, [T::ZERO; N]]);
+    }
+    /// Get the indices of the agents within a radius of a position, sorted
+    ///
+    /// An agent at the position itself is included.
+    pub fn within(&self, position: [T; N], radius: T) -> Vec<usize> {
+        let ids = self
+            .tree
+            .query_aabb([position.sub([radius; N]), [radius + radius; N]]);
+        let mut agents: Vec<usize> = (ids.into_iter().map(|id| self.indices[id]))
+            .filter(|&i| self.positions[i].squared_dist(position) <= radius * radius)
+            .collect();
+        agents.sort_unstable();
+        agents
+    }
+    /// Get the indices of the other agents within a radius of an agent, sorted
+    pub fn neighbors(&self, agent: usize, radius: T) -> Vec<usize> {
+        let mut agents = self.within(self.positions[agent], radius);
+        agents.retain(|&i| i != agent);
+        agents
+    }
+}
+
+/// Get the mean of some vectors
+fn mean<V>(vectors: &[V]) -> Option<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    if vectors.is_empty() {
+        return None;
+    }
+    let sum = vectors.iter().fold(V::ZERO, |acc, &v| acc.add(v));
+    Some(sum.div(V::Scalar::from_f64(vectors.len() as f64)))
+}
@@ -0,0 +1,108 @@
+use crate::{Aabb, FloatingScalar, Grid, Scalar};
+
+/// A summed-area table, also known as an integral image
+///
+/// This allows the sum of the values in any axis-aligned box of a grid to be
+/// queried in constant time with respect to the size of the box.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SummedAreaTable<T, const N: usize> {
+    /// Sums of all values before each position, padded with a leading zero in each dimension
+    sums: Grid<T, N>,
+}
+
+impl<T, const N: usize> SummedAreaTable<T, N>
+where
+    T: Scalar,
+{
+    /// Build a summed-area table from a grid
+    pub fn new(grid: &Grid<T, N>) -> Self {
+        let size = grid.size();
+        let mut sums = Grid::from_fn(size.map(|s| s + 1), |pos| {
+            if pos.contains(&0) {
+                T::ZERO
+            } else {
+                grid[pos.map(|p| p - 1)]
+            }
+        });
+        let padded = sums.size();
+        let mut stride = 1;
+        for &count in &padded {
+            for i in 0..sums.values().len() {
+                if (i / stride) % count != 0 {
+                    let prev = sums.values()[i - stride];
+                    sums.values_mut()[i] += prev;
+                }
+            }
+            stride *= count;
+        }
+        SummedAreaTable { sums }
+    }
+    /// Get the size of the original grid
+    pub fn size(&self) -> [usize; N] {
+        self.sums.size().map(|s| s - 1)
+    }
+    /// Get the sum of the values in a box
+    ///
+    /// The box includes positions from its origin up to but not including its end.
+    /// It is clipped to the bounds of the grid.
+    pub fn sum<A>(&self, aabb: A) -> T
+    where
+        A: Aabb<Vector = [usize; N]>,
+    {
+        let size = self.size();
+        let mut start = [0; N];
+        let mut end = [0; N];
+        for i in 0..N {
+            start[i] = aabb.origin_dim(i).min(size[i]);
+            end[i] = aabb.end_dim(i).min(size[i]);
+            if end[i] <= start[i] {
+                return T::ZERO;
+            }
+        }
+        let mut positive = T::ZERO;
+        let mut negative = T::ZERO;
+        for mask in 0..1usize << N {
+            let mut corner = [0; N];
+            for i in 0..N {
+                corner[i] = if mask & (1 << i) != 0 {
+                    end[i]
+                } else {
+                    start[i]
+                };
+            }
+            if (N - mask.count_ones() as usize).is_multiple_of(2) {
+                positive += self.sums[corner];
+            } else {
+                negative += self.sums[corner];
+            }
+        }
+        positive - negative
+    }
+}
+
+impl<T, const N: usize> SummedAreaTable<T, N>
+where
+    T: FloatingScalar,
+{
+    /// Get the mean of the values in a box
+    ///
+    /// The box is clipped to the bounds of the grid.
+    /// Returns `None` if the clipped box contains no positions.
+    pub fn mean<A>(&self, aabb: A) -> Option<T>
+    where
+        A: Aabb<Vector = [usize; N]>,
+    {
+        let size = self.size();
+        let count: usize = (0..N)
+            .map(|i| {
+                let start = aabb.origin_dim(i).min(size[i]);
+                aabb.end_dim(i).min(size[i]).saturating_sub(start)
+            })
+            .product();
+        if count == 0 {
+            None
+        } else {
+            Some(self.sum(aabb) / T::from_f64(count as f64))
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{aabb::corners, Aabb, FloatingScalar, FloatingVecN, Ray, Scalar, VecN};
+
+/// A sphere moving with a constant velocity
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MovingSphere<V>
+where
+    V: VecN,
+{
+    /// The center of the sphere at time zero
+    pub center: V,
+    /// The radius of the sphere
+    pub radius: V::Scalar,
+    /// The distance the sphere moves per unit of time
+    pub velocity: V,
+}
+
+impl<V> MovingSphere<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    /// Create a new moving sphere
+    pub fn new(center: V, radius: V::Scalar, velocity: V) -> Self {
+        MovingSphere {
+            center,
+            radius,
+            velocity,
+        }
+    }
+    /// Get the center of the sphere at a time
+    pub fn at(&self, t: V::Scalar) -> V {
+        self.center.add(self.velocity.mul(t))
+    }
+    /// Get the first time at or before `max_t` when the sphere touches another moving sphere
+    ///
+    /// Returns `0` if the spheres already overlap, or `None` if they do not touch in time.
+    pub fn toi_sphere(&self, other: &Self, max_t: V::Scalar) -> Option<V::Scalar> {
+        let radius = self.radius + other.radius;
+        if self.center.squared_dist(other.center) <= radius * radius {
+            return Some(V::Scalar::ZERO);
+        }
+        let ray = Ray::new(self.center, self.velocity.sub(other.velocity));
+        let hit = ray.intersect_sphere(other.center, radius)?;
+        (hit.t <= max_t).then_some(hit.t)
+    }
+    /// Get the first time at or before `max_t` when the sphere touches a static plane
+    ///
+    /// The plane passes through `point` and is perpendicular to `normal`, which does not
+    /// need to be normalized. Returns `0` if the sphere already overlaps the plane,
+    /// or `None` if it does not touch it in time.
+    pub fn toi_plane(&self, point: V, normal: V, max_t: V::Scalar) -> Option<V::Scalar> {
+        let normal = normal.try_unit()?;
+        let dist = self.center.sub(point).dot(normal);
+        if dist.abs() <= self.radius {
+            return Some(V::Scalar::ZERO);
+        }
+        let speed = self.velocity.dot(normal);
+        // The sphere must be moving toward the plane
+        if speed.is_zero() || (dist > V::Scalar::ZERO) == (speed > V::Scalar::ZERO) {
+            return None;
+        }
+        let t = (dist.abs() - self.radius) / speed.abs();
+        (t <= max_t).then_some(t)
+    }
+    /// Get the first time at or before `max_t` when the sphere touches a static aabb
+    ///
+    /// Returns `0` if the sphere already overlaps the aabb, or `None` if it does not touch it in time.
+    pub fn toi_aabb<A>(&self, aabb: A, max_t: V::Scalar) -> Option<V::Scalar>
+    where
+        A: Aabb<Vector = V>,
+    {
+        let (min, max) = corners(&aabb);
+        // The distance from the moving center to the aabb is convex in time
+        let dist = |t: V::Scalar| {
+            let center = self.at(t);
+            center.clamp2(min, max).dist(center)
+        };
+        if dist(V::Scalar::ZERO) <= self.radius {
+            return Some(V::Scalar::ZERO);
+        }
+        // Find the time of closest approach
+        let ratio = V::Scalar::from_f64(0.618_033_988_749_895);
+        let (mut lo, mut hi) = (V::Scalar::ZERO, max_t);
+        for _ in 0..128 {
+            let m1 = hi - (hi - lo) * ratio;
+            let m2 = lo + (hi - lo) * ratio;
+            if m1 <= lo || m2 >= hi {
+                break;
+            }
+            if dist(m1) <= dist(m2) {
+                hi = m2;
+            } else {
+                lo = m1;
+            }
+        }
+        let closest = if dist(max_t) <= dist(lo) { max_t } else { lo };
+        if dist(closest) > self.radius {
+            return None;
+        }
+        // The distance decreases until the closest approach, so bisect for the first touch
+        let (mut lo, mut hi) = (V::Scalar::ZERO, closest);
+        for _ in 0..128 {
+            let mid = (lo + hi) / V::Scalar::TWO;
+            if mid <= lo || mid >= hi {
+                break;
+            }
+            if dist(mid) > self.radius {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Some(hi)
+    }
+}
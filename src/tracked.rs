@@ -0,0 +1,126 @@
+use crate::{FloatingScalar, FloatingVecN, Scalar, VecN};
+
+/// A value that estimates its velocity and acceleration from timestamped samples
+///
+/// Each sample's velocity and acceleration are estimated with finite differences. With
+/// smoothing, the estimates move toward each new difference with
+/// [`exp_decay`](FloatingVecN::exp_decay) instead, which steadies noisy input like cursor
+/// positions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tracked<V>
+where
+    V: VecN,
+{
+    last: Option<(V::Scalar, V)>,
+    velocity: V,
+    acceleration: V,
+    velocities: usize,
+    smoothing: Option<V::Scalar>,
+}
+
+impl<V> Default for Tracked<V>
+where
+    V: VecN,
+{
+    fn default() -> Self {
+        Tracked {
+            last: None,
+            velocity: V::ZERO,
+            acceleration: V::ZERO,
+            velocities: 0,
+            smoothing: None,
+        }
+    }
+}
+
+impl<V> Tracked<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    /// Create a new tracker with no samples and no smoothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Create a new tracker that smooths its estimates with a decay rate
+    ///
+    /// Higher rates follow changes faster. See [`FloatingScalar::exp_decay`].
+    pub fn smoothed(lambda: V::Scalar) -> Self {
+        Tracked {
+            smoothing: Some(lambda),
+            ..Self::default()
+        }
+    }
+    /// Get the most recent value, if there is one
+    pub fn value(&self) -> Option<V> {
+        self.last.map(|(_, value)| value)
+    }
+    /// Get the time of the most recent value, if there is one
+    pub fn time(&self) -> Option<V::Scalar> {
+        self.last.map(|(time, _)| time)
+    }
+    /// Get the estimated velocity
+    ///
+    /// This is zero until there are two samples.
+    pub fn velocity(&self) -> V {
+        self.velocity
+    }
+    /// Get the estimated acceleration
+    ///
+    /// This is zero until there are three samples.
+    pub fn acceleration(&self) -> V {
+        self.acceleration
+    }
+    /// Predict the value at a time from the most recent value, velocity, and acceleration
+    ///
+    /// Returns `None` if there are no samples.
+    pub fn predict(&self, time: V::Scalar) -> Option<V> {
+        let (last_time, value) = self.last?;
+        let dt = time - last_time;
+        let half_dt2 = dt * dt / V::Scalar::TWO;
+        Some(
+            value
+                .add(self.velocity.mul(dt))
+                .add(self.acceleration.mul(half_dt2)),
+        )
+    }
+    /// Add a sample of the value at a time
+    ///
+    /// Samples that are not later than the most recent one replace its value without
+    /// changing the estimates.
+    pub fn update(&mut self, value: V, time: V::Scalar) {
+        let Some((last_time, last_value)) = self.last else {
+            self.last = Some((time, value));
+            return;
+        };
+        let dt = time - last_time;
+        if dt <= V::Scalar::ZERO {
+            self.last = Some((last_time, value));
+            return;
+        }
+        self.last = Some((time, value));
+        let velocity = value.sub(last_value).div(dt);
+        let old_velocity = self.velocity;
+        self.velocity = self.smooth(self.velocity, velocity, dt, self.velocities == 0);
+        self.velocities += 1;
+        if self.velocities > 1 {
+            let acceleration = self.velocity.sub(old_velocity).div(dt);
+            let first = self.velocities == 2;
+            self.acceleration = self.smooth(self.acceleration, acceleration, dt, first);
+        }
+    }
+    /// Forget all samples and estimates, keeping the smoothing
+    pub fn reset(&mut self) {
+        *self = Tracked {
+            smoothing: self.smoothing,
+            ..Self::default()
+        };
+    }
+    /// Move an estimate toward a sample, or replace it if it is the first estimate
+    fn smooth(&self, estimate: V, sample: V, dt: V::Scalar, first: bool) -> V {
+        match self.smoothing {
+            Some(lambda) if !first => estimate.exp_decay(sample, lambda, dt),
+            _ => sample,
+        }
+    }
+}
@@ -0,0 +1,418 @@
+use crate::{aabb::corners, Aabb, FloatingScalar, Ray, Scalar, VecN};
+
+/// A node of an [`AabbTree`]
+#[derive(Debug, Clone, PartialEq)]
+struct Node<T, const N: usize> {
+    min: [T; N],
+    max: [T; N],
+    parent: Option<usize>,
+    children: Option<[usize; 2]>,
+    height: usize,
+}
+
+/// A dynamic tree of aabbs for broad-phase collision detection
+///
+/// Each aabb is stored enlarged by a margin, so objects that move a little do not need
+/// to be moved in the tree. Leaves are inserted next to the sibling that grows the tree
+/// the least, and the tree is kept balanced with rotations, so insertion, removal, and
+/// updates take time proportional to the tree's height.
+///
+/// Each aabb is identified by the id returned when it was inserted. Ids of removed aabbs
+/// may be reused.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AabbTree<T, const N: usize> {
+    nodes: Vec<Option<Node<T, N>>>,
+    free: Vec<usize>,
+    root: Option<usize>,
+    leaves: usize,
+    margin: T,
+}
+
+impl<T, const N: usize> AabbTree<T, N>
+where
+    T: Scalar,
+{
+    /// Create a new empty tree that enlarges each aabb by a margin on every side
+    ///
+    /// Negative margins are treated as zero. Enlarged aabbs stop at the scalar's limits.
+    pub fn new(margin: T) -> Self {
+        AabbTree {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            leaves: 0,
+            margin: margin.maxx(T::ZERO),
+        }
+    }
+    /// Get the number of aabbs in the tree
+    pub fn len(&self) -> usize {
+        self.leaves
+    }
+    /// Check if the tree is empty
+    pub fn is_empty(&self) -> bool {
+        self.leaves == 0
+    }
+    /// Get the height of the tree
+    ///
+    /// An empty tree and a tree with one aabb both have a height of `0`.
+    pub fn height(&self) -> usize {
+        self.root.map_or(0, |root| self.node(root).height)
+    }
+    /// Get the enlarged aabb with an id as an origin and a size
+    pub fn fat_aabb(&self, id: usize) -> Option<[[T; N]; 2]> {
+        let node = self.leaf(id)?;
+        Some([node.min, node.max.sub(node.min)])
+    }
+    /// Add an aabb to the tree and get its id
+    pub fn insert<A>(&mut self, aabb: A) -> usize
+    where
+        A: Aabb<Vector = [T; N]>,
+    {
+        let (min, max) = self.fatten(aabb);
+        let id = self.alloc(Node {
+            min,
+            max,
+            parent: None,
+            children: None,
+            height: 0,
+        });
+        self.insert_leaf(id);
+        self.leaves += 1;
+        id
+    }
+    /// Remove the aabb with an id from the tree
+    ///
+    /// Returns `false` if there is no aabb with the id.
+    pub fn remove(&mut self, id: usize) -> bool {
+        if self.leaf(id).is_none() {
+            return false;
+        }
+        self.remove_leaf(id);
+        self.nodes[id] = None;
+        self.free.push(id);
+        self.leaves -= 1;
+        true
+    }
+    /// Update the aabb with an id after its object moves
+    ///
+    /// The aabb is only moved in the tree if it is no longer inside its enlarged aabb.
+    /// Returns whether it was moved, which is also `false` if there is no aabb with the id.
+    pub fn update<A>(&mut self, id: usize, aabb: A) -> bool
+    where
+        A: Aabb<Vector = [T; N]>,
+    {
+        let Some(node) = self.leaf(id) else {
+            return false;
+        };
+        let (min, max) = corners(&aabb);
+        if (0..N).all(|i| node.min[i] <= min[i] && max[i] <= node.max[i]) {
+            return false;
+        }
+        self.remove_leaf(id);
+        let (min, max) = self.fatten(aabb);
+        let node = self.node_mut(id);
+        node.min = min;
+        node.max = max;
+        self.insert_leaf(id);
+        true
+    }
+    /// Get the ids of the aabbs whose enlarged aabbs overlap an aabb
+    pub fn query_aabb<A>(&self, aabb: A) -> Vec<usize>
+    where
+        A: Aabb<Vector = [T; N]>,
+    {
+        let (min, max) = corners(&aabb);
+        let mut ids = Vec::new();
+        self.visit(min, max, |id| ids.push(id));
+        ids
+    }
+    /// Get the ids of the aabbs whose enlarged aabbs contain a point
+    pub fn query_point(&self, point: [T; N]) -> Vec<usize> {
+        self.query_aabb([point, [T::ZERO; N]])
+    }
+    /// Get every pair of ids of aabbs whose enlarged aabbs overlap
+    ///
+    /// Each pair is listed once with the smaller id first, and the pairs are sorted.
+    pub fn pairs(&self) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for (id, node) in self.nodes.iter().enumerate() {
+            let Some(node) = node.as_ref().filter(|node| node.children.is_none()) else {
+                continue;
+            };
+            self.visit(node.min, node.max, |other| {
+                if id < other {
+                    pairs.push((id, other));
+                }
+            });
+        }
+        pairs.sort_unstable();
+        pairs
+    }
+    /// Get the id of the aabb whose enlarged aabb is closest to a point
+    ///
+    /// Distances are squared euclidean distances to the enlarged aabbs, so points stored
+    /// as zero-size aabbs in a tree with no margin give exact nearest neighbors.
+    /// Ties are broken arbitrarily. Returns `None` if the tree is empty.
+    pub fn nearest(&self, point: [T; N]) -> Option<usize> {
+        let dist = |index: usize| {
+            let node = self.node(index);
+            point
+                .clamp2(node.min, node.max)
+                .abs_diff(point)
+                .squared_mag()
+        };
+        let mut best: Option<(usize, T)> = None;
+        let mut stack: Vec<(usize, T)> = self
+            .root
+            .map(|root| (root, dist(root)))
+            .into_iter()
+            .collect();
+        while let Some((index, d)) = stack.pop() {
+            if best.is_some_and(|(_, best)| d >= best) {
+                continue;
+            }
+            match self.node(index).children {
+                Some([a, b]) => {
+                    let (da, db) = (dist(a), dist(b));
+                    // Visit the closer child first
+                    if da < db {
+                        stack.extend([(b, db), (a, da)]);
+                    } else {
+                        stack.extend([(a, da), (b, db)]);
+                    }
+                }
+                None => best = Some((index, d)),
+            }
+        }
+        best.map(|(id, _)| id)
+    }
+    fn fatten<A>(&self, aabb: A) -> ([T; N], [T; N])
+    where
+        A: Aabb<Vector = [T; N]>,
+    {
+        let (min, max) = corners(&aabb);
+        let margin = self.margin;
+        // Saturate so unsigned and integer trees do not overflow near their limits
+        let min = min.map(|x| {
+            if x < T::MIN + margin {
+                T::MIN
+            } else {
+                x - margin
+            }
+        });
+        let max = max.map(|x| {
+            if x > T::MAX - margin {
+                T::MAX
+            } else {
+                x + margin
+            }
+        });
+        (min, max)
+    }
+    fn node(&self, index: usize) -> &Node<T, N> {
+        self.nodes[index].as_ref().expect("tree node is missing")
+    }
+    fn node_mut(&mut self, index: usize) -> &mut Node<T, N> {
+        self.nodes[index].as_mut().expect("tree node is missing")
+    }
+    fn leaf(&self, id: usize) -> Option<&Node<T, N>> {
+        (self.nodes.get(id)?.as_ref()).filter(|node| node.children.is_none())
+    }
+    fn alloc(&mut self, node: Node<T, N>) -> usize {
+        if let Some(index) = self.free.pop() {
+            self.nodes[index] = Some(node);
+            index
+        } else {
+            self.nodes.push(Some(node));
+            self.nodes.len() - 1
+        }
+    }
+    /// Call a function with the id of each leaf that overlaps a box
+    fn visit<F>(&self, min: [T; N], max: [T; N], f: F)
+    where
+        F: FnMut(usize),
+    {
+        self.visit_where(
+            |node_min, node_max| overlaps(node_min, node_max, min, max),
+            f,
+        );
+    }
+    /// Call a function with the id of each leaf whose box and ancestors' boxes pass a test
+    fn visit_where<P, F>(&self, test: P, mut f: F)
+    where
+        P: Fn([T; N], [T; N]) -> bool,
+        F: FnMut(usize),
+    {
+        let mut stack: Vec<usize> = self.root.into_iter().collect();
+        while let Some(index) = stack.pop() {
+            let node = self.node(index);
+            if !test(node.min, node.max) {
+                continue;
+            }
+            match node.children {
+                Some(children) => stack.extend(children),
+                None => f(index),
+            }
+        }
+    }
+    fn insert_leaf(&mut self, leaf: usize) {
+        let Some(root) = self.root else {
+            self.root = Some(leaf);
+            self.node_mut(leaf).parent = None;
+            return;
+        };
+        let (leaf_min, leaf_max) = (self.node(leaf).min, self.node(leaf).max);
+        // Descend to the sibling that grows the tree's total surface the least
+        let mut index = root;
+        while let Some([a, b]) = self.node(index).children {
+            let node = self.node(index);
+            let area = surface(node.min, node.max);
+            let combined = surface(node.min.min2(leaf_min), node.max.max2(leaf_max));
+            let cost = combined + combined;
+            let inheritance = (combined - area) + (combined - area);
+            let child_cost = |child: usize| {
+                let child = self.node(child);
+                let grown = surface(child.min.min2(leaf_min), child.max.max2(leaf_max));
+                if child.children.is_none() {
+                    grown + inheritance
+                } else {
+                    grown - surface(child.min, child.max) + inheritance
+                }
+            };
+            let (cost_a, cost_b) = (child_cost(a), child_cost(b));
+            if cost < cost_a && cost < cost_b {
+                break;
+            }
+            index = if cost_a < cost_b { a } else { b };
+        }
+        // Join the leaf and its sibling under a new parent
+        let sibling = index;
+        let old_parent = self.node(sibling).parent;
+        let sibling_node = self.node(sibling);
+        let parent = self.alloc(Node {
+            min: sibling_node.min.min2(leaf_min),
+            max: sibling_node.max.max2(leaf_max),
+            parent: old_parent,
+            children: Some([sibling, leaf]),
+            height: sibling_node.height + 1,
+        });
+        self.replace_child(old_parent, sibling, parent);
+        self.node_mut(sibling).parent = Some(parent);
+        self.node_mut(leaf).parent = Some(parent);
+        self.refit_from(old_parent);
+    }
+    fn remove_leaf(&mut self, leaf: usize) {
+        let Some(parent) = self.node(leaf).parent else {
+            self.root = None;
+            return;
+        };
+        let [a, b] = self.node(parent).children.expect("parents have children");
+        let sibling = if a == leaf { b } else { a };
+        let grandparent = self.node(parent).parent;
+        self.replace_child(grandparent, parent, sibling);
+        self.node_mut(sibling).parent = grandparent;
+        self.nodes[parent] = None;
+        self.free.push(parent);
+        self.node_mut(leaf).parent = None;
+        self.refit_from(grandparent);
+    }
+    /// Replace a child of a node, or the root if there is no node
+    fn replace_child(&mut self, parent: Option<usize>, old: usize, new: usize) {
+        match parent {
+            Some(parent) => {
+                let children = self.node_mut(parent).children.as_mut();
+                for child in children.expect("parents have children") {
+                    if *child == old {
+                        *child = new;
+                    }
+                }
+            }
+            None => self.root = Some(new),
+        }
+    }
+    /// Rebalance and recompute the bounds of the ancestors of a node, starting with itself
+    fn refit_from(&mut self, mut index: Option<usize>) {
+        while let Some(i) = index {
+            let i = self.balance(i);
+            self.refit(i);
+            index = self.node(i).parent;
+        }
+    }
+    /// Recompute the bounds and height of an internal node from its children
+    fn refit(&mut self, index: usize) {
+        let [a, b] = self.node(index).children.expect("parents have children");
+        let (a, b) = (self.node(a), self.node(b));
+        let (min, max) = (a.min.min2(b.min), a.max.max2(b.max));
+        let height = 1 + a.height.max(b.height);
+        let node = self.node_mut(index);
+        node.min = min;
+        node.max = max;
+        node.height = height;
+    }
+    /// Rotate a child up if the node's subtrees differ in height by more than one
+    ///
+    /// Returns the node that takes the original node's place.
+    fn balance(&mut self, a: usize) -> usize {
+        let Some([b, c]) = self.node(a).children else {
+            return a;
+        };
+        if self.node(a).height < 2 {
+            return a;
+        }
+        let (height_b, height_c) = (self.node(b).height, self.node(c).height);
+        let (up, stay, up_slot) = if height_c > height_b + 1 {
+            (c, b, 1)
+        } else if height_b > height_c + 1 {
+            (b, c, 0)
+        } else {
+            return a;
+        };
+        // The taller grandchild stays under the rising child, and the shorter moves to `a`
+        let [f, g] = self.node(up).children.expect("tall nodes have children");
+        let (tall, short) = if self.node(f).height > self.node(g).height {
+            (f, g)
+        } else {
+            (g, f)
+        };
+        let parent = self.node(a).parent;
+        self.replace_child(parent, a, up);
+        self.node_mut(up).parent = parent;
+        self.node_mut(up).children = Some([a, tall]);
+        self.node_mut(a).parent = Some(up);
+        let mut children = [stay, stay];
+        children[up_slot] = short;
+        self.node_mut(a).children = Some(children);
+        self.node_mut(short).parent = Some(a);
+        self.refit(a);
+        self.refit(up);
+        up
+    }
+}
+
+impl<T, const N: usize> AabbTree<T, N>
+where
+    T: FloatingScalar,
+{
+    /// Get the ids of the aabbs whose enlarged aabbs a ray passes through
+    pub fn query_ray(&self, ray: Ray<[T; N]>) -> Vec<usize> {
+        let mut ids = Vec::new();
+        self.visit_where(|min, max| ray.slab(min, max).is_some(), |id| ids.push(id));
+        ids
+    }
+}
+
+/// Check if two boxes given by their corners overlap
+fn overlaps<T, const N: usize>(a_min: [T; N], a_max: [T; N], b_min: [T; N], b_max: [T; N]) -> bool
+where
+    T: Scalar,
+{
+    (0..N).all(|i| a_min[i] <= b_max[i] && b_min[i] <= a_max[i])
+}
+
+/// Get the sum of the side lengths of a box, which generalizes its perimeter
+fn surface<T, const N: usize>(min: [T; N], max: [T; N]) -> T
+where
+    T: Scalar,
+{
+    max.sub(min).sum_dims()
+}
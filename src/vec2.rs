@@ -0,0 +1,97 @@
+use crate::{FloatingScalar, FloatingVecN, VecN, XVec, YVec};
+
+/// Trait for real-valued 2D vector operations
+///
+/// These methods only consider the X and Y dimensions.
+pub trait FloatingVec2: FloatingVecN + Copy
+where
+    Self::Scalar: FloatingScalar,
+{
+    /// Get the index of the nearest of `n` evenly spaced headings
+    ///
+    /// Heading `0` points along the positive X axis, and the rest follow counter-clockwise.
+    /// Returns `None` for the zero vector or if `n` is `0`.
+    fn direction_index(self, n: usize) -> Option<usize> {
+        if n == 0 || (self.x().is_zero() && self.y().is_zero()) {
+            return None;
+        }
+        let angle = self.y().atan2(self.x()).to_f64();
+        let step = std::f64::consts::TAU / n as f64;
+        Some(((angle / step).round() as isize).rem_euclid(n as isize) as usize)
+    }
+    /// Snap the vector to the nearest of `n` evenly spaced unit headings
+    ///
+    /// Heading `0` points along the positive X axis, and the rest follow counter-clockwise.
+    /// Returns the zero vector for the zero vector or if `n` is `0`.
+    fn quantize_direction(self, n: usize) -> Self {
+        let Some(i) = self.direction_index(n) else {
+            return Self::ZERO;
+        };
+        let angle = Self::Scalar::TAU * Self::Scalar::from_f64(i as f64 / n as f64);
+        let mut v = Self::ZERO;
+        v.set_x(angle.cos());
+        v.set_y(angle.sin());
+        v
+    }
+    /// Snap the vector to the nearest of the 4 axis directions
+    ///
+    /// Returns the zero vector for the zero vector.
+    fn to_cardinal(self) -> Self {
+        const CARDINALS: [[f64; 2]; 4] = [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0], [0.0, -1.0]];
+        self.direction_index(4)
+            .map_or(Self::ZERO, |i| heading(CARDINALS[i]))
+    }
+    /// Snap the vector to the nearest of the 4 axis and 4 diagonal directions
+    ///
+    /// Each dimension of the result is `-1`, `0`, or `1`, so diagonals are not normalized.
+    /// Returns the zero vector for the zero vector.
+    fn to_octant(self) -> Self {
+        const OCTANTS: [[f64; 2]; 8] = [
+            [1.0, 0.0],
+            [1.0, 1.0],
+            [0.0, 1.0],
+            [-1.0, 1.0],
+            [-1.0, 0.0],
+            [-1.0, -1.0],
+            [0.0, -1.0],
+            [1.0, -1.0],
+        ];
+        self.direction_index(8)
+            .map_or(Self::ZERO, |i| heading(OCTANTS[i]))
+    }
+    /// Rotate the vector counter-clockwise around the origin by an angle in radians
+    fn rotated(self, angle: Self::Scalar) -> Self {
+        let [cos, sin] = angle.angle_as_vector();
+        let mut v = self;
+        v.set_x(self.x() * cos - self.y() * sin);
+        v.set_y(self.x() * sin + self.y() * cos);
+        v
+    }
+    /// Get the signed angle in radians to rotate the vector to the direction of another
+    ///
+    /// The angle is in the range `[-π, π]` and is positive for counter-clockwise rotation.
+    /// Returns `0` if either vector is zero.
+    fn signed_angle(self, other: Self) -> Self::Scalar {
+        let perp_dot = self.x() * other.y() - self.y() * other.x();
+        let dot = self.x() * other.x() + self.y() * other.y();
+        perp_dot.atan2(dot)
+    }
+}
+
+impl<V> FloatingVec2 for V
+where
+    V: FloatingVecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+}
+
+fn heading<V>([x, y]: [f64; 2]) -> V
+where
+    V: VecN,
+    V::Scalar: FloatingScalar,
+{
+    let mut v = V::ZERO;
+    v.set_x(V::Scalar::from_f64(x));
+    v.set_y(V::Scalar::from_f64(y));
+    v
+}
@@ -0,0 +1,100 @@
+use crate::{FloatingScalar, FloatingVecN, Scalar, VecN};
+
+/// A constraint that keeps two points at a fixed distance from each other
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DistanceConstraint<T> {
+    /// The index of the first point
+    pub a: usize,
+    /// The index of the second point
+    pub b: usize,
+    /// The distance to keep between the points
+    pub rest_length: T,
+}
+
+impl<T> DistanceConstraint<T> {
+    /// Create a new distance constraint
+    pub fn new(a: usize, b: usize, rest_length: T) -> Self {
+        DistanceConstraint { a, b, rest_length }
+    }
+}
+
+/// Check if the point at an index is pinned
+fn is_pinned(pinned: &[bool], i: usize) -> bool {
+    pinned.get(i).copied().unwrap_or(false)
+}
+
+/// Move points with Verlet integration
+///
+/// `previous` holds each point's position from the last step and is updated to the current
+/// positions. Points whose entry in `pinned` is `true` do not move. Points past the end of
+/// `pinned` are not pinned.
+///
+/// # Panics
+///
+/// Panics if `points` and `previous` have different lengths.
+pub fn verlet_integrate<V>(
+    points: &mut [V],
+    previous: &mut [V],
+    pinned: &[bool],
+    acceleration: V,
+    dt: V::Scalar,
+) where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    assert_eq!(
+        points.len(),
+        previous.len(),
+        "points and previous positions have different lengths"
+    );
+    let step = acceleration.mul(dt * dt);
+    for (i, (point, prev)) in points.iter_mut().zip(previous).enumerate() {
+        let current = *point;
+        if !is_pinned(pinned, i) {
+            *point = current.add(current.sub(*prev)).add(step);
+        }
+        *prev = current;
+    }
+}
+
+/// Move points to satisfy distance constraints by repeated relaxation
+///
+/// Each iteration moves the points of every constraint toward or away from each other
+/// until they are at the rest length. Points whose entry in `pinned` is `true` do not move,
+/// and points past the end of `pinned` are not pinned. More iterations make the constraints
+/// stiffer.
+///
+/// # Panics
+///
+/// Panics if a constraint refers to a point that is out of bounds.
+pub fn relax_constraints<V>(
+    points: &mut [V],
+    constraints: &[DistanceConstraint<V::Scalar>],
+    pinned: &[bool],
+    iterations: usize,
+) where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    for _ in 0..iterations {
+        for c in constraints {
+            let (wa, wb) = match (is_pinned(pinned, c.a), is_pinned(pinned, c.b)) {
+                (true, true) => continue,
+                (true, false) => (V::Scalar::ZERO, V::Scalar::ONE),
+                (false, true) => (V::Scalar::ONE, V::Scalar::ZERO),
+                (false, false) => {
+                    let half = V::Scalar::ONE / V::Scalar::TWO;
+                    (half, half)
+                }
+            };
+            let delta = points[c.b].sub(points[c.a]);
+            let dist = delta.mag();
+            if dist.is_zero() {
+                continue;
+            }
+            let correction = delta.mul((dist - c.rest_length) / dist);
+            points[c.a].add_assign(correction.mul(wa));
+            points[c.b].sub_assign(correction.mul(wb));
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::{
+    polygon::{cross2, vec2},
+    Aabb, FloatingScalar, Polygon, Scalar, VecN, XVec, YVec,
+};
+
+/// Get the region visible from a point when looking past occluding segments
+///
+/// Only the X and Y dimensions of the vectors are considered. The edges of `bounds`
+/// also occlude, so the result is always closed. The origin should be inside `bounds`.
+///
+/// The visible region is returned as a polygon with its vertices in counter-clockwise order.
+pub fn visibility_polygon<V, A>(origin: V, segments: &[[V; 2]], bounds: A) -> Vec<V>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+    A: Aabb<Vector = V>,
+{
+    let corners = corners2(&bounds);
+    let occluders: Vec<[V; 2]> = segments
+        .iter()
+        .copied()
+        .chain((0..4).map(|i| [corners[i], corners[(i + 1) % 4]]))
+        .collect();
+    // Cast rays at each endpoint and slightly to either side to see past corners
+    let nudge = V::Scalar::EPSILON.sqrt().to_f64();
+    let tolerance = V::Scalar::EPSILON * V::Scalar::from_f64(16.0);
+    let mut angles: Vec<f64> = occluders
+        .iter()
+        .flatten()
+        .flat_map(|p| {
+            let angle = (p.y() - origin.y()).atan2(p.x() - origin.x()).to_f64();
+            [angle - nudge, angle, angle + nudge]
+        })
+        .collect();
+    angles.sort_by(|a, b| a.partial_cmp(b).expect("angle comparison failed"));
+    angles.dedup();
+    let mut polygon: Vec<V> = Vec::with_capacity(angles.len());
+    for angle in angles {
+        let dir = vec2::<V>(
+            V::Scalar::from_f64(angle.cos()),
+            V::Scalar::from_f64(angle.sin()),
+        );
+        let nearest = occluders
+            .iter()
+            .filter_map(|&segment| ray_segment(origin, dir, segment, tolerance))
+            .min_by(|a, b| a.partial_cmp(b).expect("hit distance comparison failed"));
+        if let Some(t) = nearest {
+            let point = origin.add(dir.mul(t));
+            // Rays that hit the same point give near-identical vertices
+            let repeated =
+                |a: V, b: V| a.squared_dist(b) <= (tolerance * tolerance) * a.squared_dist(origin);
+            if polygon.last().is_none_or(|&last| !repeated(point, last)) {
+                polygon.push(point);
+            }
+        }
+    }
+    if polygon.len() > 1 {
+        let (first, last) = (polygon[0], polygon[polygon.len() - 1]);
+        if first.squared_dist(last) <= (tolerance * tolerance) * first.squared_dist(origin) {
+            polygon.pop();
+        }
+    }
+    polygon
+}
+
+/// Get the shadow cast by a convex polygon from a point light
+///
+/// Only the X and Y dimensions of the vectors are considered. The shadow starts at the
+/// edges of the polygon that face away from the light and extends to the edges of `bounds`.
+/// The light should be inside `bounds`.
+///
+/// The shadow is returned as a polygon with its vertices in counter-clockwise order.
+/// Returns `None` if the light is inside the polygon or the polygon has no area.
+pub fn shadow_polygon<V, A>(light: V, occluder: &[V], bounds: A) -> Option<Vec<V>>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+    A: Aabb<Vector = V>,
+{
+    let n = occluder.len();
+    let area = occluder.signed_area();
+    if n < 3 || area.is_zero() {
+        return None;
+    }
+    // An edge faces away from the light if the light is on the polygon's inner side of it
+    let facing_away: Vec<bool> = (0..n)
+        .map(|i| {
+            let side = cross2(occluder[i], occluder[(i + 1) % n], light);
+            (side > V::Scalar::ZERO) == (area > V::Scalar::ZERO)
+        })
+        .collect();
+    let start = (0..n).find(|&i| facing_away[i] && !facing_away[(i + n - 1) % n])?;
+    let mut shadow: Vec<V> = (0..n)
+        .map(|k| (start + k) % n)
+        .enumerate()
+        .take_while(|&(k, i)| k == 0 || facing_away[(i + n - 1) % n])
+        .map(|(_, i)| occluder[i])
+        .collect();
+    let (first, last) = (shadow[0], shadow[shadow.len() - 1]);
+    let (first_dir, last_dir) = (first.sub(light), last.sub(light));
+    // Include the corners of the bounds inside the shadow's wedge,
+    // ordered from the last vertex's side back to the first's
+    let perp_dot = |a: V, b: V| a.x() * b.y() - a.y() * b.x();
+    let turn = perp_dot(first_dir, last_dir);
+    let mut corners: Vec<(V::Scalar, V)> = corners2(&bounds)
+        .into_iter()
+        .filter_map(|corner| {
+            let dir = corner.sub(light);
+            let inside = perp_dot(first_dir, dir) * turn >= V::Scalar::ZERO
+                && perp_dot(dir, last_dir) * turn >= V::Scalar::ZERO;
+            let angle = (perp_dot(first_dir, dir) * turn).atan2(first_dir.dot(dir) * turn.abs());
+            inside.then_some((angle, corner))
+        })
+        .collect();
+    corners.sort_by(|(a, _), (b, _)| b.partial_cmp(a).expect("angle comparison failed"));
+    shadow.push(exit_point(light, last_dir, &bounds));
+    shadow.extend(corners.into_iter().map(|(_, corner)| corner));
+    shadow.push(exit_point(light, first_dir, &bounds));
+    // Exit points may coincide with corners
+    shadow.dedup_by(|a, b| a.squared_dist(*b) == V::Scalar::ZERO);
+    if shadow.signed_area() < V::Scalar::ZERO {
+        shadow.reverse();
+    }
+    Some(shadow)
+}
+
+/// Get the shadow cast by an aabb from a point light
+///
+/// See [`shadow_polygon`] for details. Returns `None` if the light is inside the aabb
+/// or the aabb has no area.
+pub fn aabb_shadow_polygon<V, A>(light: V, occluder: A, bounds: A) -> Option<Vec<V>>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+    A: Aabb<Vector = V>,
+{
+    shadow_polygon(light, &corners2(&occluder), bounds)
+}
+
+/// Get the corners of the X and Y dimensions of an aabb in counter-clockwise order
+fn corners2<V, A>(aabb: &A) -> [V; 4]
+where
+    V: VecN,
+    A: Aabb<Vector = V>,
+{
+    let [left, top, right, bottom] = [
+        aabb.origin_dim(0),
+        aabb.origin_dim(1),
+        aabb.end_dim(0),
+        aabb.end_dim(1),
+    ];
+    [
+        vec2(left, top),
+        vec2(right, top),
+        vec2(right, bottom),
+        vec2(left, bottom),
+    ]
+}
+
+/// Get the point where a 2D ray from inside an aabb exits it
+fn exit_point<V, A>(origin: V, dir: V, aabb: &A) -> V
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+    A: Aabb<Vector = V>,
+{
+    let t = (0..2)
+        .filter(|&i| !dir.dim(i).is_zero())
+        .map(|i| {
+            let bound = if dir.dim(i) > V::Scalar::ZERO {
+                aabb.end_dim(i)
+            } else {
+                aabb.origin_dim(i)
+            };
+            (bound - origin.dim(i)) / dir.dim(i)
+        })
+        .fold(V::Scalar::INFINITY, V::Scalar::minn);
+    origin.add(dir.mul(t))
+}
+
+/// Get the distance along a 2D ray of its intersection with a segment
+fn ray_segment<V>(origin: V, dir: V, [a, b]: [V; 2], slack: V::Scalar) -> Option<V::Scalar>
+where
+    V: VecN + Copy,
+    V::Scalar: FloatingScalar,
+{
+    let edge = b.sub(a);
+    let denom = dir.x() * edge.y() - dir.y() * edge.x();
+    if denom.is_near_zero(edge.squared_mag()) {
+        return None;
+    }
+    let to_a = a.sub(origin);
+    let t = (to_a.x() * edge.y() - to_a.y() * edge.x()) / denom;
+    let s = (to_a.x() * dir.y() - to_a.y() * dir.x()) / denom;
+    if t < V::Scalar::ZERO || s < -slack || s > V::Scalar::ONE + slack {
+        return None;
+    }
+    Some(t)
+}
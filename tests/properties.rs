@@ -0,0 +1,866 @@
+use ndmath::*;
+use proptest::prelude::*;
+
+fn approx_eq<T: FloatingScalar>(a: T, b: T, scale: T) -> bool {
+    (a - b).abs() <= T::from_f64(1e-4) * (T::ONE + scale.abs())
+}
+
+macro_rules! integer_properties {
+    ($name:ident, $type:ty, $range:expr, $nonzero:expr) => {
+        mod $name {
+            use super::*;
+
+            proptest! {
+                #[test]
+                fn mul_div_round_trip(a in prop::array::uniform3($range), k in $nonzero) {
+                    prop_assert_eq!(a.mul(k).div(k), a);
+                }
+
+                #[test]
+                fn div2_undoes_mul2(a in prop::array::uniform3($range), b in prop::array::uniform3($nonzero)) {
+                    prop_assert_eq!(a.mul2(b).div2(b), a);
+                }
+
+                #[test]
+                fn dot_is_commutative(a in prop::array::uniform3($range), b in prop::array::uniform3($range)) {
+                    prop_assert_eq!(a.dot(b), b.dot(a));
+                }
+
+                #[test]
+                fn dot_is_bilinear(
+                    a in prop::array::uniform3($range),
+                    b in prop::array::uniform3($range),
+                    c in prop::array::uniform3($range),
+                    k in $range,
+                ) {
+                    prop_assert_eq!(a.add(b).dot(c), a.dot(c) + b.dot(c));
+                    prop_assert_eq!(a.mul(k).dot(c), a.dot(c) * k);
+                }
+
+                #[test]
+                fn dot_self_is_squared_mag(a in prop::array::uniform3($range)) {
+                    prop_assert_eq!(a.dot(a), a.squared_mag());
+                }
+
+                #[test]
+                fn abs_diff_is_symmetric(a in prop::array::uniform3($range), b in prop::array::uniform3($range)) {
+                    prop_assert_eq!(a.abs_diff(b), b.abs_diff(a));
+                }
+
+                #[test]
+                fn midpoint_is_between(a in prop::array::uniform3(any::<$type>()), b in prop::array::uniform3(any::<$type>())) {
+                    let m = a.midpoint(b);
+                    for i in 0..3 {
+                        prop_assert!(m[i] >= a[i].min(b[i]) && m[i] <= a[i].max(b[i]));
+                    }
+                }
+
+                #[test]
+                fn select_by_mask_clamps(a in prop::array::uniform3($range), lo in prop::array::uniform3($range)) {
+                    let clamped = a.select(a.lt2(&lo), lo);
+                    prop_assert!(clamped.ge2(&lo).iter().all(|&b| b));
+                    for i in 0..3 {
+                        prop_assert_eq!(clamped[i], a[i].maxx(lo[i]));
+                    }
+                }
+
+                #[test]
+                fn clamp2_is_within_bounds(a in prop::array::uniform3($range), lo in prop::array::uniform3($range), hi in prop::array::uniform3($range)) {
+                    let (lo, hi) = (lo.min2(hi), lo.max2(hi));
+                    let c = a.clamp2(lo, hi);
+                    prop_assert!(c.ge2(&lo).iter().all(|&b| b));
+                    prop_assert!(c.le2(&hi).iter().all(|&b| b));
+                }
+
+                #[test]
+                fn bounding_contains_points(points in prop::collection::vec(prop::array::uniform2($range), 1..20)) {
+                    let aabb = <[[$type; 2]; 2]>::bounding(points.iter().copied()).unwrap();
+                    for p in points {
+                        prop_assert!(aabb.contains(p));
+                    }
+                    prop_assert!(aabb.contains(aabb.center()));
+                }
+            }
+        }
+    };
+}
+
+integer_properties!(u8_props, u8, 0u8..4, 1u8..4);
+integer_properties!(u16_props, u16, 0u16..20, 1u16..20);
+integer_properties!(u32_props, u32, 0u32..1000, 1u32..1000);
+integer_properties!(u64_props, u64, 0u64..1000, 1u64..1000);
+integer_properties!(u128_props, u128, 0u128..1000, 1u128..1000);
+integer_properties!(usize_props, usize, 0usize..1000, 1usize..1000);
+integer_properties!(i8_props, i8, -3i8..4, 1i8..4);
+integer_properties!(i16_props, i16, -20i16..20, 1i16..20);
+integer_properties!(i32_props, i32, -500i32..500, 1i32..500);
+integer_properties!(i64_props, i64, -1000i64..1000, 1i64..1000);
+integer_properties!(i128_props, i128, -1000i128..1000, 1i128..1000);
+integer_properties!(isize_props, isize, -1000isize..1000, 1isize..1000);
+
+macro_rules! float_properties {
+    ($name:ident, $type:ty) => {
+        mod $name {
+            use super::*;
+
+            const RANGE: std::ops::Range<$type> = -1000.0..1000.0;
+
+            fn nonzero() -> impl Strategy<Value = $type> {
+                prop_oneof![-1000.0 as $type..-0.01, 0.01 as $type..1000.0]
+            }
+
+            proptest! {
+                #[test]
+                fn div_mul_round_trip(a in prop::array::uniform3(RANGE), k in nonzero()) {
+                    let b = a.div(k).mul(k);
+                    for i in 0..3 {
+                        prop_assert!(approx_eq(b[i], a[i], a[i]));
+                    }
+                }
+
+                #[test]
+                fn div2_undoes_mul2(a in prop::array::uniform3(RANGE), b in prop::array::uniform3(nonzero())) {
+                    let c = a.mul2(b).div2(b);
+                    for i in 0..3 {
+                        prop_assert!(approx_eq(c[i], a[i], a[i]));
+                    }
+                }
+
+                #[test]
+                fn dot_is_commutative(a in prop::array::uniform3(RANGE), b in prop::array::uniform3(RANGE)) {
+                    prop_assert_eq!(a.dot(b), b.dot(a));
+                }
+
+                #[test]
+                fn dot_is_bilinear(
+                    a in prop::array::uniform3(RANGE),
+                    b in prop::array::uniform3(RANGE),
+                    c in prop::array::uniform3(RANGE),
+                    k in RANGE,
+                ) {
+                    let scale = (a.mag() + b.mag()) * c.mag();
+                    prop_assert!(approx_eq(a.add(b).dot(c), a.dot(c) + b.dot(c), scale));
+                    prop_assert!(approx_eq(a.mul(k).dot(c), a.dot(c) * k, scale * k));
+                }
+
+                #[test]
+                fn dot_self_is_squared_mag(a in prop::array::uniform3(RANGE)) {
+                    prop_assert!(approx_eq(a.dot(a), a.mag() * a.mag(), a.squared_mag()));
+                }
+
+                #[test]
+                fn cross_is_orthogonal(a in prop::array::uniform3(RANGE), b in prop::array::uniform3(RANGE)) {
+                    let c = a.cross(b);
+                    let scale = a.squared_mag() * b.mag();
+                    prop_assert!(approx_eq(c.dot(a), 0.0, scale));
+                    prop_assert!(approx_eq(c.dot(b), 0.0, scale));
+                    prop_assert_eq!(b.cross(a), c.neg());
+                }
+
+                #[test]
+                fn rotate_around_preserves_mag_and_axis(
+                    a in prop::array::uniform3(RANGE),
+                    axis in prop::array::uniform3(RANGE),
+                    angle in -10.0 as $type..10.0,
+                ) {
+                    prop_assume!(axis.mag() > 0.01);
+                    let r = a.rotate_around(axis, angle);
+                    prop_assert!(approx_eq(r.mag(), a.mag(), a.mag()));
+                    let scale = a.mag() * axis.mag();
+                    prop_assert!(approx_eq(r.dot(axis), a.dot(axis), scale));
+                }
+
+                #[test]
+                fn projection_and_rejection_sum(a in prop::array::uniform3(RANGE), b in prop::array::uniform3(RANGE)) {
+                    prop_assume!(b.mag() > 0.01);
+                    let (p, r) = (a.project_onto(b), a.reject_from(b));
+                    let sum = p.add(r);
+                    for i in 0..3 {
+                        prop_assert!(approx_eq(sum[i], a[i], a.mag()));
+                    }
+                    prop_assert!(approx_eq(r.dot(b), 0.0, a.mag() * b.mag()));
+                }
+
+                #[test]
+                fn reflect_preserves_mag(a in prop::array::uniform3(RANGE), n in prop::array::uniform3(RANGE)) {
+                    prop_assume!(n.mag() > 0.01);
+                    let n = n.unit();
+                    let r = a.reflect(n);
+                    prop_assert!(approx_eq(r.mag(), a.mag(), a.mag()));
+                    prop_assert!(approx_eq(r.dot(n), -a.dot(n), a.mag()));
+                }
+
+                #[test]
+                fn clamp_mag_is_within_bounds(a in prop::array::uniform3(RANGE), min in 0.0 as $type..100.0, extra in 0.0 as $type..100.0) {
+                    prop_assume!(a.mag() > 0.01);
+                    let max = min + extra;
+                    let c = a.clamp_mag(min, max).mag();
+                    prop_assert!(c >= min * (1.0 - 1e-4) && c <= max * (1.0 + 1e-4));
+                    prop_assert!(a.limit(max).mag() <= max * (1.0 + 1e-4));
+                }
+
+                #[test]
+                fn unit_has_unit_mag(a in prop::array::uniform3(RANGE)) {
+                    prop_assume!(a.mag() > 0.01);
+                    prop_assert!(approx_eq(a.unit().mag(), 1.0, 1.0));
+                }
+
+                #[test]
+                fn element_wise_inverses_round_trip(a in prop::array::uniform3(0.01 as $type..1000.0)) {
+                    let b = a.ln().exp();
+                    let c = a.sqrt().powi(2);
+                    for i in 0..3 {
+                        prop_assert!(approx_eq(b[i], a[i], a[i]));
+                        prop_assert!(approx_eq(c[i], a[i], a[i]));
+                    }
+                }
+
+                #[test]
+                fn normalize_fast_is_near_unit(a in prop::array::uniform3(RANGE)) {
+                    prop_assume!(a.mag() > 0.01);
+                    // The fast-rsqrt approximation is only accurate to within a fraction of a percent
+                    prop_assert!((a.normalize_fast().mag() - 1.0).abs() < 0.005);
+                }
+
+                #[test]
+                fn bounding_contains_points(points in prop::collection::vec(prop::array::uniform3(RANGE), 1..20)) {
+                    let aabb = <[$type; 6]>::bounding(points.iter().copied()).unwrap();
+                    // The end of the aabb is computed as origin + size, which may round down
+                    for p in points {
+                        for i in 0..3 {
+                            prop_assert!(p[i] >= aabb.origin_dim(i));
+                            prop_assert!(approx_eq(p[i].min(aabb.end_dim(i)), p[i], p[i]));
+                        }
+                    }
+                    prop_assert!(aabb.contains(aabb.center()));
+                }
+            }
+        }
+    };
+}
+
+float_properties!(f32_props, f32);
+float_properties!(f64_props, f64);
+
+fn flip(o: Orientation) -> Orientation {
+    match o {
+        Orientation::CounterClockwise => Orientation::Clockwise,
+        Orientation::Clockwise => Orientation::CounterClockwise,
+        Orientation::Degenerate => Orientation::Degenerate,
+    }
+}
+
+proptest! {
+    #[test]
+    fn exact_orientation_is_antisymmetric(
+        a in prop::array::uniform2(-1000.0f64..1000.0),
+        b in prop::array::uniform2(-1000.0f64..1000.0),
+        t in 0.0f64..1.0,
+    ) {
+        // Points near the segment from a to b are nearly collinear
+        let c = a.lerp(b, t);
+        prop_assert_eq!(orient2d_exact(a, b, c), flip(orient2d_exact(b, a, c)));
+        prop_assert_eq!(orient2d_exact(a, b, c), orient2d_exact(b, c, a));
+    }
+
+    #[test]
+    fn exact_orientation_matches_tolerant(
+        a in prop::array::uniform3(-1000.0f64..1000.0),
+        b in prop::array::uniform3(-1000.0f64..1000.0),
+        c in prop::array::uniform3(-1000.0f64..1000.0),
+        d in prop::array::uniform3(-1000.0f64..1000.0),
+    ) {
+        let tolerant = orient3d(a, b, c, d, 1e-6);
+        prop_assume!(tolerant != Orientation::Degenerate);
+        prop_assert_eq!(orient3d_exact(a, b, c, d), tolerant);
+    }
+}
+
+proptest! {
+    #[test]
+    fn cubic_roots_are_roots(a in -100.0f64..100.0, b in -100.0f64..100.0, c in -100.0f64..100.0, k in 0.1f64..10.0) {
+        // k * (x - a)(x - b)(x - c)
+        let roots = cubic_roots(k, -k * (a + b + c), k * (a * b + b * c + c * a), -k * a * b * c);
+        prop_assert!(!roots.is_empty());
+        for root in roots {
+            let nearest = [a, b, c].into_iter().map(|r| (r - root).abs()).fold(f64::INFINITY, f64::min);
+            prop_assert!(nearest < 1e-3 * (1.0 + root.abs()), "{} is not a root", root);
+        }
+    }
+
+    #[test]
+    fn quartic_roots_are_roots(roots in prop::array::uniform4(-10.0f64..10.0)) {
+        let [a, b, c, d] = roots;
+        // (x - a)(x - b)(x - c)(x - d)
+        let found = quartic_roots(
+            1.0,
+            -(a + b + c + d),
+            a * b + a * c + a * d + b * c + b * d + c * d,
+            -(a * b * c + a * b * d + a * c * d + b * c * d),
+            a * b * c * d,
+        );
+        prop_assert!(!found.is_empty());
+        for root in found {
+            let nearest = roots.into_iter().map(|r| (r - root).abs()).fold(f64::INFINITY, f64::min);
+            prop_assert!(nearest < 1e-3 * (1.0 + root.abs()), "{} is not a root", root);
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum TreeOp<T> {
+    Insert([[T; 2]; 2]),
+    Update(usize, [[T; 2]; 2]),
+    Remove(usize),
+}
+
+fn tree_op<T: std::fmt::Debug>(
+    aabb: impl Strategy<Value = [[T; 2]; 2]> + Clone,
+) -> impl Strategy<Value = TreeOp<T>> {
+    prop_oneof![
+        2 => aabb.clone().prop_map(TreeOp::Insert),
+        1 => (any::<usize>(), aabb).prop_map(|(i, aabb)| TreeOp::Update(i, aabb)),
+        1 => any::<usize>().prop_map(TreeOp::Remove),
+    ]
+}
+
+fn boxes_overlap<T: Scalar>(a: [[T; 2]; 2], b: [[T; 2]; 2]) -> bool {
+    (0..2).all(|i| a[0][i] <= b[0][i] + b[1][i] && b[0][i] <= a[0][i] + a[1][i])
+}
+
+fn tree_matches_brute_force<T>(
+    ops: Vec<TreeOp<T>>,
+    query: [[T; 2]; 2],
+    point: [T; 2],
+    margin: T,
+) -> Result<(), TestCaseError>
+where
+    T: Scalar + std::fmt::Debug,
+{
+    let mut tree = AabbTree::new(margin);
+    let mut live: Vec<(usize, [[T; 2]; 2])> = Vec::new();
+    for op in ops {
+        match op {
+            TreeOp::Insert(aabb) => live.push((tree.insert(aabb), aabb)),
+            TreeOp::Update(i, aabb) if !live.is_empty() => {
+                let i = i % live.len();
+                tree.update(live[i].0, aabb);
+                live[i].1 = aabb;
+            }
+            TreeOp::Remove(i) if !live.is_empty() => {
+                let (id, _) = live.swap_remove(i % live.len());
+                prop_assert!(tree.remove(id));
+            }
+            _ => {}
+        }
+    }
+    prop_assert_eq!(tree.len(), live.len());
+    let fat = |id: usize| tree.fat_aabb(id).expect("live ids have aabbs");
+    for &(id, aabb) in &live {
+        let fat = fat(id);
+        prop_assert!((0..2)
+            .all(|i| fat[0][i] <= aabb[0][i] && aabb[0][i] + aabb[1][i] <= fat[0][i] + fat[1][i]));
+    }
+    let mut found = tree.query_aabb(query);
+    found.sort_unstable();
+    let mut expected: Vec<usize> = (live.iter().map(|&(id, _)| id))
+        .filter(|&id| boxes_overlap(fat(id), query))
+        .collect();
+    expected.sort_unstable();
+    prop_assert_eq!(found, expected);
+    let mut expected_pairs = Vec::new();
+    for &(a, _) in &live {
+        for &(b, _) in &live {
+            if a < b && boxes_overlap(fat(a), fat(b)) {
+                expected_pairs.push((a, b));
+            }
+        }
+    }
+    expected_pairs.sort_unstable();
+    prop_assert_eq!(tree.pairs(), expected_pairs);
+    // The nearest enlarged aabb is as close as the closest one found by brute force
+    let dist = |id: usize| {
+        let fat = fat(id);
+        let end = [fat[0][0] + fat[1][0], fat[0][1] + fat[1][1]];
+        point.clamp2(fat[0], end).abs_diff(point).squared_mag()
+    };
+    let closest = live
+        .iter()
+        .map(|&(id, _)| dist(id))
+        .reduce(|a, b| a.minn(b));
+    prop_assert_eq!(tree.nearest(point).map(dist), closest);
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn aabb_tree_matches_brute_force(
+        ops in prop::collection::vec(tree_op((
+            prop::array::uniform2(-100.0f64..100.0),
+            prop::array::uniform2(0.0f64..20.0),
+        ).prop_map(|(origin, size)| [origin, size])), 1..60),
+        query in (prop::array::uniform2(-100.0f64..100.0), prop::array::uniform2(0.0f64..20.0)),
+        point in prop::array::uniform2(-120.0f64..120.0),
+        margin in 0.0f64..5.0,
+    ) {
+        tree_matches_brute_force(ops, [query.0, query.1], point, margin)?;
+    }
+
+    #[test]
+    fn unsigned_aabb_tree_matches_brute_force(
+        ops in prop::collection::vec(tree_op((
+            prop::array::uniform2(0u32..100),
+            prop::array::uniform2(0u32..20),
+        ).prop_map(|(origin, size)| [origin, size])), 1..60),
+        query in (prop::array::uniform2(0u32..100), prop::array::uniform2(0u32..20)),
+        point in prop::array::uniform2(0u32..120),
+        margin in 0u32..5,
+    ) {
+        // Aabbs near zero enlarge past the scalar's minimum
+        tree_matches_brute_force(ops, [query.0, query.1], point, margin)?;
+    }
+
+    #[test]
+    fn interval_set_matches_set_model(
+        ops in prop::collection::vec((any::<bool>(), -50i32..50, 0i32..20), 0..40),
+        other in prop::collection::vec((-50i32..50, 0i32..20), 0..10),
+    ) {
+        let mut set = IntervalSet::new();
+        let mut model = std::collections::BTreeSet::new();
+        for (insert, start, len) in ops {
+            if insert {
+                set.insert(start..start + len);
+                model.extend(start..start + len);
+            } else {
+                set.remove(start..start + len);
+                for x in start..start + len {
+                    model.remove(&x);
+                }
+            }
+        }
+        prop_assert_eq!(set.len(), model.len() as u64);
+        for x in -60..80 {
+            prop_assert_eq!(set.contains(x), model.contains(&x));
+        }
+        // Runs are sorted, nonempty, and separated by gaps
+        for pair in set.runs().windows(2) {
+            prop_assert!(pair[0].end < pair[1].start);
+        }
+        prop_assert!(set.runs().iter().all(|run| run.start < run.end));
+        let mut other_set = IntervalSet::new();
+        let mut other_model = std::collections::BTreeSet::new();
+        for (start, len) in other {
+            other_set.insert(start..start + len);
+            other_model.extend(start..start + len);
+        }
+        let union = set.union(&other_set);
+        let intersection = set.intersection(&other_set);
+        for x in -60..80 {
+            prop_assert_eq!(union.contains(x), model.contains(&x) || other_model.contains(&x));
+            prop_assert_eq!(intersection.contains(x), model.contains(&x) && other_model.contains(&x));
+        }
+    }
+
+    #[test]
+    fn hex_round_trips(
+        hex in prop::array::uniform2(-1000i32..1000),
+        offset in prop::array::uniform2(-0.2f64..0.2),
+        flat in any::<bool>(),
+        size in 0.1f64..100.0,
+        origin in prop::array::uniform2(-1000.0f64..1000.0),
+    ) {
+        let [q, r] = hex;
+        prop_assert_eq!(<[i32; 2]>::round_hex(q as f64, r as f64), hex);
+        // Points within a small cube distance of a hex's center round to it
+        prop_assert_eq!(<[i32; 2]>::round_hex(q as f64 + offset[0], r as f64 + offset[1]), hex);
+        let orientation = if flat {
+            HexOrientation::FlatTop
+        } else {
+            HexOrientation::PointyTop
+        };
+        let layout = HexLayout::new(orientation, size, origin);
+        prop_assert_eq!(layout.from_world(layout.to_world::<[f64; 2]>(hex)), hex);
+    }
+
+    #[test]
+    fn display_parse_round_trips(
+        f in prop::array::uniform3(-1e6f64..1e6),
+        i in prop::array::uniform3(any::<i32>()),
+        origin in prop::array::uniform2(-1000i32..1000),
+        size in prop::array::uniform2(0i32..1000),
+    ) {
+        prop_assert_eq!(parse_vec::<[f64; 3]>(&f.display().to_string()), Some(f));
+        prop_assert_eq!(parse_vec::<[i32; 3]>(&i.display().to_string()), Some(i));
+        let aabb = [origin, size];
+        prop_assert_eq!(parse_aabb::<[[i32; 2]; 2]>(&aabb.display_aabb().to_string()), Some(aabb));
+    }
+
+    #[test]
+    fn catenary_has_length_and_endpoints(
+        a in prop::array::uniform2(-100.0f64..100.0),
+        b in prop::array::uniform2(-100.0f64..100.0),
+        slack in 1.05f64..3.0,
+    ) {
+        prop_assume!((a[0] - b[0]).abs() > 1.0);
+        let length = a.dist(b) * slack;
+        let points = catenary(a, b, length, [0.0, -1.0], 2000);
+        prop_assert_eq!(points.len(), 2000);
+        prop_assert!(points[0].dist(a) < 1e-6 * (1.0 + length));
+        prop_assert!(points[1999].dist(b) < 1e-6 * (1.0 + length));
+        let sampled: f64 = points.windows(2).map(|pair| pair[0].dist(pair[1])).sum();
+        // The chords between samples are slightly shorter than the rope
+        prop_assert!(sampled <= length * (1.0 + 1e-9) && sampled >= length * (1.0 - 1e-3), "{} != {}", sampled, length);
+    }
+}
+
+fn rotation() -> impl Strategy<Value = [[f64; 3]; 3]> {
+    (prop::array::uniform3(-1.0f64..1.0), -10.0f64..10.0).prop_map(|(axis, angle)| {
+        [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]].map(|v| v.rotate_around(axis, angle))
+    })
+}
+
+fn rotations_match(a: [[f64; 3]; 3], b: [[f64; 3]; 3]) -> bool {
+    (0..3).all(|i| a[i].dist(b[i]) < 1e-9)
+}
+
+proptest! {
+    #[test]
+    fn swing_twist_recomposes(basis in rotation(), axis in prop::array::uniform3(-1.0f64..1.0)) {
+        prop_assume!(axis.mag() > 1e-3);
+        let (swing, twist) = swing_twist(basis, axis);
+        prop_assert!(rotations_match(compose_rotations(swing, twist), basis));
+        // The twist leaves the axis in place, and the swing turns it where the basis does
+        prop_assert!(rotate_by(twist, axis).dist(axis) < 1e-9);
+        prop_assert!(rotate_by(swing, axis).dist(rotate_by(basis, axis)) < 1e-9);
+    }
+}
+
+proptest! {
+    #[test]
+    fn rotation_ln_undoes_exp(axis in prop::array::uniform3(-1.0f64..1.0), angle in 0.0f64..3.1) {
+        prop_assume!(axis.mag() > 1e-3);
+        let axis_angle = axis.unit().mul(angle);
+        prop_assert!(rotation_ln(rotation_exp(axis_angle)).dist(axis_angle) < 1e-6);
+    }
+
+    #[test]
+    fn rotation_exp_undoes_ln(basis in rotation()) {
+        prop_assert!(rotations_match(rotation_exp(rotation_ln(basis)), basis));
+    }
+
+    #[test]
+    fn rotation_half_pow_squares_back(basis in rotation()) {
+        let half = rotation_pow(basis, 0.5);
+        prop_assert!(rotations_match(compose_rotations(half, half), basis));
+    }
+
+    #[test]
+    fn angular_velocity_steps_add_up(
+        basis in rotation(),
+        omega in prop::array::uniform3(-5.0f64..5.0),
+        dt in 0.0f64..1.0,
+    ) {
+        let once = integrate_angular_velocity(basis, omega, dt);
+        let half = integrate_angular_velocity(basis, omega, dt / 2.0);
+        let twice = integrate_angular_velocity(half, omega, dt / 2.0);
+        prop_assert!(rotations_match(once, twice));
+    }
+}
+
+proptest! {
+    #[test]
+    fn depth_linearization_round_trips(
+        near in 0.01f64..10.0,
+        range in 1.0f64..1000.0,
+        t in 0.0f64..1.0,
+        infinite in any::<bool>(),
+    ) {
+        let far = if infinite { f64::INFINITY } else { near + range };
+        let linear = near + range * t;
+        let depth = delinearize_depth(linear, near, far);
+        prop_assert!((0.0..=1.0).contains(&depth));
+        prop_assert!(approx_eq(linearize_depth(depth, near, far), linear, linear));
+        let depth = delinearize_reversed_depth(linear, near, far);
+        prop_assert!((0.0..=1.0).contains(&depth));
+        prop_assert!(approx_eq(linearize_reversed_depth(depth, near, far), linear, linear));
+        // The planes land on the ends of the range
+        prop_assert!(approx_eq(delinearize_depth(near, near, far), 0.0, 0.0));
+        prop_assert!(approx_eq(delinearize_reversed_depth(near, near, far), 1.0, 0.0));
+    }
+}
+
+proptest! {
+    #[test]
+    fn cascade_splits_increase(
+        near in 0.01f64..10.0,
+        range in 1.0f64..1000.0,
+        count in 1usize..8,
+        lambda in 0.0f64..1.0,
+    ) {
+        let far = near + range;
+        let splits = cascade_splits(near, far, count, lambda);
+        prop_assert_eq!(splits.len(), count + 1);
+        prop_assert_eq!(splits[0], near);
+        prop_assert_eq!(splits[count], far);
+        prop_assert!(splits.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn frustum_corners_lie_on_planes(
+        eye in prop::array::uniform3(-10.0f64..10.0),
+        target in prop::array::uniform3(-10.0f64..10.0),
+        fov_y in 0.1f64..3.0,
+        aspect in 0.2f64..5.0,
+        near in 0.01f64..10.0,
+        range in 1.0f64..1000.0,
+    ) {
+        prop_assume!(eye.dist(target) > 1e-3);
+        let orientation = look_at(eye, target, [0.0, 1.0, 0.0]);
+        let [right, up, forward] = orientation;
+        let far = near + range;
+        let corners = frustum_corners(eye, orientation, fov_y, aspect, near, far);
+        for (i, corner) in corners.into_iter().enumerate() {
+            let offset = corner.sub(eye);
+            let depth = if i < 4 { near } else { far };
+            prop_assert!(approx_eq(offset.dot(forward), depth, depth));
+            // Each corner sits on the edge of the field of view
+            let tan_y = (fov_y / 2.0).tan();
+            prop_assert!(approx_eq(offset.dot(up).abs(), tan_y * depth, tan_y * depth));
+            prop_assert!(approx_eq(offset.dot(right).abs(), tan_y * aspect * depth, tan_y * aspect * depth));
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn screen_aabb_contains_visible_points(
+        origin in prop::array::uniform3(-10.0f64..10.0),
+        size in prop::array::uniform3(0.0f64..10.0),
+        eye in prop::array::uniform3(-10.0f64..10.0),
+        target in prop::array::uniform3(-10.0f64..10.0),
+        fov_y in 0.1f64..3.0,
+        aspect in 0.2f64..5.0,
+        near in 0.01f64..1.0,
+        t in prop::array::uniform3(0.0f64..1.0),
+    ) {
+        prop_assume!(eye.dist(target) > 1e-3);
+        let orientation = look_at(eye, target, [0.0, 1.0, 0.0]);
+        let [right, up, forward] = orientation;
+        let aabb = [origin, size];
+        let screen = screen_aabb(aabb, eye, orientation, fov_y, aspect, near);
+        let point = origin.add(size.mul2(t));
+        let [x, y, z] = [right, up, forward].map(|axis| point.sub(eye).dot(axis));
+        if z >= near {
+            let screen = screen.expect("a visible point gives bounds");
+            let tan_y = (fov_y / 2.0).tan();
+            let projected = [x / (z * tan_y * aspect), y / (z * tan_y)];
+            for d in 0..2 {
+                prop_assert!(projected[d] >= screen[0][d] - 1e-6 * (1.0 + projected[d].abs()));
+                prop_assert!(projected[d] <= screen[0][d] + screen[1][d] + 1e-6 * (1.0 + projected[d].abs()));
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn matrix_ln_undoes_exp(m in prop::array::uniform3(prop::array::uniform3(-0.5f64..0.5))) {
+        let back = matrix_ln(matrix_exp(m)).expect("the exponential has a logarithm");
+        prop_assert!((0..3).all(|i| back[i].dist(m[i]) < 1e-9), "{:?} != {:?}", back, m);
+    }
+
+    #[test]
+    fn matrix_exp_of_cross_product_is_rotation(axis_angle in prop::array::uniform3(-3.0f64..3.0)) {
+        // The columns of the matrix that crosses the axis-angle vector with a vector
+        let [x, y, z] = axis_angle;
+        let cross = [[0.0, z, -y], [-z, 0.0, x], [y, -x, 0.0]];
+        prop_assert!(rotations_match(matrix_exp(cross), rotation_exp(axis_angle)));
+    }
+
+    #[test]
+    fn matrix_exp_of_diagonal(d in prop::array::uniform4(-5.0f64..5.0)) {
+        let diag: [[f64; 4]; 4] = std::array::from_fn(|i| std::array::from_fn(|j| if i == j { d[i] } else { 0.0 }));
+        let exp = matrix_exp(diag);
+        for (i, row) in exp.iter().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                let expected = if i == j { d[i].exp() } else { 0.0 };
+                prop_assert!(approx_eq(x, expected, expected));
+            }
+        }
+        let ln = matrix_ln(exp).expect("positive diagonals have a logarithm");
+        prop_assert!((0..4).all(|i| ln[i].dist(diag[i]) < 1e-9));
+    }
+}
+
+proptest! {
+    #[test]
+    fn heightfield_vertical_rays_hit(
+        heights in prop::collection::vec(-5.0f64..5.0, 16),
+        xy in prop::array::uniform2(0.01f64..2.99),
+        down in any::<bool>(),
+    ) {
+        let field = Heightfield::new(Grid::new([4, 4], heights).expect("16 heights fill the grid"), [1.0, 1.0]);
+        let (z, dir) = if down { (10.0, -1.0) } else { (-10.0, 1.0) };
+        let hit = field.raycast(Ray::new([xy[0], xy[1], z], [0.0, 0.0, dir]));
+        let hit = hit.expect("a vertical ray over the field hits it");
+        prop_assert!(approx_eq(hit.point[0], xy[0], 1.0) && approx_eq(hit.point[1], xy[1], 1.0));
+        prop_assert!(field.raycast(Ray::new([xy[0], xy[1], 0.0], [0.0; 3])).is_none());
+    }
+}
+
+proptest! {
+    #[test]
+    fn round_offset_stays_on_circles(
+        step in prop_oneof![Just(0.0f64), Just(-1.0), Just(f64::NAN), 1e-3f64..1.0],
+        distance in 0.1f64..2.0,
+    ) {
+        let square = [[0.0f64, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+        let offset = square.offset(distance, LineJoin::Round { step });
+        // Each corner gets at most the most segments a round join can have
+        prop_assert!(offset.len() <= 4 * 1025);
+        for p in offset {
+            let gap = square.iter().map(|c| c.dist(p)).fold(f64::INFINITY, f64::min);
+            let edge = square.signed_distance(p).expect("the square has vertices");
+            prop_assert!(approx_eq(gap.min(edge), distance, distance), "{:?}", p);
+        }
+    }
+}
+
+fn mass_matches(a: MassProperties<f64>, b: MassProperties<f64>) -> bool {
+    let scale = a.volume.abs() + a.inertia.iter().flatten().map(|x| x.abs()).sum::<f64>();
+    approx_eq(a.volume, b.volume, scale)
+        && a.center.dist(b.center) < 1e-6 * (1.0 + a.center.mag())
+        && (0..3).all(|i| (0..3).all(|j| approx_eq(a.inertia[i][j], b.inertia[i][j], scale)))
+}
+
+proptest! {
+    #[test]
+    fn convex_hull_of_box_matches_box(
+        origin in prop::array::uniform3(-10.0f64..10.0),
+        size in prop::array::uniform3(0.1f64..10.0),
+        inside in prop::collection::vec(prop::array::uniform3(0.0f64..1.0), 0..20),
+        density in 0.1f64..10.0,
+    ) {
+        let aabb = [origin, size];
+        let corners = (0..8).map(|i: usize| {
+            let t: [f64; 3] = std::array::from_fn(|d| (i >> d & 1) as f64);
+            origin.add(size.mul2(t))
+        });
+        // Interior points come first so the hull is built around them
+        let points: Vec<[f64; 3]> = inside.iter().map(|&t| origin.add(size.mul2(t))).chain(corners).collect();
+        let hull = MassProperties::convex_hull(&points, density).expect("boxes have volume");
+        prop_assert!(mass_matches(hull, MassProperties::aabb(aabb, density)));
+    }
+
+    #[test]
+    fn convex_hull_contains_its_center(
+        points in prop::collection::vec(prop::array::uniform3(-10.0f64..10.0), 4..40),
+    ) {
+        if let Some(hull) = MassProperties::convex_hull(&points, 1.0) {
+            prop_assert!(hull.volume > 0.0);
+            let bounds: [[f64; 3]; 2] = Aabb::bounding(points.iter().copied()).expect("there are points");
+            prop_assert!(bounds.contains(hull.center));
+            prop_assert!(hull.volume <= bounds[1].product_dims() * (1.0 + 1e-9));
+        }
+    }
+
+    #[test]
+    fn triangle_mesh_rejects_inside_out(
+        offset in prop::array::uniform3(-10.0f64..10.0),
+        scale in 0.1f64..10.0,
+    ) {
+        let tetra = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+            .map(|p: [f64; 3]| p.mul(scale).add(offset));
+        let outward = [[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]].map(|face: [usize; 3]| face.map(|i| tetra[i]));
+        let mass = MassProperties::triangle_mesh(&outward, 1.0).expect("the tetrahedron has volume");
+        prop_assert!(approx_eq(mass.volume, scale * scale * scale / 6.0, mass.volume));
+        let inside_out = outward.map(|[a, b, c]| [a, c, b]);
+        prop_assert!(MassProperties::triangle_mesh(&inside_out, 1.0).is_none());
+    }
+}
+
+fn orbit_camera() -> impl Strategy<Value = OrbitCamera<f64>> {
+    (
+        prop::array::uniform3(-10.0f64..10.0),
+        0.1f64..20.0,
+        -10.0f64..10.0,
+        -1.5f64..1.5,
+    )
+        .prop_map(|(target, distance, yaw, pitch)| OrbitCamera::new(target, distance, yaw, pitch))
+}
+
+proptest! {
+    #[test]
+    fn orbit_camera_view_centers_target(camera in orbit_camera()) {
+        let (rotation, translation) = camera.view();
+        let to_view = |p: [f64; 3]| rotate_by(rotation, p).add(translation);
+        prop_assert!(to_view(camera.eye()).mag() < 1e-9 * (1.0 + camera.eye().mag()));
+        let target = to_view(camera.target);
+        prop_assert!(target.dist([0.0, 0.0, -camera.distance]) < 1e-9 * (1.0 + camera.target.mag()));
+        // The camera's up is the view's Y axis
+        prop_assert!(rotate_by(rotation, camera.up()).dist([0.0, 1.0, 0.0]) < 1e-9);
+    }
+
+    #[test]
+    fn orbit_camera_zoom_keeps_cursor_point(
+        camera in orbit_camera(),
+        screen in prop::array::uniform2(-1.0f64..1.0),
+        fov_y in 0.2f64..2.5,
+        aspect in 0.2f64..5.0,
+        factor in 0.1f64..10.0,
+    ) {
+        let ray = screen_ray(camera.eye(), camera.orientation(), fov_y, aspect, screen);
+        let point = ray.at(camera.distance);
+        let mut zoomed = camera;
+        zoomed.zoom_toward(screen, fov_y, aspect, factor);
+        prop_assert!(approx_eq(zoomed.distance, camera.distance * factor, zoomed.distance));
+        let (rotation, translation) = zoomed.view();
+        let [x, y, z] = rotate_by(rotation, point).add(translation);
+        let tan_y = (fov_y / 2.0).tan();
+        let projected = [x / (-z * tan_y * aspect), y / (-z * tan_y)];
+        prop_assert!(projected.dist(screen) < 1e-6, "{:?} != {:?}", projected, screen);
+    }
+}
+
+proptest! {
+    #[test]
+    fn chunks_round_trip_i64(
+        pos in prop::array::uniform3(-1i64 << 40..1i64 << 40),
+        size in prop::array::uniform3(1i64..1000),
+    ) {
+        let (chunk, local) = world_to_chunk(pos, size);
+        prop_assert!((0..3).all(|i| (0..size[i]).contains(&local[i])));
+        prop_assert_eq!(chunk_to_world(chunk, local, size), pos);
+        let [origin, extent] = chunk_aabb(chunk, size);
+        prop_assert!((0..3).all(|i| origin[i] <= pos[i] && pos[i] < origin[i] + extent[i]));
+    }
+
+    #[test]
+    fn chunks_round_trip_u16(pos in prop::array::uniform2(any::<u16>()), size in prop::array::uniform2(1u16..64)) {
+        let (chunk, local) = world_to_chunk(pos, size);
+        prop_assert_eq!(chunk_to_world(chunk, local, size), pos);
+    }
+}
+
+proptest! {
+    #[test]
+    fn wander_steers_at_full_speed(
+        velocity in prop_oneof![Just([0.0f64; 2]), prop::array::uniform2(-5.0f64..5.0)],
+        offset in prop_oneof![Just([0.0f64; 2]), prop::array::uniform2(-1.0f64..1.0)],
+        distance in 0.0f64..5.0,
+        radius in 0.0f64..5.0,
+        max_speed in 0.1f64..10.0,
+    ) {
+        let force = steer::wander(velocity, distance, radius, offset, max_speed);
+        prop_assert!(force.iter().all(|x| x.is_finite()));
+        let desired = velocity.add(force);
+        if velocity == [0.0; 2] && offset == [0.0; 2] {
+            prop_assert_eq!(force, [0.0; 2]);
+        } else {
+            prop_assert!(approx_eq(desired.mag(), max_speed, max_speed));
+        }
+        if velocity == [0.0; 2] && offset != [0.0; 2] {
+            prop_assert!(desired.unit().dist(offset.unit()) < 1e-9);
+        }
+    }
+}